@@ -7,6 +7,8 @@
 // except according to those terms.
 
 use std::hash;
+use std::mem;
+use std::rc::Rc;
 use std::iter::FromIterator;
 use std::iter::IntoIterator;
 use std::ops::{
@@ -18,8 +20,10 @@ use imp_prelude::*;
 use {
     Iter,
     IterMut,
+    IntoIter,
     NdIndex,
 };
+use iterators;
 
 use numeric_util;
 
@@ -112,6 +116,14 @@ impl<S, D> Eq for ArrayBase<S, D>
           S::Elem: Eq,
 { }
 
+/// Create a one-dimensional array from an iterator.
+///
+/// ```
+/// use ndarray::{Array1, arr1};
+///
+/// let a: Array1<_> = (0..5).map(|x| x * x).collect();
+/// assert_eq!(a, arr1(&[0, 1, 4, 9, 16]));
+/// ```
 impl<A, S> FromIterator<A> for ArrayBase<S, Ix1>
     where S: DataOwned<Elem=A>
 {
@@ -122,6 +134,36 @@ impl<A, S> FromIterator<A> for ArrayBase<S, Ix1>
     }
 }
 
+/// Append the elements of an iterator to a one-dimensional array.
+///
+/// The array's own buffer is extended in place when its elements are
+/// contiguous and in order; otherwise they are first moved into a new buffer.
+///
+/// ```
+/// use ndarray::arr1;
+///
+/// let mut a = arr1(&[1, 2]);
+/// a.extend(vec![3, 4]);
+/// assert_eq!(a, arr1(&[1, 2, 3, 4]));
+/// ```
+impl<A> Extend<A> for Array<A, Ix1> {
+    fn extend<I>(&mut self, iterable: I)
+        where I: IntoIterator<Item=A>,
+    {
+        let a = mem::replace(self, Array::from_vec(Vec::new()));
+        let is_whole_buffer = a.len() == a.data.len() &&
+            a.ptr == a.data.as_ptr() as *mut A &&
+            (a.len() <= 1 || a.strides[0] == 1);
+        let mut v = if is_whole_buffer {
+            a.data
+        } else {
+            a.into_iter().collect()
+        };
+        v.extend(iterable);
+        *self = Array::from_vec(v);
+    }
+}
+
 impl<'a, S, D> IntoIterator for &'a ArrayBase<S, D>
     where D: Dimension,
           S: Data,
@@ -146,6 +188,63 @@ impl<'a, S, D> IntoIterator for &'a mut ArrayBase<S, D>
     }
 }
 
+/// Consume the array and yield its elements by value, in logical order.
+///
+/// The array's buffer is reused, no elements are cloned.
+///
+/// ```
+/// use ndarray::arr2;
+///
+/// let a = arr2(&[[String::from("a"), String::from("b")],
+///                [String::from("c"), String::from("d")]]);
+/// let v: Vec<String> = a.reversed_axes().into_iter().collect();
+/// assert_eq!(v, ["a", "c", "b", "d"]);
+/// ```
+impl<A, D> IntoIterator for Array<A, D>
+    where D: Dimension
+{
+    type Item = A;
+    type IntoIter = IntoIter<A, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        iterators::new_into_iter(self)
+    }
+}
+
+/// Consume the array and yield its elements by value, in logical order.
+///
+/// The buffer is reused if the array is the only owner of its data,
+/// otherwise the elements are cloned.
+impl<A, D> IntoIterator for RcArray<A, D>
+    where D: Dimension,
+          A: Clone,
+{
+    type Item = A;
+    type IntoIter = IntoIter<A, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        match Rc::try_unwrap(self.data) {
+            Ok(data) => {
+                iterators::new_into_iter(ArrayBase {
+                    data: data,
+                    ptr: self.ptr,
+                    dim: self.dim,
+                    strides: self.strides,
+                })
+            }
+            Err(data) => {
+                let a = ArrayBase {
+                    data: data,
+                    ptr: self.ptr,
+                    dim: self.dim,
+                    strides: self.strides,
+                };
+                a.to_owned().into_iter()
+            }
+        }
+    }
+}
+
 impl<'a, A, D> IntoIterator for ArrayView<'a, A, D>
     where D: Dimension
 {
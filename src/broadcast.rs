@@ -0,0 +1,74 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use dimension::IntoDimension;
+use error::{ShapeError, incompatible_shapes};
+
+/// Broadcast arrays together to their common shape.
+///
+/// Return a view of each array in `arrays`, broadcast to the shape that all
+/// of them broadcast to: axes are matched from the back, and along each
+/// axis the lengths must be equal, or one of them 1 (which is then
+/// repeated). Arrays with fewer axes (only possible with `IxDyn`) get
+/// leading axes of length one. No elements are copied; repeated elements
+/// are views with stride zero.
+///
+/// This is the broadcasting that the arithmetic operators do for two
+/// arrays, extended to any number of arrays, for writing elementwise
+/// functions of several arrays.
+///
+/// ***Errors*** if the shapes can not be broadcast together; the error
+/// records the first shape that does not fit the common shape of the arrays
+/// before it.
+///
+/// ```
+/// use ndarray::{arr2, aview2, broadcast_arrays, Zip};
+///
+/// let a = arr2(&[[1., 2., 3.]]);
+/// let b = arr2(&[[10.],
+///                [20.]]);
+/// let c = arr2(&[[0., 0., 0.],
+///                [0., 0., 0.]]);
+/// let views = broadcast_arrays(&[a.view(), b.view(), c.view()]).unwrap();
+/// assert_eq!(views[0], aview2(&[[1., 2., 3.], [1., 2., 3.]]));
+/// assert_eq!(views[1], aview2(&[[10., 10., 10.], [20., 20., 20.]]));
+///
+/// // an elementwise function of three arrays
+/// let mut sum = c.clone();
+/// Zip::from(&mut sum).and(&views[0]).and(&views[1]).and(&views[2])
+///     .apply(|s, &x, &y, &z| *s = x + y + z);
+/// assert_eq!(sum, arr2(&[[11., 12., 13.], [21., 22., 23.]]));
+///
+/// assert!(broadcast_arrays(&[c.view(), c.t()]).is_err());
+/// ```
+pub fn broadcast_arrays<'a, A, D>(arrays: &[ArrayView<'a, A, D>])
+    -> Result<Vec<ArrayView<'a, A, D>>, ShapeError>
+    where D: Dimension,
+{
+    if arrays.is_empty() {
+        return Ok(Vec::new());
+    }
+    let ndim = arrays.iter().map(|a| a.ndim()).max().unwrap();
+    let mut shape = vec![1; ndim];
+    for a in arrays {
+        for (s, &l) in shape.iter_mut().rev().zip(a.shape().iter().rev()) {
+            if *s == 1 {
+                *s = l;
+            } else if l != 1 && l != *s {
+                return Err(incompatible_shapes(&a.raw_dim(), &shape.clone().into_dimension()));
+            }
+        }
+    }
+    let shape = D::from_dimension(&shape.into_dimension()).unwrap();
+    Ok(arrays.iter().map(|a| {
+        let view = a.broadcast(shape.clone()).unwrap();
+        // rebuild the view with the lifetime of `a`'s data
+        unsafe { ArrayView::new_(view.ptr, view.dim, view.strides) }
+    }).collect())
+}
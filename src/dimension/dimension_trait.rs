@@ -13,7 +13,8 @@ use std::ops::{Add, Sub, Mul, AddAssign, SubAssign, MulAssign};
 
 use itertools::{enumerate, zip};
 
-use {Ix, Ixs, Ix0, Ix1, Ix2, Ix3, IxDyn, Dim, Si};
+use {Ix, Ixs, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn, Dim, Si};
+use RemoveAxis;
 use IntoDimension;
 use {ArrayView1, ArrayViewMut1};
 use {zipsl, zipsl_mut, ZipExt};
@@ -62,6 +63,12 @@ pub unsafe trait Dimension : Clone + Eq + Debug + Send + Sync + Default +
     /// - and so on..
     /// - For `Vec<Ix>`: `Vec<usize>`,
     type Pattern: IntoDimension<Dim=Self>;
+    /// Next larger dimension, with one more axis.
+    ///
+    /// - For `Ix1`: `Ix2`
+    /// - and so on..
+    /// - For `Ix6` and `IxDyn`: `IxDyn`
+    type Larger: RemoveAxis;
     #[doc(hidden)]
     fn ndim(&self) -> usize;
 
@@ -180,6 +187,23 @@ pub unsafe trait Dimension : Clone + Eq + Debug + Send + Sync + Default +
         }
     }
 
+    #[doc(hidden)]
+    /// Iteration -- Use self as size, and return the index before `index`
+    /// or None if there is none.
+    #[inline]
+    fn prev_for(&self, index: Self) -> Option<Self> {
+        let mut index = index;
+        for (&dim, ix) in zip(self.slice(), index.slice_mut()).rev() {
+            if *ix == 0 {
+                *ix = dim - 1;
+            } else {
+                *ix -= 1;
+                return Some(index);
+            }
+        }
+        None
+    }
+
     #[doc(hidden)]
     /// Return stride offset for index.
     fn stride_offset(index: &Self, strides: &Self) -> isize {
@@ -298,6 +322,18 @@ pub unsafe trait Dimension : Clone + Eq + Debug + Send + Sync + Default +
         indices.slice_mut().sort_by_key(|&i| strides[i]);
         indices
     }
+
+    /// Convert the dimension `d` into this dimension type, if it has the
+    /// same number of axes.
+    #[doc(hidden)]
+    fn from_dimension<D2: Dimension>(d: &D2) -> Option<Self> {
+        let mut dim = Self::default();
+        if dim.ndim() != d.ndim() {
+            return None;
+        }
+        dim.slice_mut().copy_from_slice(d.slice());
+        Some(dim)
+    }
 }
 
 // utility functions
@@ -318,6 +354,7 @@ fn abs_index(len: Ixs, index: Ixs) -> Ix {
 unsafe impl Dimension for Dim<[Ix; 0]> {
     type SliceArg = [Si; 0];
     type Pattern = ();
+    type Larger = Ix1;
     // empty product is 1 -> size is 1
     #[inline]
     fn ndim(&self) -> usize { 0 }
@@ -339,6 +376,7 @@ unsafe impl Dimension for Dim<[Ix; 0]> {
 unsafe impl Dimension for Dim<[Ix; 1]> {
     type SliceArg = [Si; 1];
     type Pattern = Ix;
+    type Larger = Ix2;
     #[inline]
     fn ndim(&self) -> usize { 1 }
     #[inline]
@@ -408,6 +446,7 @@ unsafe impl Dimension for Dim<[Ix; 1]> {
 unsafe impl Dimension for Dim<[Ix; 2]> {
     type SliceArg = [Si; 2];
     type Pattern = (Ix, Ix);
+    type Larger = Ix3;
     #[inline]
     fn ndim(&self) -> usize { 2 }
     #[inline]
@@ -542,6 +581,7 @@ unsafe impl Dimension for Dim<[Ix; 2]> {
 unsafe impl Dimension for Dim<[Ix; 3]> {
     type SliceArg = [Si; 3];
     type Pattern = (Ix, Ix, Ix);
+    type Larger = Ix4;
     #[inline]
     fn ndim(&self) -> usize { 3 }
     #[inline]
@@ -620,10 +660,11 @@ unsafe impl Dimension for Dim<[Ix; 3]> {
 }
 
 macro_rules! large_dim {
-    ($n:expr, $name:ident, $($ix:ident),+) => (
+    ($n:expr, $name:ident, $larger:ty, $($ix:ident),+) => (
         unsafe impl Dimension for Dim<[Ix; $n]> {
             type SliceArg = [Si; $n];
             type Pattern = ($($ix,)*);
+            type Larger = $larger;
             #[inline]
             fn ndim(&self) -> usize { $n }
             #[inline]
@@ -638,9 +679,9 @@ macro_rules! large_dim {
     )
 }
 
-large_dim!(4, Ix4, Ix, Ix, Ix, Ix);
-large_dim!(5, Ix5, Ix, Ix, Ix, Ix, Ix);
-large_dim!(6, Ix6, Ix, Ix, Ix, Ix, Ix, Ix);
+large_dim!(4, Ix4, Ix5, Ix, Ix, Ix, Ix);
+large_dim!(5, Ix5, Ix6, Ix, Ix, Ix, Ix, Ix);
+large_dim!(6, Ix6, IxDyn, Ix, Ix, Ix, Ix, Ix, Ix);
 
 /// Vec<Ix> is a "dynamic" index, pretty hard to use when indexing,
 /// and memory wasteful, but it allows an arbitrary and dynamic number of axes.
@@ -648,6 +689,7 @@ unsafe impl Dimension for IxDyn
 {
     type SliceArg = [Si];
     type Pattern = Self;
+    type Larger = Self;
     fn ndim(&self) -> usize { self.ix().len() }
     fn slice(&self) -> &[Ix] { self.ix() }
     fn slice_mut(&mut self) -> &mut [Ix] { self.ixm() }
@@ -655,6 +697,9 @@ unsafe impl Dimension for IxDyn
     fn into_pattern(self) -> Self::Pattern {
         self
     }
+    fn from_dimension<D2: Dimension>(d: &D2) -> Option<Self> {
+        Some(d.slice().to_vec().into_dimension())
+    }
 }
 
 impl<J> Index<J> for Dim<Vec<usize>>
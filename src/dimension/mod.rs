@@ -6,7 +6,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use {Ix, Ixs};
+use {Ix, Ixs, Ix1, Si};
 use error::{from_kind, ErrorKind, ShapeError};
 use {zipsl, ZipExt};
 
@@ -136,6 +136,44 @@ fn stride_offset_checked_arithmetic<D>(dim: &D, strides: &D, index: &D)
     Some(offset)
 }
 
+/// Return the indices that `slice` selects from an axis of length `axis_len`,
+/// as the arithmetic progression `(start, step, len)`.
+fn slice_axis_indices(axis_len: Ix, slice: Si) -> (Ixs, Ixs, Ix) {
+    let mut dim = Ix1(axis_len);
+    let mut stride = Ix1(1);
+    let start = Dimension::do_slices(&mut dim, &mut stride, &[slice]);
+    (start, stride[0] as Ixs, dim[0])
+}
+
+/// Return `true` if the two index progressions have an index in common.
+fn indices_intersect(a: (Ixs, Ixs, Ix), b: (Ixs, Ixs, Ix)) -> bool {
+    let (short, long) = if a.2 <= b.2 { (a, b) } else { (b, a) };
+    let (start, step, len) = long;
+    (0..short.2).any(|i| {
+        let diff = short.0 + (i as Ixs) * short.1 - start;
+        diff % step == 0 && diff / step >= 0 && ((diff / step) as Ix) < len
+    })
+}
+
+/// Return `true` if slicing an array of shape `dim` with `a` and with `b`
+/// produces two views that have at least one element in common.
+///
+/// **Panics** if an index is out of bounds or stride is zero.
+pub fn slices_intersect<D: Dimension>(dim: &D, a: &D::SliceArg, b: &D::SliceArg)
+    -> bool
+{
+    let (a, b) = (a.as_ref(), b.as_ref());
+    assert!(a.len() == dim.ndim() && b.len() == dim.ndim());
+    for (&axis_len, &sa, &sb) in zipsl(dim.slice(), a).zip_cons(b) {
+        // the views are disjoint if they are disjoint along any one axis
+        if !indices_intersect(slice_axis_indices(axis_len, sa),
+                              slice_axis_indices(axis_len, sb)) {
+            return false;
+        }
+    }
+    true
+}
+
 /// Stride offset checked general version (slices)
 pub fn stride_offset_checked(dim: &[Ix], strides: &[Ix], index: &[Ix]) -> Option<isize> {
     if index.len() != dim.len() {
@@ -9,13 +9,18 @@ use std::fmt;
 use std::error::Error;
 use super::{
     Dimension,
+    Ix,
 };
 
 /// An error related to array shape or layout.
+///
+/// Errors from shape mismatches also record the two shapes involved, which
+/// are included when the error is displayed.
 #[derive(Clone)]
 pub struct ShapeError {
     // we want to be able to change this representation later
     repr: ErrorKind,
+    shapes: Option<Box<(Vec<Ix>, Vec<Ix>)>>,
 }
 
 impl ShapeError {
@@ -29,6 +34,20 @@ impl ShapeError {
     pub fn from_kind(error: ErrorKind) -> Self {
         from_kind(error)
     }
+
+    /// Return the shape that did not fit, if the error records it: the
+    /// shape of the array being reshaped or broadcast, or of the array
+    /// assigned, appended or stacked with the others.
+    pub fn from_shape(&self) -> Option<&[Ix]> {
+        self.shapes.as_ref().map(|s| &s.0[..])
+    }
+
+    /// Return the shape that the [`from_shape`](#method.from_shape) did not
+    /// fit, if the error records it: the requested shape, the shape of the
+    /// array assigned or appended to, or the shape of the other arrays.
+    pub fn to_shape(&self) -> Option<&[Ix]> {
+        self.shapes.as_ref().map(|s| &s.1[..])
+    }
 }
 
 /// Error code for an error related to array shape or layout.
@@ -54,7 +73,8 @@ pub enum ErrorKind {
 #[inline(always)]
 pub fn from_kind(k: ErrorKind) -> ShapeError {
     ShapeError {
-        repr: k
+        repr: k,
+        shapes: None,
     }
 }
 
@@ -87,19 +107,28 @@ impl Error for ShapeError {
 
 impl fmt::Display for ShapeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ShapeError/{:?}: {}", self.kind(), self.description())
+        try!(write!(f, "ShapeError/{:?}: {}", self.kind(), self.description()));
+        if let Some(ref shapes) = self.shapes {
+            try!(write!(f, " (from {:?} to {:?})", shapes.0, shapes.1));
+        }
+        Ok(())
     }
 }
 
 impl fmt::Debug for ShapeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ShapeError/{:?}: {}", self.kind(), self.description())
+        fmt::Display::fmt(self, f)
     }
 }
 
-pub fn incompatible_shapes<D, E>(_a: &D, _b: &E) -> ShapeError
+/// Return an incompatible shape error recording that shape `from` does not
+/// fit shape `to`.
+pub fn incompatible_shapes<D, E>(from: &D, to: &E) -> ShapeError
     where D: Dimension,
           E: Dimension
 {
-    from_kind(ErrorKind::IncompatibleShape)
+    ShapeError {
+        repr: ErrorKind::IncompatibleShape,
+        shapes: Some(Box::new((from.slice().to_vec(), to.slice().to_vec()))),
+    }
 }
@@ -85,6 +85,76 @@ pub fn aview_mut1<A>(xs: &mut [A]) -> ArrayViewMut1<A> {
     ArrayViewMut::from(xs)
 }
 
+/// Return a view of `a` with at least two axes, as a two-dimensional view.
+///
+/// Axes of length one are inserted in front of the axes of `a` as needed:
+/// a scalar (zero-dimensional) array becomes `1 × 1`, and a vector of
+/// length `n` becomes a `1 × n` row.
+///
+/// **Panics** if `a` has more than two axes.
+///
+/// ```
+/// use ndarray::{arr0, arr1, arr2, atleast_2d};
+///
+/// assert_eq!(atleast_2d(&arr0(1.)), arr2(&[[1.]]));
+/// assert_eq!(atleast_2d(&arr1(&[1., 2.])), arr2(&[[1., 2.]]));
+/// assert_eq!(atleast_2d(&arr1(&[1., 2.]).into_dyn()).dim(), (1, 2));
+/// ```
+pub fn atleast_2d<A, S, D>(a: &ArrayBase<S, D>) -> ArrayView2<A>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    insert_leading_axes("atleast_2d", a)
+}
+
+/// Return a view of `a` with at least three axes, as a three-dimensional
+/// view.
+///
+/// Axes of length one are inserted in front of the axes of `a` as needed:
+/// a vector of length `n` becomes `1 × 1 × n`, and an `m × n` matrix becomes
+/// `1 × m × n`.
+///
+/// **Panics** if `a` has more than three axes.
+///
+/// ```
+/// use ndarray::{arr2, arr3, atleast_3d};
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// assert_eq!(atleast_3d(&a), arr3(&[[[1., 2.],
+///                                    [3., 4.]]]));
+/// ```
+pub fn atleast_3d<A, S, D>(a: &ArrayBase<S, D>) -> ArrayView3<A>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    insert_leading_axes("atleast_3d", a)
+}
+
+/// Return a view of `a` with the dimension type `E`, inserting leading
+/// axes of length one.
+fn insert_leading_axes<'a, A, S, D, E>(name: &str, a: &'a ArrayBase<S, D>) -> ArrayView<'a, A, E>
+    where S: Data<Elem=A>,
+          D: Dimension,
+          E: Dimension,
+{
+    let mut dim = E::default();
+    let mut strides = E::default();
+    let n = dim.ndim();
+    assert!(a.ndim() <= n, "ndarray: {}: array of shape {:?} has more than {} axes",
+            name, a.shape(), n);
+    let k = n - a.ndim();
+    for i in 0..k {
+        dim[i] = 1;
+        strides[i] = 0;
+    }
+    dim.slice_mut()[k..].copy_from_slice(a.shape());
+    for (s, &t) in strides.slice_mut()[k..].iter_mut().zip(a.strides()) {
+        *s = t as Ix;
+    }
+    unsafe { ArrayView::new_(a.as_ptr(), dim, strides) }
+}
+
 /// Fixed-size array used for array initialization
 pub unsafe trait FixedInitializer {
     type Elem;
@@ -8,7 +8,10 @@
 
 
 //! Methods for two-dimensional arrays.
+use std::mem;
+
 use imp_prelude::*;
+use Zip;
 
 impl<A, S> ArrayBase<S, Ix2>
     where S: Data<Elem=A>,
@@ -61,5 +64,62 @@ impl<A, S> ArrayBase<S, Ix2>
     pub fn is_square(&self) -> bool {
         self.rows() == self.cols()
     }
+
+    /// Swap rows `i` and `j` in place.
+    ///
+    /// `i` and `j` may be equal.
+    ///
+    /// **Panics** if `i` or `j` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1, 2],
+    ///                    [3, 4],
+    ///                    [5, 6]]);
+    /// a.swap_rows(0, 2);
+    /// assert_eq!(a, arr2(&[[5, 6],
+    ///                      [3, 4],
+    ///                      [1, 2]]));
+    /// ```
+    pub fn swap_rows(&mut self, i: Ix, j: Ix)
+        where S: DataMut
+    {
+        swap_subviews(self.view_mut(), Axis(0), i, j);
+    }
+
+    /// Swap columns `i` and `j` in place.
+    ///
+    /// `i` and `j` may be equal.
+    ///
+    /// **Panics** if `i` or `j` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1, 2, 3],
+    ///                    [4, 5, 6]]);
+    /// a.swap_columns(2, 0);
+    /// assert_eq!(a, arr2(&[[3, 2, 1],
+    ///                      [6, 5, 4]]));
+    /// ```
+    pub fn swap_columns(&mut self, i: Ix, j: Ix)
+        where S: DataMut
+    {
+        swap_subviews(self.view_mut(), Axis(1), i, j);
+    }
 }
 
+fn swap_subviews<A>(a: ArrayViewMut2<A>, axis: Axis, i: Ix, j: Ix) {
+    let len = a.len_of(axis);
+    assert!(i < len && j < len,
+            "ndarray: swap: indices {} and {} for an axis of length {}", i, j, len);
+    if i == j {
+        return;
+    }
+    let (i, j) = if i < j { (i, j) } else { (j, i) };
+    let (top, bottom) = a.split_at(axis, j);
+    Zip::from(top.into_subview(axis, i))
+        .and(bottom.into_subview(axis, 0))
+        .apply(mem::swap);
+}
@@ -0,0 +1,73 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Elementwise comparisons, producing arrays of `bool`.
+
+use imp_prelude::*;
+use Zip;
+
+macro_rules! compare_methods {
+    ($($mth:ident, $scalar_mth:ident, $trt:ident, $op:tt, $desc:expr;)+) => {
+        $(
+    /// Compare each element with the corresponding element of `rhs` using
+    #[doc=$desc]
+    /// and return the results as an array of `bool`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn $mth<B, S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+        where A: $trt<B>,
+              S2: Data<Elem=B>,
+              E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        let mut res = Array::from_elem(self.raw_dim(), false);
+        Zip::from(&mut res).and(self).and(&rhs).apply(|r, a, b| *r = *a $op *b);
+        res
+    }
+
+    /// Compare each element with the scalar `x` using
+    #[doc=$desc]
+    /// and return the results as an array of `bool`.
+    pub fn $scalar_mth<B>(&self, x: B) -> Array<bool, D>
+        where A: $trt<B>,
+    {
+        self.map(move |a| *a $op x)
+    }
+        )+
+    };
+}
+
+/// # Elementwise Comparisons
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+///
+/// let a = arr2(&[[1, 5],
+///                [7, 3]]);
+/// assert_eq!(a.gt_scalar(4), arr2(&[[false, true],
+///                                   [true, false]]));
+///
+/// // `rhs` is broadcast along the rows of `a`
+/// assert_eq!(a.le(&arr1(&[1, 3])), arr2(&[[true, false],
+///                                         [false, true]]));
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    compare_methods! {
+        eq_elem, eq_scalar, PartialEq, ==, "`==`";
+        ne_elem, ne_scalar, PartialEq, !=, "`!=`";
+        lt, lt_scalar, PartialOrd, <, "`<`";
+        le, le_scalar, PartialOrd, <=, "`<=`";
+        gt, gt_scalar, PartialOrd, >, "`>`";
+        ge, ge_scalar, PartialOrd, >=, "`>=`";
+    }
+}
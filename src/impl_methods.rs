@@ -24,8 +24,11 @@ use dimension::IntoDimension;
 
 use {
     NdIndex,
+    Order,
     AxisChunksIter,
     AxisChunksIterMut,
+    Blocks,
+    BlocksMut,
     Iter,
     IterMut,
     IndexedIter,
@@ -164,12 +167,59 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         self.view_mut().into_iter_()
     }
 
+    /// Return an iterator of references to the elements of the array, in
+    /// column major (“Fortran”) order.
+    ///
+    /// Elements are visited so that the leftmost index is varying the
+    /// fastest. No data is copied; for an array in column major memory
+    /// layout, this is the order the elements are stored in.
+    ///
+    /// Iterator element type is `&A`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// let v = a.iter_f().cloned().collect::<Vec<_>>();
+    /// assert_eq!(v, vec![1, 3, 2, 4]);
+    /// ```
+    pub fn iter_f(&self) -> Iter<A, D> {
+        self.view().reversed_axes().into_iter_()
+    }
+
+    /// Return an iterator of mutable references to the elements of the array,
+    /// in column major (“Fortran”) order.
+    ///
+    /// Elements are visited so that the leftmost index is varying the
+    /// fastest.
+    ///
+    /// Iterator element type is `&mut A`.
+    pub fn iter_mut_f(&mut self) -> IterMut<A, D>
+        where S: DataMut,
+    {
+        self.view_mut().reversed_axes().into_iter_()
+    }
+
     /// Return an iterator of indexes and references to the elements of the array.
     ///
     /// Elements are visited in the *logical order* of the array, which
     /// is where the rightmost index is varying the fastest.
     ///
     /// Iterator element type is `(D::Pattern, &A)`.
+    ///
+    /// The index is relative to `self`, so that `self[index]` is the element
+    /// it is paired with, even for a sliced or strided array.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// let v = a.indexed_iter().collect::<Vec<_>>();
+    /// assert_eq!(v, vec![((0, 0), &1), ((0, 1), &2),
+    ///                    ((1, 0), &3), ((1, 1), &4)]);
+    /// ```
     pub fn indexed_iter(&self) -> IndexedIter<A, D> {
         IndexedIter(self.view().into_elements_base())
     }
@@ -218,6 +268,96 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         arr
     }
 
+    /// Return an iterator of mutable references to the elements of a sliced
+    /// region of the array.
+    ///
+    /// Only the elements inside the slice are visited, in their logical
+    /// order. If the array uniquely owns its data, it is modified in place;
+    /// a shared `RcArray` is made unique first.
+    ///
+    /// See also [`D::SliceArg`].
+    ///
+    /// [`D::SliceArg`]: trait.Dimension.html#associatedtype.SliceArg
+    ///
+    /// **Panics** if an index is out of bounds or stride is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `indexes` does not match the number of array axes.)
+    ///
+    /// ```
+    /// #[macro_use(s)]
+    /// extern crate ndarray;
+    ///
+    /// use ndarray::arr2;
+    ///
+    /// fn main() {
+    ///     let mut a = arr2(&[[1., 1.],
+    ///                        [1., 1.],
+    ///                        [1., 1.]]);
+    ///     // scale every other row
+    ///     for x in a.slice_iter_mut(s![..;2, ..]) {
+    ///         *x *= 2.;
+    ///     }
+    ///     assert_eq!(a, arr2(&[[2., 2.],
+    ///                          [1., 1.],
+    ///                          [2., 2.]]));
+    /// }
+    /// ```
+    pub fn slice_iter_mut(&mut self, indexes: &D::SliceArg) -> IterMut<A, D>
+        where S: DataMut
+    {
+        self.slice_mut(indexes).into_iter_()
+    }
+
+    /// Return several sliced read-write views of the array at once.
+    ///
+    /// Each element of `indexes` is sliced like in
+    /// [`.slice_mut()`](#method.slice_mut), and the views are returned in the
+    /// same order. The slices must be disjoint, which is checked at runtime.
+    ///
+    /// **Panics** if any two of the slices have an element in common.<br>
+    /// **Panics** if an index is out of bounds or stride is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `indexes` does not match the number of array axes.)
+    ///
+    /// ```
+    /// #[macro_use(s)]
+    /// extern crate ndarray;
+    ///
+    /// use ndarray::arr2;
+    ///
+    /// fn main() {
+    ///     let mut a = arr2(&[[1, 2, 3],
+    ///                        [4, 5, 6]]);
+    ///     {
+    ///         let mut views = a.multi_slice_mut(&[s![.., ..1], s![.., 2..]]);
+    ///         let right = views.pop().unwrap();
+    ///         views[0].assign(&right);
+    ///     }
+    ///     assert_eq!(a, arr2(&[[3, 2, 3],
+    ///                          [6, 5, 6]]));
+    /// }
+    /// ```
+    pub fn multi_slice_mut(&mut self, indexes: &[&D::SliceArg])
+        -> Vec<ArrayViewMut<A, D>>
+        where S: DataMut
+    {
+        for (i, a) in indexes.iter().enumerate() {
+            for b in &indexes[i + 1..] {
+                if dimension::slices_intersect(&self.dim, a, b) {
+                    panic!("ndarray: multi_slice_mut: slices {:?} and {:?} overlap",
+                           a.as_ref(), b.as_ref());
+                }
+            }
+        }
+        let view = self.view_mut();
+        indexes.iter().map(|indexes| {
+            // the views are disjoint, so they don't alias each other
+            let mut arr = unsafe {
+                ArrayViewMut::new_(view.ptr, view.dim.clone(), view.strides.clone())
+            };
+            arr.islice(indexes);
+            arr
+        }).collect()
+    }
+
     /// Slice the array’s view in place.
     ///
     /// See also [`D::SliceArg`].
@@ -309,6 +449,16 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// Indices may be equal.
     ///
     /// ***Panics*** if an index is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1, 2],
+    ///                    [3, 4]]);
+    /// a.swap((0, 1), (1, 0));
+    /// assert_eq!(a, arr2(&[[1, 3],
+    ///                      [2, 4]]));
+    /// ```
     pub fn swap<I>(&mut self, index1: I, index2: I)
         where S: DataMut,
               I: NdIndex<D>,
@@ -379,6 +529,9 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// Along `axis`, select the subview `index` and return a read-write view
     /// with the axis removed.
     ///
+    /// The data is made unique (copy on write for `RcArray`) before the view
+    /// is created, so only `self` is modified through the subview.
+    ///
     /// **Panics** if `axis` or `index` is out of bounds.
     ///
     /// ```
@@ -436,6 +589,44 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         }
     }
 
+    /// Insert a new axis of length one at `axis`, and return `self` with
+    /// the larger dimension type.
+    ///
+    /// This is the inverse of selecting a subview with
+    /// [`.into_subview()`](#method.into_subview); no elements are moved.
+    ///
+    /// **Panics** if `axis` is greater than the number of axes.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[1, 2, 3]);
+    /// assert_eq!(a.view().insert_axis(Axis(0)), arr2(&[[1, 2, 3]]));
+    /// assert_eq!(a.insert_axis(Axis(1)), arr2(&[[1], [2], [3]]));
+    /// ```
+    pub fn insert_axis(self, axis: Axis) -> ArrayBase<S, D::Larger> {
+        let axis = axis.axis();
+        assert!(axis <= self.ndim(), "ndarray: insert_axis: axis {} for an array of shape {:?}",
+                axis, self.shape());
+        // keep the memory order: the new axis steps over the axis it is
+        // placed in front of
+        let stride = if axis < self.ndim() {
+            self.dim[axis] as Ixs * self.strides[axis] as Ixs
+        } else {
+            1
+        };
+        let mut dim = self.dim.slice().to_vec();
+        let mut strides = self.strides.slice().to_vec();
+        dim.insert(axis, 1);
+        strides.insert(axis, stride as Ix);
+        ArrayBase {
+            data: self.data,
+            ptr: self.ptr,
+            dim: D::Larger::from_dimension(&dim.into_dimension()).unwrap(),
+            strides: D::Larger::from_dimension(&strides.into_dimension()).unwrap(),
+        }
+    }
+
     /// Along `axis`, select arbitrary subviews corresponding to `indices`
     /// and and copy them into a new array.
     ///
@@ -508,6 +699,44 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         iterators::new_inner_iter_mut(self.view_mut())
     }
 
+    /// Return an iterator that yields each one-dimensional *lane* along
+    /// `axis`: the 1D views where the index along `axis` varies and all
+    /// other indices are fixed.
+    ///
+    /// For example, in a 2 × 3 × 4 array with `axis` equal to `Axis(1)`,
+    /// the iterator element is a lane of 3 elements (and there are
+    /// 2 × 4 = 8 lanes in total). `.inner_iter()` yields the lanes along
+    /// the last axis.
+    ///
+    /// Iterator element is `ArrayView1<A>` (1D array view).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[0, 1, 2],
+    ///                [3, 4, 5]]);
+    /// // The lanes along axis 0 are the columns
+    /// let mut column_sums = a.lanes(Axis(0)).map(|v| v.scalar_sum());
+    /// assert_eq!(column_sums.collect::<Vec<_>>(), vec![3, 5, 7]);
+    /// ```
+    pub fn lanes(&self, axis: Axis) -> InnerIter<A, D> {
+        iterators::new_lanes(self.view(), axis)
+    }
+
+    /// Return an iterator that yields each one-dimensional *lane* along
+    /// `axis`.
+    ///
+    /// Iterator element is `ArrayViewMut1<A>` (1D read-write array view).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn lanes_mut(&mut self, axis: Axis) -> InnerIterMut<A, D>
+        where S: DataMut
+    {
+        iterators::new_lanes_mut(self.view_mut(), axis)
+    }
+
     /// Return an iterator that traverses over the outermost dimension
     /// and yields each subview.
     ///
@@ -579,6 +808,19 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// (read-write array view).
     ///
     /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1, 2, 3],
+    ///                    [4, 5, 6]]);
+    /// // scale each column by its index
+    /// for (i, mut column) in a.axis_iter_mut(Axis(1)).enumerate() {
+    ///     column *= i as i32;
+    /// }
+    /// assert_eq!(a, arr2(&[[0, 2,  6],
+    ///                      [0, 5, 12]]));
+    /// ```
     pub fn axis_iter_mut(&mut self, axis: Axis) -> AxisIterMut<A, D::Smaller>
         where S: DataMut,
               D: RemoveAxis,
@@ -630,6 +872,55 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         iterators::new_chunk_iter_mut(self.view_mut(), axis.axis(), size)
     }
 
+    /// Return an iterator over the array in blocks (tiles) of shape
+    /// `block`, yielding a view of each block.
+    ///
+    /// Iterator element is `ArrayView<A, D>`
+    ///
+    /// The blocks are non-overlapping and cover the whole array; they are
+    /// visited in the logical order of their position. A block at the end
+    /// of an axis is shorter if the block length does not divide the axis
+    /// length.
+    ///
+    /// Processing a large strided or transposed array block by block,
+    /// with blocks that fit in cache, keeps the memory accesses local.
+    ///
+    /// **Panics** if `block` has a zero length, or if its dimensionality
+    /// does not match the array’s.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a = Array2::from_shape_fn((3, 5), |(i, j)| 10 * i + j);
+    /// let mut blocks = a.blocks((2, 2));
+    /// assert_eq!(blocks.len(), 6);
+    /// assert_eq!(blocks.next().unwrap(), arr2(&[[ 0,  1],
+    ///                                           [10, 11]]));
+    /// // the last block is 1 × 1
+    /// assert_eq!(blocks.last().unwrap(), arr2(&[[24]]));
+    /// ```
+    pub fn blocks<E>(&self, block: E) -> Blocks<A, D>
+        where E: IntoDimension<Dim=D>,
+    {
+        iterators::new_blocks(self.view(), block.into_dimension())
+    }
+
+    /// Return an iterator over the array in blocks (tiles) of shape
+    /// `block`, yielding a read-write view of each block.
+    ///
+    /// Iterator element is `ArrayViewMut<A, D>`
+    ///
+    /// See [`.blocks()`](#method.blocks) for more information.
+    ///
+    /// **Panics** if `block` has a zero length, or if its dimensionality
+    /// does not match the array’s.
+    pub fn blocks_mut<E>(&mut self, block: E) -> BlocksMut<A, D>
+        where E: IntoDimension<Dim=D>,
+              S: DataMut,
+    {
+        iterators::new_blocks_mut(self.view_mut(), block.into_dimension())
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
@@ -819,7 +1110,7 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
                 strides: shape.default_strides(),
                 dim: shape,
             })
-        } else if self.ndim() > 1 && self.view().reversed_axes().is_standard_layout() {
+        } else if self.ndim() > 1 && self.t().is_standard_layout() {
             Ok(ArrayBase {
                 data: self.data,
                 ptr: self.ptr,
@@ -831,8 +1122,8 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         }
     }
 
-    /// *Note: Reshape is for `RcArray` only. Use `.into_shape()` for
-    /// other arrays and array views.*
+    /// *Note: Reshape is for `RcArray` only. Use `.into_reshape()` for
+    /// other owned arrays, and `.into_shape()` for array views.*
     ///
     /// Transform the array into `shape`; any shape with the same number of
     /// elements is accepted.
@@ -840,7 +1131,8 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// May clone all elements if needed to arrange elements in standard
     /// layout (and break sharing).
     ///
-    /// **Panics** if shapes are incompatible.
+    /// **Panics** if shapes are incompatible; see
+    /// [`.try_reshape()`](#method.try_reshape) for a checked version.
     ///
     /// ```
     /// use ndarray::{rcarr1, rcarr2};
@@ -855,30 +1147,208 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         where S: DataShared + DataOwned,
               A: Clone,
               E: IntoDimension,
+    {
+        match self.try_reshape(shape) {
+            Ok(a) => a,
+            Err(e) => panic!("ndarray: reshape: {}", e),
+        }
+    }
+
+    /// *Note: Reshape is for `RcArray` only.*
+    ///
+    /// Transform the array into `shape` like
+    /// [`.reshape()`](#method.reshape), but return an error instead of
+    /// panicking.
+    ///
+    /// **Errors** if the shapes don't have the same number of elements; the
+    /// error records both shapes.
+    ///
+    /// ```
+    /// use ndarray::{rcarr1, ErrorKind};
+    ///
+    /// let a = rcarr1(&[1., 2., 3., 4.]);
+    /// assert!(a.try_reshape((2, 2)).is_ok());
+    ///
+    /// let err = a.try_reshape((3, 2)).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    /// assert_eq!(err.from_shape(), Some(&[4][..]));
+    /// assert_eq!(err.to_shape(), Some(&[3, 2][..]));
+    /// ```
+    pub fn try_reshape<E>(&self, shape: E) -> Result<ArrayBase<S, E::Dim>, ShapeError>
+        where S: DataShared + DataOwned,
+              A: Clone,
+              E: IntoDimension,
     {
         let shape = shape.into_dimension();
         if shape.size_checked() != Some(self.dim.size()) {
-            panic!("ndarray: incompatible shapes in reshape, attempted from: {:?}, to: {:?}",
-                   self.dim.slice(),
-                   shape.slice())
+            return Err(error::incompatible_shapes(&self.dim, &shape));
         }
         // Check if contiguous, if not => copy all, else just adapt strides
         if self.is_standard_layout() {
             let cl = self.clone();
-            ArrayBase {
+            Ok(ArrayBase {
                 data: cl.data,
                 ptr: cl.ptr,
                 strides: shape.default_strides(),
                 dim: shape,
-            }
+            })
         } else {
             let v = self.iter().map(|x| x.clone()).collect::<Vec<A>>();
+            unsafe {
+                Ok(ArrayBase::from_shape_vec_unchecked(shape, v))
+            }
+        }
+    }
+
+    /// Transform the owned array into `shape`, consuming it; any shape with
+    /// the same number of elements is accepted.
+    ///
+    /// If the array is in standard layout, the elements are not touched:
+    /// the data is reused with the new shape (for an `RcArray`, it stays
+    /// shared). Otherwise the elements are cloned into a new array in
+    /// standard layout, in logical order.
+    ///
+    /// **Panics** if shapes are incompatible.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// let a = Array::range(0., 6., 1.);
+    /// let ptr = a.as_ptr();
+    /// let b = a.into_reshape((2, 3));
+    /// assert_eq!(b, arr2(&[[0., 1., 2.],
+    ///                      [3., 4., 5.]]));
+    /// // no copy was made
+    /// assert_eq!(b.as_ptr(), ptr);
+    ///
+    /// // the transpose is not in standard layout, so it is copied
+    /// assert_eq!(b.reversed_axes().into_reshape(6),
+    ///            Array::from_vec(vec![0., 3., 1., 4., 2., 5.]));
+    /// ```
+    pub fn into_reshape<E>(self, shape: E) -> ArrayBase<S, E::Dim>
+        where S: DataOwned,
+              A: Clone,
+              E: IntoDimension,
+    {
+        let shape = shape.into_dimension();
+        if shape.size_checked() != Some(self.dim.size()) {
+            panic!("ndarray: incompatible shapes in into_reshape, attempted from: {:?}, to: {:?}",
+                   self.dim.slice(),
+                   shape.slice())
+        }
+        if self.is_standard_layout() {
+            ArrayBase {
+                data: self.data,
+                ptr: self.ptr,
+                strides: shape.default_strides(),
+                dim: shape,
+            }
+        } else {
+            let v = self.iter().cloned().collect::<Vec<A>>();
             unsafe {
                 ArrayBase::from_shape_vec_unchecked(shape, v)
             }
         }
     }
 
+    /// Flatten the owned array into a one-dimensional array, consuming it,
+    /// with the elements in `order`.
+    ///
+    /// If the array is already laid out contiguously in `order` (in standard
+    /// layout for `Order::RowMajor`, or in Fortran layout for
+    /// `Order::ColumnMajor`), the data is reused without touching the
+    /// elements. Otherwise the elements are cloned into a new array.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Order};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.clone().ravel(Order::RowMajor), arr1(&[1, 2, 3, 4, 5, 6]));
+    /// assert_eq!(a.ravel(Order::ColumnMajor), arr1(&[1, 4, 2, 5, 3, 6]));
+    /// ```
+    pub fn ravel(self, order: Order) -> ArrayBase<S, Ix1>
+        where S: DataOwned,
+              A: Clone,
+    {
+        let len = self.len();
+        let contiguous = match order {
+            Order::RowMajor => self.is_standard_layout(),
+            Order::ColumnMajor => self.t().is_standard_layout(),
+        };
+        if contiguous {
+            ArrayBase {
+                data: self.data,
+                ptr: self.ptr,
+                strides: Ix1(1),
+                dim: Ix1(len),
+            }
+        } else {
+            let v = match order {
+                Order::RowMajor => self.iter().cloned().collect::<Vec<A>>(),
+                Order::ColumnMajor => self.t().iter().cloned().collect::<Vec<A>>(),
+            };
+            ArrayBase::from_vec(v)
+        }
+    }
+
+    /// Convert an array or array view into one with dynamic dimensions
+    /// (`IxDyn`), keeping its shape, strides and elements.
+    ///
+    /// No elements are copied. See
+    /// [`.into_dimensionality()`](#method.into_dimensionality) for the
+    /// conversion back.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// let d = a.view().reversed_axes().into_dyn();
+    /// assert_eq!(d.shape(), &[2, 2]);
+    /// assert_eq!(d[[0, 1]], 3);
+    /// ```
+    pub fn into_dyn(self) -> ArrayBase<S, IxDyn> {
+        ArrayBase {
+            data: self.data,
+            ptr: self.ptr,
+            dim: self.dim.slice().to_vec().into_dimension(),
+            strides: self.strides.slice().to_vec().into_dimension(),
+        }
+    }
+
+    /// Convert an array or array view into one with the dimension type `D2`,
+    /// keeping its shape, strides and elements.
+    ///
+    /// This is the inverse of [`.into_dyn()`](#method.into_dyn): an array
+    /// with dynamic dimensions can be given back its fixed dimension type,
+    /// for use with functions that require it. No elements are copied.
+    ///
+    /// **Errors** if the array does not have as many axes as `D2`.
+    ///
+    /// ```
+    /// use ndarray::{ArrayD, Ix2, Ix3};
+    ///
+    /// let a = ArrayD::<f64>::zeros(vec![2, 3]);
+    /// let b = a.into_dimensionality::<Ix2>().unwrap();
+    /// assert_eq!(b.dim(), (2, 3));
+    ///
+    /// assert!(b.into_dyn().into_dimensionality::<Ix3>().is_err());
+    /// ```
+    pub fn into_dimensionality<D2>(self) -> Result<ArrayBase<S, D2>, ShapeError>
+        where D2: Dimension
+    {
+        match (D2::from_dimension(&self.dim), D2::from_dimension(&self.strides)) {
+            (Some(dim), Some(strides)) => Ok(ArrayBase {
+                data: self.data,
+                ptr: self.ptr,
+                dim: dim,
+                strides: strides,
+            }),
+            _ => Err(error::from_kind(error::ErrorKind::IncompatibleShape)),
+        }
+    }
+
     /// Act like a larger size and/or shape array by *broadcasting*
     /// into a larger shape, if possible.
     ///
@@ -962,6 +1432,33 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         unsafe { Some(ArrayView::new_(self.ptr, dim, broadcast_strides)) }
     }
 
+    /// Broadcast into a larger shape like
+    /// [`.broadcast()`](#method.broadcast), but return an error instead of
+    /// `None`.
+    ///
+    /// **Errors** if the shapes can not be broadcast together; the error
+    /// records the shape of `self` and the requested shape.
+    ///
+    /// ```
+    /// use ndarray::{arr1, ErrorKind};
+    ///
+    /// let a = arr1(&[1., 2.]);
+    /// assert_eq!(a.try_broadcast((3, 2)).unwrap().shape(), &[3, 2]);
+    ///
+    /// let err = a.try_broadcast((2, 3)).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    /// assert_eq!(err.to_shape(), Some(&[2, 3][..]));
+    /// ```
+    pub fn try_broadcast<E>(&self, dim: E) -> Result<ArrayView<A, E::Dim>, ShapeError>
+        where E: IntoDimension
+    {
+        let dim = dim.into_dimension();
+        match self.broadcast(dim.clone()) {
+            Some(view) => Ok(view),
+            None => Err(error::incompatible_shapes(&self.dim, &dim)),
+        }
+    }
+
     /// Swap axes `ax` and `bx`.
     ///
     /// This does not move any data, it just adjusts the array’s dimensions
@@ -998,6 +1495,18 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// This is a shorthand for `self.view().reversed_axes()`.
     ///
     /// See also the more general methods `.reversed_axes()` and `.swap_axes()`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.]]);
+    /// assert_eq!(a.t().dim(), (2, 3));
+    /// // the normal matrix aᵀ a, without a mutable temporary
+    /// assert_eq!(a.t().dot(&a), arr2(&[[35., 44.],
+    ///                                  [44., 56.]]));
+    /// ```
     pub fn t(&self) -> ArrayView<A, D> {
         self.view().reversed_axes()
     }
@@ -1028,6 +1537,30 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         self.zip_mut_with(rhs, |x, y| *x = y.clone());
     }
 
+    /// Perform an elementwise assigment to `self` from `rhs` like
+    /// [`.assign()`](#method.assign), but return an error instead of
+    /// panicking.
+    ///
+    /// **Errors** if `rhs` can not be broadcast to the shape of `self`; the
+    /// error records the shape of `rhs` and the shape of `self`, and `self`
+    /// is left unchanged.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array};
+    ///
+    /// let mut a = Array::zeros((2, 3));
+    /// assert!(a.try_assign(&arr1(&[1., 2., 3.])).is_ok());
+    /// assert_eq!(a.row(1), arr1(&[1., 2., 3.]));
+    /// assert!(a.try_assign(&arr1(&[1., 2.])).is_err());
+    /// ```
+    pub fn try_assign<E: Dimension, S2>(&mut self, rhs: &ArrayBase<S2, E>) -> Result<(), ShapeError>
+        where S: DataMut,
+              A: Clone,
+              S2: Data<Elem=A>,
+    {
+        self.try_zip_mut_with(rhs, |x, y| *x = y.clone())
+    }
+
     /// Perform an elementwise assigment to `self` from scalar `x`.
     #[deprecated(note="This method is replaced with ArrayBase::fill and will be removed.")]
     pub fn assign_scalar(&mut self, x: &A)
@@ -1123,6 +1656,23 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
     /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1., 2.],
+    ///                    [3., 4.]]);
+    /// let b = arr2(&[[1., 0.],
+    ///                [0., 1.]]);
+    /// a.zip_mut_with(&b, |x, &y| *x = f64::max(*x, y * 2.));
+    /// assert_eq!(a, arr2(&[[2., 2.],
+    ///                      [3., 4.]]));
+    ///
+    /// // `rhs` is broadcast along the rows of `a`
+    /// a.zip_mut_with(&arr1(&[10., 20.]), |x, &y| *x += y);
+    /// assert_eq!(a, arr2(&[[12., 22.],
+    ///                      [13., 24.]]));
+    /// ```
     #[inline]
     pub fn zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F)
         where S: DataMut,
@@ -1141,6 +1691,47 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         }
     }
 
+    /// Traverse two arrays in unspecified order, in lock step, applying the
+    /// closure `f` on each element pair, like
+    /// [`.zip_mut_with()`](#method.zip_mut_with), but return an error
+    /// instead of panicking.
+    ///
+    /// This is the checked form of the in place arithmetic operators; for
+    /// example `a += &b` is `a.try_zip_mut_with(&b, |x, &y| *x += y)`.
+    ///
+    /// **Errors** if `rhs` can not be broadcast to the shape of `self`; the
+    /// error records the shape of `rhs` and the shape of `self`, and `f` is
+    /// not called.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1., 2.],
+    ///                    [3., 4.]]);
+    /// assert!(a.try_zip_mut_with(&arr1(&[10., 20.]), |x, &y| *x += y).is_ok());
+    /// assert_eq!(a, arr2(&[[11., 22.],
+    ///                      [13., 24.]]));
+    ///
+    /// let err = a.try_zip_mut_with(&arr1(&[1., 2., 3.]), |x, &y| *x += y).unwrap_err();
+    /// assert_eq!(err.from_shape(), Some(&[3][..]));
+    /// assert_eq!(err.to_shape(), Some(&[2, 2][..]));
+    /// ```
+    pub fn try_zip_mut_with<B, S2, E, F>(&mut self, rhs: &ArrayBase<S2, E>, f: F)
+        -> Result<(), ShapeError>
+        where S: DataMut,
+              S2: Data<Elem=B>,
+              E: Dimension,
+              F: FnMut(&mut A, &B)
+    {
+        if rhs.dim.ndim() == 0 || self.shape() == rhs.shape() {
+            self.zip_mut_with(rhs, f);
+        } else {
+            let rhs_broadcast = try!(rhs.try_broadcast(self.raw_dim()));
+            self.zip_mut_with_by_rows(&rhs_broadcast, f);
+        }
+        Ok(())
+    }
+
     /// Traverse the array elements and apply a fold,
     /// returning the resulting value.
     ///
@@ -1211,8 +1802,8 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     ///                                [1., 2.]])
     /// );
     /// ```
-    pub fn mapv<B, F>(&self, f: F) -> Array<B, D>
-        where F: Fn(A) -> B,
+    pub fn mapv<B, F>(&self, mut f: F) -> Array<B, D>
+        where F: FnMut(A) -> B,
               A: Clone,
     {
         self.map(move |x| f(x.clone()))
@@ -1221,10 +1812,23 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// Call `f` by **v**alue on each element, update the array with the new values
     /// and return it.
     ///
+    /// This reuses the array’s own storage, so for an `Array` no new
+    /// allocation is made.
+    ///
     /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1., 2., 3.]);
+    /// let ptr = a.as_ptr();
+    /// let b = a.mapv_into(|x| x * x);
+    /// assert_eq!(b, arr1(&[1., 4., 9.]));
+    /// assert_eq!(b.as_ptr(), ptr);
+    /// ```
     pub fn mapv_into<F>(mut self, f: F) -> Self
         where S: DataMut,
-              F: Fn(A) -> A,
+              F: FnMut(A) -> A,
               A: Clone,
     {
         self.mapv_inplace(f);
@@ -1236,7 +1840,7 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     /// Elements are visited in arbitrary order.
     pub fn map_inplace<F>(&mut self, f: F)
         where S: DataMut,
-              F: Fn(&mut A),
+              F: FnMut(&mut A),
     {
         self.unordered_foreach_mut(f);
     }
@@ -1257,9 +1861,9 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
     ///                         [0.36788, 7.38906]]), 1e-5)
     /// );
     /// ```
-    pub fn mapv_inplace<F>(&mut self, f: F)
+    pub fn mapv_inplace<F>(&mut self, mut f: F)
         where S: DataMut,
-              F: Fn(A) -> A,
+              F: FnMut(A) -> A,
               A: Clone,
     {
         self.unordered_foreach_mut(move |x| *x = f(x.clone()));
@@ -1285,12 +1889,152 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
         }
     }
 
+    /// Return `true` if `predicate` returns `true` for all elements of the
+    /// array, and `false` otherwise.
+    ///
+    /// Stops at the first element for which `predicate` returns `false`.
+    /// An empty array returns `true`.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert!(a.all(|&x| x > 0.));
+    /// assert!(!a.all(|&x| x > 1.));
+    /// ```
+    pub fn all<F>(&self, mut predicate: F) -> bool
+        where F: FnMut(&A) -> bool,
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return slc.iter().all(predicate);
+        }
+        for row in self.inner_iter() {
+            if !row.into_iter_().all(&mut predicate) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Return `true` if `predicate` returns `true` for any element of the
+    /// array, and `false` otherwise.
+    ///
+    /// Stops at the first element for which `predicate` returns `true`.
+    /// An empty array returns `false`.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert!(a.any(|&x| x == 4.));
+    /// assert!(!a.any(|&x| x < 0.));
+    /// ```
+    pub fn any<F>(&self, mut predicate: F) -> bool
+        where F: FnMut(&A) -> bool,
+    {
+        !self.all(move |x| !predicate(x))
+    }
+
+    /// Return the indices of the elements for which `predicate` returns
+    /// `true`, as one index array per axis.
+    ///
+    /// The indices are in the *logical order* of the array; element `k` of
+    /// each index array belongs to the `k`th matching element.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// let index = a.indices_where(|&x| x > 3);
+    /// assert_eq!(index, vec![arr1(&[0, 1, 1]), arr1(&[1, 0, 2])]);
+    /// ```
+    pub fn indices_where<F>(&self, mut predicate: F) -> Vec<Array1<Ix>>
+        where F: FnMut(&A) -> bool,
+    {
+        let mut index = vec![Vec::new(); self.ndim()];
+        for (i, x) in self.indexed_iter() {
+            if predicate(x) {
+                for (v, &ix) in zip(&mut index, i.into_dimension().slice()) {
+                    v.push(ix);
+                }
+            }
+        }
+        index.into_iter().map(Array1::from_vec).collect()
+    }
+
+    /// Return `true` for each lane along `axis` where `predicate` returns
+    /// `true` for all elements, `false` otherwise.
+    ///
+    /// Return the result as an `Array`, with `axis` removed. An empty lane
+    /// gives `true`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., -4.]]);
+    /// assert_eq!(a.all_axis(Axis(1), |&x| x > 0.), arr1(&[true, false]));
+    /// assert_eq!(a.all_axis(Axis(0), |&x| x > 0.), arr1(&[true, false]));
+    /// ```
+    pub fn all_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<bool, D::Smaller>
+        where D: RemoveAxis,
+              F: FnMut(&A) -> bool,
+    {
+        self.fold_axis(axis, true, move |&acc, x| acc && predicate(x))
+    }
+
+    /// Return `true` for each lane along `axis` where `predicate` returns
+    /// `true` for any element, `false` otherwise.
+    ///
+    /// Return the result as an `Array`, with `axis` removed. An empty lane
+    /// gives `false`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    /// use std::f64;
+    ///
+    /// // which rows contain NaN
+    /// let a = arr2(&[[1., f64::NAN],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.any_axis(Axis(1), |x| x.is_nan()), arr1(&[true, false]));
+    /// ```
+    pub fn any_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<bool, D::Smaller>
+        where D: RemoveAxis,
+              F: FnMut(&A) -> bool,
+    {
+        self.fold_axis(axis, false, move |&acc, x| acc || predicate(x))
+    }
+
     /// Fold along an axis.
     ///
     /// Combine the elements of each subview with the previous using the `fold`
     /// function and initial value `init`.
     ///
-    /// Return the result as an `Array`.
+    /// Return the result as an `Array`, with `axis` removed.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 5., 3.],
+    ///                [4., 2., 6.]]);
+    ///
+    /// // count the elements greater than 2 in each row
+    /// let count = a.fold_axis(Axis(1), 0, |&n, &x| if x > 2. { n + 1 } else { n });
+    /// assert_eq!(count, arr1(&[2, 2]));
+    /// ```
     pub fn fold_axis<B, F>(&self, axis: Axis, init: B, mut fold: F)
         -> Array<B, D::Smaller>
         where D: RemoveAxis,
@@ -1328,4 +2072,78 @@ impl<A, S, D> ArrayBase<S, D> where S: Data<Elem=A>, D: Dimension
             }
         })
     }
+
+    /// Apply `mapping` to each one-dimensional lane along `axis`, where it
+    /// produces a new lane, and collect the new lanes into an array.
+    ///
+    /// The result has the same shape as `self`, except along `axis` where
+    /// its length is the length of the lanes returned by `mapping`. (If
+    /// there are no lanes, its length along `axis` is zero.)
+    /// See [`.map_axis()`](#method.map_axis) for a mapping from each lane to
+    /// a single value.
+    ///
+    /// Lanes are visited in the same order as by [`.lanes()`](#method.lanes).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `mapping` returns lanes of
+    /// different lengths.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// // cumulative sums down each column
+    /// let b = a.apply_along_axis(Axis(0), |col| {
+    ///     let mut acc = 0.;
+    ///     col.map(|&x| { acc += x; acc })
+    /// });
+    /// assert_eq!(b, arr2(&[[1., 2.],
+    ///                      [4., 6.]]));
+    ///
+    /// // a lane can change length
+    /// let c = a.apply_along_axis(Axis(1), |row| arr1(&[row.scalar_sum()]));
+    /// assert_eq!(c, arr2(&[[3.],
+    ///                      [7.]]));
+    /// ```
+    pub fn apply_along_axis<'a, B, F>(&'a self, axis: Axis, mut mapping: F)
+        -> Array<B, D>
+        where F: FnMut(ArrayView1<'a, A>) -> Array1<B>,
+              A: 'a,
+    {
+        let mut dim = self.raw_dim();
+        let mut lane_len = None;
+        let mut v = Vec::new();
+        for lane in self.lanes(axis) {
+            let new_lane = mapping(lane);
+            match lane_len {
+                None => {
+                    lane_len = Some(new_lane.len());
+                    v.reserve(new_lane.len() * (self.len() / dim[axis.axis()].max(1)));
+                }
+                Some(len) => assert_eq!(len, new_lane.len(),
+                                        "ndarray: apply_along_axis: lanes of \
+                                         different lengths"),
+            }
+            v.extend(new_lane);
+        }
+        let lane_len = lane_len.unwrap_or(0);
+        // The new lanes are stored one after another, so `axis` has unit
+        // stride and the other axes are in row major order.
+        let mut strides = {
+            let mut outer = dim.clone();
+            outer.set_axis(axis, 1);
+            outer.default_strides()
+        };
+        for s in strides.slice_mut() {
+            *s *= lane_len;
+        }
+        strides.set_axis(axis, 1);
+        dim.set_axis(axis, lane_len);
+        if v.is_empty() {
+            strides = dim.default_strides();
+        }
+        unsafe {
+            ArrayBase::from_shape_vec_unchecked(dim.strides(strides), v)
+        }
+    }
 }
@@ -29,6 +29,18 @@ use num_complex::Complex;
 /// This trait ***does not*** limit which elements can be stored in an array in general.
 /// Non-`ScalarOperand` types can still participate in arithmetic as array elements in
 /// in array-array operations.
+///
+/// The in place operations `C @= K` modify the elements directly, without
+/// allocating a new array (an `RcArray` is first made unique, if it is shared).
+///
+/// ```
+/// use ndarray::arr1;
+///
+/// let mut a = arr1(&[3., 4.]);
+/// let norm = f64::sqrt(a.dot(&a));
+/// a /= norm;
+/// assert_eq!(a, arr1(&[0.6, 0.8]));
+/// ```
 pub trait ScalarOperand : 'static + Clone { }
 impl ScalarOperand for bool { }
 impl ScalarOperand for i8 { }
@@ -102,7 +114,8 @@ impl<'a, A, S, S2, D, E> $trt<&'a ArrayBase<S2, E>> for ArrayBase<S, D>
 /// between references `self` and `rhs`,
 /// and return the result as a new `Array`.
 ///
-/// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+/// If their shapes disagree, `self` and `rhs` are broadcast together to a
+/// common shape, which must have the same number of axes as `self`.
 ///
 /// **Panics** if broadcasting isn’t possible.
 impl<'a, 'b, A, S, S2, D, E> $trt<&'a ArrayBase<S2, E>> for &'b ArrayBase<S, D>
@@ -114,8 +127,12 @@ impl<'a, 'b, A, S, S2, D, E> $trt<&'a ArrayBase<S2, E>> for &'b ArrayBase<S, D>
 {
     type Output = Array<A, D>;
     fn $mth(self, rhs: &'a ArrayBase<S2, E>) -> Array<A, D> {
-        // FIXME: Can we co-broadcast arrays here? And how?
-        self.to_owned().$mth(rhs)
+        let shape = co_broadcast(&self.raw_dim(), &rhs.raw_dim());
+        if shape.slice() == self.shape() {
+            self.to_owned().$mth(rhs)
+        } else {
+            self.broadcast_unwrap(shape).to_owned().$mth(rhs)
+        }
     }
 }
 
@@ -217,6 +234,37 @@ mod arithmetic_ops {
     use std::ops::*;
     use num_complex::Complex;
 
+    /// Return the shape that arrays of shapes `a` and `b` broadcast to together.
+    ///
+    /// Axes are matched from the back; each pair must be equal or one of
+    /// them 1. `b` may have fewer axes than `a` but not more.
+    ///
+    /// **Panics** if the shapes can not be broadcast together.
+    fn co_broadcast<D, E>(a: &D, b: &E) -> D
+        where D: Dimension,
+              E: Dimension,
+    {
+        #[cold]
+        #[inline(never)]
+        fn co_broadcast_panic(a: &[Ix], b: &[Ix]) -> ! {
+            panic!("ndarray: could not broadcast arrays of shapes {:?} and {:?} together",
+                   a, b)
+        }
+
+        if b.ndim() > a.ndim() {
+            co_broadcast_panic(a.slice(), b.slice());
+        }
+        let mut shape = a.clone();
+        for (s, &l) in shape.slice_mut().iter_mut().rev().zip(b.slice().iter().rev()) {
+            if *s == 1 {
+                *s = l;
+            } else if l != 1 && l != *s {
+                co_broadcast_panic(a.slice(), b.slice());
+            }
+        }
+        shape
+    }
+
     impl_binary_op!(Add, +, add, +=, "addition");
     impl_binary_op!(Sub, -, sub, -=, "subtraction");
     impl_binary_op!(Mul, *, mul, *=, "multiplication");
@@ -250,6 +298,8 @@ mod arithmetic_ops {
     all_scalar_ops!(u32);
     all_scalar_ops!(i64);
     all_scalar_ops!(u64);
+    all_scalar_ops!(isize);
+    all_scalar_ops!(usize);
 
     impl_scalar_lhs_op!(bool, Commute, &, BitAnd, bitand, "bit and");
     impl_scalar_lhs_op!(bool, Commute, |, BitOr, bitor, "bit or");
@@ -292,6 +342,20 @@ mod arithmetic_ops {
         }
     }
 
+    impl<'a, A, S, D> Neg for &'a ArrayBase<S, D>
+        where &'a A: Neg<Output=A>,
+              A: 'a,
+              S: Data<Elem=A>,
+              D: Dimension
+    {
+        type Output = Array<A, D>;
+        /// Perform an elementwise negation of reference `self` and return the
+        /// result as a new `Array`.
+        fn neg(self) -> Array<A, D> {
+            self.map(Neg::neg)
+        }
+    }
+
     impl<A, S, D> Not for ArrayBase<S, D>
         where A: Clone + Not<Output=A>,
               S: DataOwned<Elem=A> + DataMut,
@@ -306,6 +370,20 @@ mod arithmetic_ops {
             self
         }
     }
+
+    impl<'a, A, S, D> Not for &'a ArrayBase<S, D>
+        where &'a A: Not<Output=A>,
+              A: 'a,
+              S: Data<Elem=A>,
+              D: Dimension
+    {
+        type Output = Array<A, D>;
+        /// Perform an elementwise unary not of reference `self` and return the
+        /// result as a new `Array`.
+        fn not(self) -> Array<A, D> {
+            self.map(Not::not)
+        }
+    }
 }
 
 mod assign_ops {
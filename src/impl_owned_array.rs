@@ -1,5 +1,6 @@
 
 use imp_prelude::*;
+use error::{self, ShapeError};
 
 impl<A, D> ArrayBase<Vec<A>, D>
     where D: Dimension
@@ -12,4 +13,88 @@ impl<A, D> ArrayBase<Vec<A>, D>
     pub fn into_raw_vec(self) -> Vec<A> {
         self.data
     }
+
+    /// Append the elements of `array` to the end of the array along
+    /// `Axis(0)`, growing the array in place.
+    ///
+    /// `array` must have the same shape as `self`, except along `Axis(0)`;
+    /// use [`.insert_axis()`](#method.insert_axis) to append a single
+    /// subview.
+    ///
+    /// If the array is in standard layout and owns exactly its elements, the
+    /// new elements are pushed onto its vector, which grows by doubling like
+    /// a `Vec`: appending one row at a time takes amortized constant time
+    /// per element. Otherwise the array is first copied into standard
+    /// layout, which makes following appends fast.
+    ///
+    /// **Errors** if the shapes of `self` and `array` differ apart from along
+    /// `Axis(0)`, or if the arrays are zero-dimensional; the error records
+    /// the shape of `array` and the shape of `self`, and `self` is left
+    /// unchanged.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array, Axis};
+    ///
+    /// let mut a = Array::zeros((0, 2));
+    /// for i in 0..3 {
+    ///     a.append(&arr2(&[[i, 10 * i]])).unwrap();
+    /// }
+    /// a.append(&arr2(&[[7, 8], [9, 10]])).unwrap();
+    /// assert_eq!(a, arr2(&[[0, 0],
+    ///                      [1, 10],
+    ///                      [2, 20],
+    ///                      [7, 8],
+    ///                      [9, 10]]));
+    ///
+    /// assert!(a.append(&arr2(&[[1, 2, 3]])).is_err());
+    /// ```
+    pub fn append<S>(&mut self, array: &ArrayBase<S, D>) -> Result<(), ShapeError>
+        where A: Clone,
+              S: Data<Elem=A>,
+    {
+        if self.ndim() == 0 || array.shape()[1..] != self.shape()[1..] {
+            return Err(error::incompatible_shapes(&array.dim, &self.dim));
+        }
+        let owns_elements = self.data.len() == self.len() &&
+                            self.ptr == self.data.as_mut_ptr();
+        if !(owns_elements && self.is_standard_layout()) {
+            let v = self.iter().cloned().collect::<Vec<A>>();
+            self.data = v;
+            self.ptr = self.data.as_mut_ptr();
+            self.strides = self.dim.default_strides();
+        }
+        // reserve first and update the pointer before cloning, so that the
+        // array stays valid if a clone panics
+        self.data.reserve(array.len());
+        self.ptr = self.data.as_mut_ptr();
+        self.data.extend(array.iter().cloned());
+        self.dim[0] += array.shape()[0];
+        self.strides = self.dim.default_strides();
+        Ok(())
+    }
+}
+
+impl<A> ArrayBase<Vec<A>, Ix2> {
+    /// Append `row` as a new last row of the matrix, growing it in place.
+    ///
+    /// See [`.append()`](#method.append) for how the array grows.
+    ///
+    /// **Errors** if `row` does not have as many elements as the matrix has
+    /// columns.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array2};
+    ///
+    /// let mut a = Array2::zeros((0, 3));
+    /// a.push_row(&arr1(&[1., 2., 3.])).unwrap();
+    /// a.push_row(&arr1(&[4., 5., 6.])).unwrap();
+    /// assert_eq!(a, arr2(&[[1., 2., 3.],
+    ///                      [4., 5., 6.]]));
+    /// ```
+    pub fn push_row<S>(&mut self, row: &ArrayBase<S, Ix1>) -> Result<(), ShapeError>
+        where A: Clone,
+              S: Data<Elem=A>,
+    {
+        self.append(&row.view().insert_axis(Axis(0)))
+    }
 }
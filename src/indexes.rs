@@ -20,8 +20,18 @@ pub struct Indices<D> {
 
 /// Create an iterator over the array shape `shape`.
 ///
+/// The indices are produced in row major order (the last index varies
+/// the fastest), and no array data is involved.
+///
 /// *Note:* prefer higher order methods, arithmetic operations and
 /// non-indexed iteration before using indices.
+///
+/// ```
+/// use ndarray::indices;
+///
+/// let ix: Vec<_> = indices((2, 2)).collect();
+/// assert_eq!(ix, [(0, 0), (0, 1), (1, 0), (1, 1)]);
+/// ```
 pub fn indices<E>(shape: E) -> Indices<E::Dim>
     where E: IntoDimension,
 {
@@ -36,6 +46,16 @@ pub fn indices<E>(shape: E) -> Indices<E::Dim>
 ///
 /// *Note:* prefer higher order methods, arithmetic operations and
 /// non-indexed iteration before using indices.
+///
+/// ```
+/// use ndarray::{arr2, indices_of};
+///
+/// // find the index of the smallest element
+/// let a = arr2(&[[3, 1, 4],
+///                [1, 5, 9]]);
+/// let argmin = indices_of(&a).min_by_key(|&ix| a[ix]);
+/// assert_eq!(argmin, Some((0, 1)));
+/// ```
 pub fn indices_of<S, D>(array: &ArrayBase<S, D>) -> Indices<D>
     where S: Data, D: Dimension,
 {
@@ -5,7 +5,9 @@
 // <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
+use std::cmp;
 use std::marker::PhantomData;
+use std::mem;
 use std::ptr;
 
 use Ix1;
@@ -30,6 +32,10 @@ pub struct Baseiter<'a, A: 'a, D> {
     pub dim: D,
     pub strides: D,
     pub index: Option<D>,
+    /// Index of the last remaining element; only valid while `index` is `Some`.
+    pub back: D,
+    /// Number of remaining elements
+    pub len: usize,
     pub life: PhantomData<&'a A>,
 }
 
@@ -45,9 +51,16 @@ impl<'a, A, D: Dimension> Baseiter<'a, A, D> {
     /// NOTE: Mind the lifetime, it's arbitrary
     #[inline]
     pub unsafe fn new(ptr: *mut A, len: D, stride: D) -> Baseiter<'a, A, D> {
+        let mut back = len.clone();
+        for ix in back.slice_mut() {
+            // (unused if the array is empty)
+            *ix = ix.saturating_sub(1);
+        }
         Baseiter {
             ptr: ptr,
             index: len.first_index(),
+            back: back,
+            len: len.size(),
             dim: len,
             strides: stride,
             life: PhantomData,
@@ -63,7 +76,12 @@ impl<'a, A, D: Dimension> Baseiter<'a, A, D> {
             Some(ref ix) => ix.clone(),
         };
         let offset = D::stride_offset(&index, &self.strides);
-        self.index = self.dim.next_for(index);
+        self.len -= 1;
+        self.index = if self.len == 0 {
+            None
+        } else {
+            self.dim.next_for(index)
+        };
         unsafe { Some(self.ptr.offset(offset)) }
     }
 
@@ -78,18 +96,7 @@ impl<'a, A, D: Dimension> Baseiter<'a, A, D> {
     }
 
     fn size_hint(&self) -> usize {
-        match self.index {
-            None => 0,
-            Some(ref ix) => {
-                let gone = self.dim
-                               .default_strides()
-                               .slice()
-                               .iter()
-                               .zip(ix.slice().iter())
-                               .fold(0, |s, (&a, &b)| s + a as usize * b as usize);
-                self.dim.size() - gone
-            }
-        }
+        self.len
     }
 
     fn fold<Acc, G>(mut self, init: Acc, mut g: G) -> Acc
@@ -103,36 +110,41 @@ impl<'a, A, D: Dimension> Baseiter<'a, A, D> {
                 let stride = self.strides.last_elem() as isize;
                 let elem_index = index.last_elem();
                 let len = self.dim.last_elem();
+                // the last row may be cut short by `next_back`
+                let row_len = cmp::min(len - elem_index, self.len);
                 let offset = D::stride_offset(&index, &self.strides);
                 unsafe {
                     let row_ptr = self.ptr.offset(offset);
-                    for i in 0..(len - elem_index) {
+                    for i in 0..row_len {
                         accum = g(accum, row_ptr.offset(i as isize * stride));
                     }
                 }
+                self.len -= row_len;
                 index.set_last_elem(len - 1);
-                self.index = self.dim.next_for(index);
+                self.index = if self.len == 0 {
+                    None
+                } else {
+                    self.dim.next_for(index)
+                };
             } else {
                 break;
             };
         }
         accum
     }
-}
 
-impl<'a, A> Baseiter<'a, A, Ix1> {
     #[inline]
     fn next_back(&mut self) -> Option<*mut A> {
-        let index = match self.index {
-            None => return None,
-            Some(ix) => ix,
-        };
-        self.dim[0] -= 1;
-        let offset = <_>::stride_offset(&self.dim, &self.strides);
-        if index == self.dim {
+        if self.index.is_none() {
+            return None;
+        }
+        let offset = D::stride_offset(&self.back, &self.strides);
+        self.len -= 1;
+        if self.len == 0 {
             self.index = None;
+        } else if let Some(back) = self.dim.prev_for(self.back.clone()) {
+            self.back = back;
         }
-
         unsafe { Some(self.ptr.offset(offset)) }
     }
 
@@ -154,6 +166,8 @@ impl<'a, A, D: Clone> Clone for Baseiter<'a, A, D> {
             dim: self.dim.clone(),
             strides: self.strides.clone(),
             index: self.index.clone(),
+            back: self.back.clone(),
+            len: self.len,
             life: self.life,
         }
     }
@@ -186,7 +200,7 @@ impl<'a, A, D: Dimension> Iterator for ElementsBase<'a, A, D> {
     }
 }
 
-impl<'a, A> DoubleEndedIterator for ElementsBase<'a, A, Ix1> {
+impl<'a, A, D: Dimension> DoubleEndedIterator for ElementsBase<'a, A, D> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a A> {
         self.inner.next_back_ref()
@@ -247,7 +261,7 @@ impl<'a, A, D: Dimension> Iterator for Iter<'a, A, D> {
     }
 }
 
-impl<'a, A> DoubleEndedIterator for Iter<'a, A, Ix1> {
+impl<'a, A, D: Dimension> DoubleEndedIterator for Iter<'a, A, D> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a A> {
         either_mut!(self.inner, iter => iter.next_back())
@@ -301,7 +315,7 @@ impl<'a, A, D: Dimension> Iterator for IterMut<'a, A, D> {
     }
 }
 
-impl<'a, A> DoubleEndedIterator for IterMut<'a, A, Ix1> {
+impl<'a, A, D: Dimension> DoubleEndedIterator for IterMut<'a, A, D> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a mut A> {
         either_mut!(self.inner, iter => iter.next_back())
@@ -333,7 +347,7 @@ impl<'a, A, D: Dimension> Iterator for ElementsBaseMut<'a, A, D> {
     }
 }
 
-impl<'a, A> DoubleEndedIterator for ElementsBaseMut<'a, A, Ix1> {
+impl<'a, A, D: Dimension> DoubleEndedIterator for ElementsBaseMut<'a, A, D> {
     #[inline]
     fn next_back(&mut self) -> Option<&'a mut A> {
         self.inner.next_back_ref_mut()
@@ -367,14 +381,17 @@ impl<'a, A, D> ExactSizeIterator for IndexedIterMut<'a, A, D>
 /// An iterator that traverses over all dimensions but the innermost,
 /// and yields each inner row.
 ///
-/// See [`.inner_iter()`](struct.ArrayBase.html#method.inner_iter) for more information.
+/// It is also used for the lanes along any other axis.
+///
+/// See [`.inner_iter()`](struct.ArrayBase.html#method.inner_iter)
+/// or [`.lanes()`](struct.ArrayBase.html#method.lanes) for more information.
 pub struct InnerIter<'a, A: 'a, D> {
     inner_len: Ix,
     inner_stride: Ixs,
     iter: Baseiter<'a, A, D>,
 }
 
-pub fn new_inner_iter<A, D>(mut v: ArrayView<A, D>) -> InnerIter<A, D>
+pub fn new_inner_iter<A, D>(v: ArrayView<A, D>) -> InnerIter<A, D>
     where D: Dimension
 {
     let ndim = v.ndim();
@@ -385,15 +402,21 @@ pub fn new_inner_iter<A, D>(mut v: ArrayView<A, D>) -> InnerIter<A, D>
             iter: v.into_base_iter(),
         }
     } else {
-        // Set length of innerest dimension to 1, start iteration
-        let len = v.dim.last_elem();
-        let stride = v.strides.last_elem() as isize;
-        v.dim.set_last_elem(1);
-        InnerIter {
-            inner_len: len,
-            inner_stride: stride,
-            iter: v.into_base_iter(),
-        }
+        new_lanes(v, Axis(ndim - 1))
+    }
+}
+
+pub fn new_lanes<A, D>(mut v: ArrayView<A, D>, axis: Axis) -> InnerIter<A, D>
+    where D: Dimension
+{
+    // Set length of the lane axis to 1, start iteration
+    let len = v.dim[axis.axis()];
+    let stride = v.strides[axis.axis()] as isize;
+    v.dim[axis.axis()] = 1;
+    InnerIter {
+        inner_len: len,
+        inner_stride: stride,
+        iter: v.into_base_iter(),
     }
 }
 
@@ -423,7 +446,10 @@ impl<'a, A, D> ExactSizeIterator for InnerIter<'a, A, D>
 /// An iterator that traverses over all dimensions but the innermost,
 /// and yields each inner row (mutable).
 ///
+/// It is also used for the lanes along any other axis.
+///
 /// See [`.inner_iter_mut()`](struct.ArrayBase.html#method.inner_iter_mut)
+/// or [`.lanes_mut()`](struct.ArrayBase.html#method.lanes_mut)
 /// for more information.
 pub struct InnerIterMut<'a, A: 'a, D> {
     inner_len: Ix,
@@ -431,7 +457,7 @@ pub struct InnerIterMut<'a, A: 'a, D> {
     iter: Baseiter<'a, A, D>,
 }
 
-pub fn new_inner_iter_mut<A, D>(mut v: ArrayViewMut<A, D>) -> InnerIterMut<A, D>
+pub fn new_inner_iter_mut<A, D>(v: ArrayViewMut<A, D>) -> InnerIterMut<A, D>
     where D: Dimension,
 {
     let ndim = v.ndim();
@@ -442,15 +468,21 @@ pub fn new_inner_iter_mut<A, D>(mut v: ArrayViewMut<A, D>) -> InnerIterMut<A, D>
             iter: v.into_base_iter(),
         }
     } else {
-        // Set length of innerest dimension to 1, start iteration
-        let len = v.dim.last_elem();
-        let stride = v.strides.last_elem() as isize;
-        v.dim.set_last_elem(1);
-        InnerIterMut {
-            inner_len: len,
-            inner_stride: stride,
-            iter: v.into_base_iter(),
-        }
+        new_lanes_mut(v, Axis(ndim - 1))
+    }
+}
+
+pub fn new_lanes_mut<A, D>(mut v: ArrayViewMut<A, D>, axis: Axis) -> InnerIterMut<A, D>
+    where D: Dimension,
+{
+    // Set length of the lane axis to 1, start iteration
+    let len = v.dim[axis.axis()];
+    let stride = v.strides[axis.axis()] as isize;
+    v.dim[axis.axis()] = 1;
+    InnerIterMut {
+        inner_len: len,
+        inner_stride: stride,
+        iter: v.into_base_iter(),
     }
 }
 
@@ -926,6 +958,254 @@ pub fn new_chunk_iter_mut<A, D>(v: ArrayViewMut<A, D>, axis: usize, size: usize)
 chunk_iter_impl!(AxisChunksIter, ArrayView);
 chunk_iter_impl!(AxisChunksIterMut, ArrayViewMut);
 
+/// Traversal of an array in blocks (tiles) of a fixed shape.
+///
+/// The blocks are visited in the logical order of their position. Blocks at
+/// the far end of an axis are smaller if the block length doesn't divide the
+/// axis length.
+struct BlocksCore<A, D> {
+    ptr: *mut A,
+    dim: D,
+    strides: D,
+    block: D,
+    /// Number of blocks along each axis
+    grid: D,
+    /// Index of the next block in the grid
+    index: Option<D>,
+    /// Number of remaining blocks
+    len: usize,
+}
+
+fn new_blocks_core<A, D>(v: ArrayView<A, D>, block: D) -> BlocksCore<A, D>
+    where D: Dimension
+{
+    assert_eq!(block.ndim(), v.ndim(),
+               "ndarray: block shape must have the same dimensionality as the array");
+    let mut grid = v.dim.clone();
+    for (g, &b) in grid.slice_mut().iter_mut().zip(block.slice()) {
+        assert!(b > 0, "ndarray: block shape must be non-zero");
+        *g = *g / b + (*g % b != 0) as usize;
+    }
+    BlocksCore {
+        ptr: v.ptr,
+        index: grid.first_index(),
+        len: grid.size(),
+        grid: grid,
+        dim: v.dim,
+        strides: v.strides,
+        block: block,
+    }
+}
+
+impl<A, D: Dimension> BlocksCore<A, D> {
+    /// Return the pointer, shape and strides of the next block
+    fn next(&mut self) -> Option<(*mut A, D, D)> {
+        let index = match self.index {
+            None => return None,
+            Some(ref ix) => ix.clone(),
+        };
+        let mut start = index.clone();
+        let mut dim = self.block.clone();
+        for (((st, d), &b), &len) in start.slice_mut().iter_mut()
+                                          .zip(dim.slice_mut())
+                                          .zip(self.block.slice())
+                                          .zip(self.dim.slice())
+        {
+            *st *= b;
+            if *st + b > len {
+                *d = len - *st;
+            }
+        }
+        let offset = D::stride_offset(&start, &self.strides);
+        self.index = self.grid.next_for(index);
+        self.len -= 1;
+        unsafe { Some((self.ptr.offset(offset), dim, self.strides.clone())) }
+    }
+}
+
+/// An iterator over the blocks (tiles) of an array, yielding a view of each.
+///
+/// Iterator element type is `ArrayView<'a, A, D>`.
+///
+/// See [`.blocks()`](struct.ArrayBase.html#method.blocks) for more information.
+pub struct Blocks<'a, A: 'a, D> {
+    iter: BlocksCore<A, D>,
+    life: PhantomData<&'a A>,
+}
+
+pub fn new_blocks<A, D>(v: ArrayView<A, D>, block: D) -> Blocks<A, D>
+    where D: Dimension
+{
+    Blocks {
+        iter: new_blocks_core(v, block),
+        life: PhantomData,
+    }
+}
+
+/// An iterator over the blocks (tiles) of an array, yielding a mutable view
+/// of each.
+///
+/// Iterator element type is `ArrayViewMut<'a, A, D>`.
+///
+/// See [`.blocks_mut()`](struct.ArrayBase.html#method.blocks_mut) for more
+/// information.
+pub struct BlocksMut<'a, A: 'a, D> {
+    iter: BlocksCore<A, D>,
+    life: PhantomData<&'a mut A>,
+}
+
+pub fn new_blocks_mut<A, D>(v: ArrayViewMut<A, D>, block: D) -> BlocksMut<A, D>
+    where D: Dimension
+{
+    BlocksMut {
+        iter: new_blocks_core(v.view(), block),
+        life: PhantomData,
+    }
+}
+
+macro_rules! blocks_iter_impl {
+    ($iter:ident, $array:ident) => (
+        impl<'a, A, D> Iterator for $iter<'a, A, D>
+            where D: Dimension,
+        {
+            type Item = $array<'a, A, D>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.iter.next().map(|(ptr, dim, strides)| unsafe {
+                    $array::new_(ptr, dim, strides)
+                })
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.iter.len, Some(self.iter.len))
+            }
+        }
+
+        impl<'a, A, D> ExactSizeIterator for $iter<'a, A, D>
+            where D: Dimension,
+        { }
+    )
+}
+
+blocks_iter_impl!(Blocks, ArrayView);
+blocks_iter_impl!(BlocksMut, ArrayViewMut);
+
+
+/// An iterator that moves the elements out of an array.
+///
+/// Iterator element type is `A`.
+///
+/// Elements are produced in logical order. The array's buffer is reused;
+/// elements that are not yielded are dropped along with the iterator.
+///
+/// See [`.into_iter()`](struct.ArrayBase.html#method.into_iter)
+/// for more information.
+pub struct IntoIter<A, D>
+    where D: Dimension
+{
+    // Owns the allocation only, its length is always zero; it is never read,
+    // but frees the buffer when dropped.
+    _data: Vec<A>,
+    ptr: *mut A,
+    dim: D,
+    strides: D,
+    index: Option<D>,
+    len: usize,
+}
+
+pub fn new_into_iter<A, D>(mut a: ArrayBase<Vec<A>, D>) -> IntoIter<A, D>
+    where D: Dimension,
+{
+    let len = a.len();
+    let hidden = a.data.len() - len;
+    unsafe {
+        // The array's elements are now owned by the iterator; elements of the
+        // buffer that are not part of the array are dropped right away.
+        let base = a.data.as_mut_ptr();
+        let data_len = a.data.len();
+        a.data.set_len(0);
+        if hidden > 0 {
+            if mem::size_of::<A>() == 0 {
+                for _ in 0..hidden {
+                    ptr::drop_in_place(base);
+                }
+            } else {
+                // Visit the array's elements in order of increasing address,
+                // with all strides positive and the largest stride first,
+                // and drop the elements of the buffer between them.
+                let mut first = a.ptr;
+                let mut dim = a.dim.clone();
+                let mut strides = a.strides.clone();
+                for (&len, s) in dim.slice().iter().zip(strides.slice_mut()) {
+                    let stride = *s as Ixs;
+                    if stride < 0 && len > 0 {
+                        first = first.offset((len - 1) as Ixs * stride);
+                        *s = -stride as Ix;
+                    }
+                }
+                for i in 0..dim.ndim() {
+                    let largest = (i..dim.ndim()).max_by_key(|&j| strides.slice()[j]).unwrap();
+                    dim.slice_mut().swap(i, largest);
+                    strides.slice_mut().swap(i, largest);
+                }
+                let mut next = base;
+                for elt in ArrayView::new_(first, dim, strides).iter() {
+                    let elt = elt as *const A as *mut A;
+                    while next != elt {
+                        ptr::drop_in_place(next);
+                        next = next.offset(1);
+                    }
+                    next = next.offset(1);
+                }
+                let end = base.offset(data_len as isize);
+                while next != end {
+                    ptr::drop_in_place(next);
+                    next = next.offset(1);
+                }
+            }
+        }
+    }
+    IntoIter {
+        _data: a.data,
+        ptr: a.ptr,
+        index: a.dim.first_index(),
+        dim: a.dim,
+        strides: a.strides,
+        len: len,
+    }
+}
+
+impl<A, D: Dimension> Iterator for IntoIter<A, D> {
+    type Item = A;
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        let index = match self.index {
+            None => return None,
+            Some(ref ix) => ix.clone(),
+        };
+        let offset = D::stride_offset(&index, &self.strides);
+        self.index = self.dim.next_for(index);
+        self.len -= 1;
+        unsafe { Some(ptr::read(self.ptr.offset(offset))) }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<A, D> ExactSizeIterator for IntoIter<A, D>
+    where D: Dimension
+{}
+
+impl<A, D> Drop for IntoIter<A, D>
+    where D: Dimension
+{
+    fn drop(&mut self) {
+        // drop the elements that were not yielded; `_data` frees the buffer
+        for _ in self.by_ref() { }
+    }
+}
 
 // Send and Sync
 // All the iterators are thread safe the same way the slice's iterator are
@@ -951,12 +1231,18 @@ send_sync_read_only!(IndexedIter);
 send_sync_read_only!(InnerIter);
 send_sync_read_only!(AxisIter);
 send_sync_read_only!(AxisChunksIter);
+send_sync_read_only!(Blocks);
 
 send_sync_read_write!(IterMut);
 send_sync_read_write!(IndexedIterMut);
 send_sync_read_write!(InnerIterMut);
 send_sync_read_write!(AxisIterMut);
 send_sync_read_write!(AxisChunksIterMut);
+send_sync_read_write!(BlocksMut);
+
+// the by-value iterator owns its elements, same as `std::vec::IntoIter`.
+unsafe impl<A, D> Send for IntoIter<A, D> where A: Send, D: Send + Dimension { }
+unsafe impl<A, D> Sync for IntoIter<A, D> where A: Sync, D: Sync + Dimension { }
 
 /// (Trait used internally) An iterator that we trust
 /// to deliver exactly as many items as it said it would.
@@ -40,6 +40,8 @@
 //!   + The higher order functions like ``.map()``, ``.map_inplace()`` and
 //!     ``.zip_mut_with()`` are the most efficient ways to
 //!     perform single traversal and lock step traversal respectively.
+//!   + ``Zip`` and the ``azip!()`` macro extend lock step traversal to
+//!     several arrays at once.
 //!   + Performance of an operation depends on the memory layout of the array
 //!     or array view. Especially if it's a binary operation, which
 //!     needs matching memory layout to be efficient (with some exceptions).
@@ -59,8 +61,15 @@
 //!   - Enables serialization support for serde 0.8
 //! - `blas`
 //!   - Optional and experimental, compatible with Rust stable
-//!   - Enable transparent BLAS support for matrix multiplication. Pluggable
-//!     backend via `blas-sys`.
+//!   - Enable transparent BLAS support for matrix multiplication,
+//!     matrix-vector multiplication and vector dot product of `f32` and `f64`
+//!     arrays. Pluggable backend via `blas-sys`.
+//! - `lapack`
+//!   - Optional and experimental, compatible with Rust stable
+//!   - Compute the LU, QR, singular value and eigen decompositions in
+//!     `linalg` of `f32` and `f64` matrices with LAPACK, falling back to the
+//!     pure Rust implementations for other element types. Pluggable
+//!     backend via `lapack-sys`.
 //!
 
 #[cfg(feature = "serde")]
@@ -70,6 +79,8 @@ extern crate rustc_serialize as serialize;
 
 #[cfg(feature="blas")]
 extern crate blas_sys;
+#[cfg(feature="lapack")]
+extern crate lapack_sys;
 
 extern crate matrixmultiply;
 
@@ -77,7 +88,7 @@ extern crate itertools;
 extern crate num_traits as libnum;
 extern crate num_complex;
 
-use std::iter::Zip;
+use std::iter;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::slice::{self, Iter as SliceIter, IterMut as SliceIterMut};
@@ -104,13 +115,21 @@ pub use iterators::{
     AxisIterMut,
     AxisChunksIter,
     AxisChunksIterMut,
+    Blocks,
+    BlocksMut,
+    IntoIter,
 };
 
 pub use arraytraits::AsArray;
-pub use linalg_traits::{LinalgScalar, NdFloat};
-pub use stacking::stack;
+pub use linalg_traits::{LinalgScalar, NdFloat, ComplexField};
+pub use stacking::{stack, stack_new_axis, tile};
+pub use broadcast::broadcast_arrays;
+
+pub use shape_builder::{ShapeBuilder, Order};
+pub use pad::PadMode;
 
-pub use shape_builder::{ ShapeBuilder};
+pub use zip::{Zip, NdProducer, IntoNdProducer};
+pub use parallel::{ParIter, ParIterMut};
 
 mod aliases;
 mod arraytraits;
@@ -136,15 +155,19 @@ mod dimension;
 mod free_functions;
 pub use free_functions::*;
 
+mod broadcast;
 mod indexes;
 mod iterators;
 mod linalg_traits;
 mod linspace;
 mod numeric_util;
+mod parallel;
 mod si;
 mod error;
+mod pad;
 mod shape_builder;
 mod stacking;
+mod zip;
 
 /// Implementation's prelude. Common types used everywhere.
 mod imp_prelude {
@@ -467,6 +490,24 @@ pub type Ixs = isize;
 /// );
 /// ```
 ///
+/// When an operation updates an array (`B @ A`, `B @ &A` and `C @= &A`),
+/// only the right hand side operand is broadcast, to the shape of the left
+/// hand side. For `&A @ &A`, both operands are broadcast together to a
+/// common shape (with as many axes as the left hand side), so that for
+/// example a column and a row produce a matrix:
+///
+/// ```
+/// use ndarray::arr2;
+///
+/// let col = arr2(&[[0],
+///                  [10]]);
+/// let row = arr2(&[[1, 2, 3]]);
+/// assert_eq!(&col + &row, arr2(&[[ 1,  2,  3],
+///                                [11, 12, 13]]));
+/// ```
+///
+/// Operations **panic** if the shapes can not be broadcast.
+///
 pub struct ArrayBase<S, D>
     where S: Data
 {
@@ -603,9 +644,13 @@ mod numeric;
 
 pub mod linalg;
 
+pub mod stats;
+
 mod impl_ops;
 pub use impl_ops::ScalarOperand;
 
+mod impl_compare;
+
 // Array view methods
 mod impl_views;
 
@@ -753,18 +798,18 @@ pub struct IndexedIter<'a, A: 'a, D>(ElementsBase<'a, A, D>);
 pub struct IndexedIterMut<'a, A: 'a, D>(ElementsBaseMut<'a, A, D>);
 
 fn zipsl<'a, 'b, A, B>(t: &'a [A], u: &'b [B])
-    -> Zip<SliceIter<'a, A>, SliceIter<'b, B>> {
+    -> iter::Zip<SliceIter<'a, A>, SliceIter<'b, B>> {
     t.iter().zip(u)
 }
 fn zipsl_mut<'a, 'b, A, B>(t: &'a mut [A], u: &'b mut [B])
-    -> Zip<SliceIterMut<'a, A>, SliceIterMut<'b, B>> {
+    -> iter::Zip<SliceIterMut<'a, A>, SliceIterMut<'b, B>> {
     t.iter_mut().zip(u)
 }
 
 use itertools::{cons_tuples, ConsTuples};
 
 trait ZipExt : Iterator {
-    fn zip_cons<J>(self, iter: J) -> ConsTuples<Zip<Self, J::IntoIter>, (Self::Item, J::Item)>
+    fn zip_cons<J>(self, iter: J) -> ConsTuples<iter::Zip<Self, J::IntoIter>, (Self::Item, J::Item)>
         where J: IntoIterator,
               Self: Sized,
     {
@@ -0,0 +1,231 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use imp_prelude::*;
+use {LinalgScalar, NdFloat};
+use super::solve::SingularError;
+
+/// A square banded matrix, with `kl` subdiagonals and `ku` superdiagonals,
+/// stored by diagonals.
+///
+/// The diagonals are the rows of a `(kl + ku + 1) × n` array, the *band*:
+/// element `[i, j]` of the matrix is `band[[ku + i - j, j]]`. So row `0` is
+/// the top superdiagonal, row `ku` the main diagonal and row `kl + ku` the
+/// bottom subdiagonal (the layout of LAPACK). The band elements outside
+/// the matrix are unused.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::Banded;
+///
+/// let a = arr2(&[[2., 1., 0.],
+///                [1., 2., 1.],
+///                [0., 1., 2.]]);
+/// let b = Banded::from_dense(&a, 1, 1);
+/// assert_eq!(b.band(), arr2(&[[0., 1., 1.],
+///                             [2., 2., 2.],
+///                             [1., 1., 0.]]));
+/// assert_eq!(b.to_dense(), a);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Banded<A> {
+    band: Array2<A>,
+    kl: usize,
+    ku: usize,
+}
+
+impl<A: LinalgScalar> Banded<A> {
+    /// Create a banded matrix from its band.
+    ///
+    /// **Panics** if `band` does not have `kl + ku + 1` rows.
+    pub fn from_band(band: Array2<A>, kl: usize, ku: usize) -> Self {
+        assert!(band.rows() == kl + ku + 1,
+                "ndarray: Banded::from_band: band must have {} rows, but has shape {:?}",
+                kl + ku + 1, band.shape());
+        Banded {
+            band: band,
+            kl: kl,
+            ku: ku,
+        }
+    }
+
+    /// Create a banded matrix from the elements of the square matrix `a`
+    /// within the band; the other elements are ignored.
+    ///
+    /// **Panics** if `a` is not square.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, kl: usize, ku: usize) -> Self
+        where S: Data<Elem=A>,
+    {
+        assert!(a.is_square(), "ndarray: Banded::from_dense: matrix must be square, \
+                but has shape {:?}", a.shape());
+        let n = a.rows();
+        let mut band = Array2::zeros((kl + ku + 1, n));
+        for j in 0..n {
+            for i in j.saturating_sub(ku)..cmp::min(n, j + kl + 1) {
+                band[[ku + i - j, j]] = a[[i, j]];
+            }
+        }
+        Banded::from_band(band, kl, ku)
+    }
+
+    /// Return the number of rows (and columns) of the matrix.
+    pub fn dim(&self) -> usize {
+        self.band.cols()
+    }
+
+    /// Return the number of subdiagonals.
+    pub fn kl(&self) -> usize {
+        self.kl
+    }
+
+    /// Return the number of superdiagonals.
+    pub fn ku(&self) -> usize {
+        self.ku
+    }
+
+    /// Return a view of the band.
+    pub fn band(&self) -> ArrayView2<A> {
+        self.band.view()
+    }
+
+    /// Return a read-write view of the band.
+    pub fn band_mut(&mut self) -> ArrayViewMut2<A> {
+        self.band.view_mut()
+    }
+
+    /// Return the matrix as a dense array.
+    pub fn to_dense(&self) -> Array2<A> {
+        let n = self.dim();
+        let mut a = Array2::zeros((n, n));
+        for j in 0..n {
+            for i in self.rows_of_col(j) {
+                a[[i, j]] = self.band[[self.ku + i - j, j]];
+            }
+        }
+        a
+    }
+
+    /// Return the matrix-vector product of the banded matrix and `x`, in
+    /// `O(n (kl + ku))` time.
+    ///
+    /// **Panics** if the length of `x` is not the dimension of the matrix.
+    pub fn dot<S>(&self, x: &ArrayBase<S, Ix1>) -> Array1<A>
+        where S: Data<Elem=A>,
+    {
+        let n = self.dim();
+        assert!(x.len() == n, "ndarray: Banded::dot: vector of length {} for a matrix \
+                of dimension {}", x.len(), n);
+        let mut y = Array1::zeros(n);
+        for j in 0..n {
+            let xj = x[j];
+            for i in self.rows_of_col(j) {
+                y[i] = y[i] + self.band[[self.ku + i - j, j]] * xj;
+            }
+        }
+        y
+    }
+
+    /// The rows with elements in the band in column `j`.
+    fn rows_of_col(&self, j: usize) -> ::std::ops::Range<usize> {
+        j.saturating_sub(self.ku)..cmp::min(self.dim(), j + self.kl + 1)
+    }
+}
+
+impl<A: NdFloat> Banded<A> {
+    /// Solve the linear system `a x = b` for the banded matrix `a`.
+    ///
+    /// `b` is either a vector, or a matrix with a right hand side in each
+    /// column; the solution `x` has the same shape as `b`. This uses
+    /// Gaussian elimination with partial pivoting within the band, in
+    /// `O(n kl (kl + ku))` time; the pivoting widens the upper band of the
+    /// factor to `kl + ku`.
+    ///
+    /// Return an error if `a` is singular (a pivot is exactly zero).
+    ///
+    /// **Panics** if `b` is not one or two dimensional, or if `b` does not
+    /// have `n` rows.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::Banded;
+    ///
+    /// let a = Banded::from_band(arr2(&[[0., 1., 1., 1.],
+    ///                                  [4., 4., 4., 4.],
+    ///                                  [1., 1., 1., 0.]]), 1, 1);
+    /// let x = arr1(&[1., -1., 2., 0.5]);
+    /// let b = a.dot(&x);
+    /// assert!(a.solve(&b).unwrap().all_close(&x, 1e-12));
+    /// ```
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, SingularError>
+        where S: Data<Elem=A>,
+              D: Dimension,
+    {
+        let n = self.dim();
+        assert!(b.ndim() == 1 || b.ndim() == 2,
+                "ndarray: Banded::solve: right hand side must be one or two dimensional, \
+                 but has shape {:?}", b.shape());
+        assert!(b.shape()[0] == n,
+                "ndarray: Banded::solve: right hand side has shape {:?}, but the matrix has {} rows",
+                b.shape(), n);
+        let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+        let mut x = Array::from_iter(b.iter().cloned()).into_shape((n, k)).unwrap();
+
+        let (kl, u) = (self.kl, self.kl + self.ku);
+        // the working band, with room for the fill-in of the pivoting:
+        // element [i, j] is at w[[u + i - j, j]], for -u ≤ i - j ≤ kl
+        let mut w = Array2::zeros((u + kl + 1, n));
+        w.view_mut().split_at(Axis(0), kl).1.assign(&self.band);
+        macro_rules! at {
+            ($i:expr, $j:expr) => { w[[u + $i - $j, $j]] }
+        }
+
+        for c in 0..n {
+            let last = cmp::min(n - 1, c + kl);
+            let mut p = c;
+            for i in c + 1..last + 1 {
+                if at!(i, c).abs() > at!(p, c).abs() {
+                    p = i;
+                }
+            }
+            if at!(p, c) == A::zero() {
+                return Err(SingularError);
+            }
+            let end = cmp::min(n - 1, c + u);
+            if p != c {
+                for j in c..end + 1 {
+                    let t = at!(c, j);
+                    at!(c, j) = at!(p, j);
+                    at!(p, j) = t;
+                }
+                x.swap_rows(c, p);
+            }
+            let pivot = at!(c, c);
+            for i in c + 1..last + 1 {
+                let factor = at!(i, c) / pivot;
+                for j in c + 1..end + 1 {
+                    at!(i, j) = at!(i, j) - factor * at!(c, j);
+                }
+                let (top, mut bottom) = x.view_mut().split_at(Axis(0), i);
+                bottom.row_mut(0).scaled_add(-factor, &top.row(c));
+            }
+        }
+        for i in (0..n).rev() {
+            let end = cmp::min(n - 1, i + u);
+            let (mut top, bottom) = x.view_mut().split_at(Axis(0), i + 1);
+            let mut row = top.row_mut(i);
+            for j in i + 1..end + 1 {
+                row.scaled_add(-at!(i, j), &bottom.row(j - i - 1));
+            }
+            let pivot = at!(i, i);
+            row.mapv_inplace(|y| y / pivot);
+        }
+        Ok(x.into_shape(b.raw_dim()).unwrap())
+    }
+}
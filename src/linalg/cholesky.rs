@@ -0,0 +1,199 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::error::Error;
+
+use libnum::{Float, Zero};
+
+use imp_prelude::*;
+use ComplexField;
+use super::triangular::{forward_substitution, right_hand_side, Diag};
+
+/// An error returned when a matrix that must be positive definite is not.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NotPositiveDefiniteError;
+
+impl Error for NotPositiveDefiniteError {
+    fn description(&self) -> &str {
+        "the matrix is not positive definite"
+    }
+}
+
+impl fmt::Display for NotPositiveDefiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+/// A Cholesky decomposition, `A = L Lᴴ`, of a Hermitian (or real
+/// symmetric) positive definite matrix `A`.
+///
+/// `L` is lower triangular with a real, positive diagonal, and `Lᴴ` is its
+/// conjugate transpose. The decomposition can be used to solve linear
+/// systems with `A` for any number of right hand sides, without factoring
+/// `A` again.
+///
+/// Created with [`cholesky`](fn.cholesky.html).
+#[derive(Clone, Debug)]
+pub struct Cholesky<A> {
+    /// `L`, with zeros above the diagonal
+    l: Array2<A>,
+}
+
+/// Compute the Cholesky decomposition of the Hermitian (or real symmetric)
+/// positive definite matrix `a`.
+///
+/// Return the decomposition `a = l lᴴ`, where `l` is lower triangular with
+/// a real, positive diagonal and `lᴴ` is the conjugate transpose of `l`.
+///
+/// Only the lower triangle of `a` is read, and the imaginary part of its
+/// diagonal is ignored.
+///
+/// Return an error if `a` is not positive definite.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate ndarray;
+///
+/// use num_complex::Complex;
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::cholesky;
+///
+/// # fn main() {
+/// let c = |re, im| Complex::new(re, im);
+/// let a = arr2(&[[c(4., 0.), c(2., -2.)],
+///                [c(2., 2.), c(6., 0.)]]);
+/// let f = cholesky(&a).unwrap();
+/// let l = f.l();
+/// assert!((&l.dot(&l.t().conj()) - &a).norm().iter().all(|&e: &f64| e < 1e-12));
+///
+/// // factor once, solve many
+/// for b in &[arr1(&[c(2., 0.), c(4., 2.)]), arr1(&[c(0., 1.), c(-1., 0.)])] {
+///     let x = f.solve(b);
+///     assert!((&a.dot(&x) - b).norm().iter().all(|&e: &f64| e < 1e-12));
+/// }
+/// # }
+/// ```
+pub fn cholesky<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Cholesky<A>, NotPositiveDefiniteError>
+    where A: ComplexField,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: cholesky: matrix must be square, but has shape {:?}",
+            a.shape());
+    let mut l = a.to_owned();
+    try!(factor_inplace(l.view_mut()));
+    Ok(Cholesky { l: l })
+}
+
+/// Compute the Cholesky decomposition of the Hermitian (or real symmetric)
+/// positive definite matrix `a`, overwriting `a` with `l`.
+///
+/// See [`cholesky`](fn.cholesky.html); on success, `a` is the lower
+/// triangular factor `l` of `a = l lᴴ` (the upper triangle is set to zero).
+/// This avoids allocating `l`, for repeated factorizations of same-sized
+/// matrices.
+///
+/// Return an error if `a` is not positive definite; `a` is then partially
+/// overwritten.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::cholesky_inplace;
+///
+/// let mut a = arr2(&[[4., 2.],
+///                    [2., 5.]]);
+/// cholesky_inplace(&mut a).unwrap();
+/// assert_eq!(a, arr2(&[[2., 0.],
+///                      [1., 2.]]));
+/// ```
+pub fn cholesky_inplace<A, S>(a: &mut ArrayBase<S, Ix2>) -> Result<(), NotPositiveDefiniteError>
+    where A: ComplexField,
+          S: DataMut<Elem=A>,
+{
+    assert!(a.is_square(),
+            "ndarray: cholesky_inplace: matrix must be square, but has shape {:?}",
+            a.shape());
+    factor_inplace(a.view_mut())
+}
+
+/// Overwrite the lower triangle of `l` with its Cholesky factor, and the
+/// upper triangle with zeros.
+fn factor_inplace<A: ComplexField>(mut l: ArrayViewMut2<A>) -> Result<(), NotPositiveDefiniteError> {
+    let n = l.rows();
+    for j in 0..n {
+        let mut d = l[[j, j]].re();
+        for k in 0..j {
+            let m = l[[j, k]].modulus();
+            d -= m * m;
+        }
+        // also rejects NaN
+        if d.partial_cmp(&A::Real::zero()) != Some(Ordering::Greater) {
+            return Err(NotPositiveDefiniteError);
+        }
+        let pivot = d.sqrt();
+        l[[j, j]] = A::from_real(pivot);
+        for i in j + 1..n {
+            let mut sum = l[[i, j]];
+            for k in 0..j {
+                // not `-=`: the num-complex versions we support don't all
+                // implement the assign operators for Complex
+                sum = sum - l[[i, k]] * l[[j, k]].conj();
+            }
+            l[[i, j]] = sum / A::from_real(pivot);
+            l[[j, i]] = A::zero();
+        }
+    }
+    Ok(())
+}
+
+impl<A: ComplexField> Cholesky<A> {
+    /// Return a view of the lower triangular factor `L`.
+    pub fn l(&self) -> ArrayView2<A> {
+        self.l.view()
+    }
+
+    /// Return the lower triangular factor `L`.
+    pub fn into_l(self) -> Array2<A> {
+        self.l
+    }
+
+    /// Solve the linear system `A x = b`, by forward substitution with `L`
+    /// and back substitution with `Lᴴ`.
+    ///
+    /// `b` is either a vector, or a matrix with a right hand side in each
+    /// column; the solution `x` has the same shape as `b`.
+    ///
+    /// **Panics** if `b` is not one or two dimensional, or if `b` does not
+    /// have `n` rows.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Array<A, D>
+        where S: Data<Elem=A>,
+              D: Dimension,
+    {
+        let n = self.l.rows();
+        let mut x = right_hand_side("Cholesky::solve", n, b);
+        // the diagonal of L is positive, so the substitutions succeed
+        forward_substitution(self.l.view(), x.view_mut(), Diag::NonUnit).unwrap();
+        // back substitution with Lᴴ, whose row i is column i of L conjugated
+        for i in (0..n).rev() {
+            let (mut top, bottom) = x.view_mut().split_at(Axis(0), i + 1);
+            let mut row = top.row_mut(i);
+            for j in i + 1..n {
+                row.scaled_add(-self.l[[j, i]].conj(), &bottom.row(j - i - 1));
+            }
+            let pivot = self.l[[i, i]];
+            row.mapv_inplace(|y| y / pivot);
+        }
+        x.into_shape(b.raw_dim()).unwrap()
+    }
+}
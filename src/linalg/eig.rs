@@ -0,0 +1,611 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+use std::error::Error;
+use std::fmt;
+
+use num_complex::Complex;
+
+use imp_prelude::*;
+use NdFloat;
+#[cfg(feature="lapack")]
+use super::lapack;
+
+/// An error returned when an iterative algorithm does not converge, for
+/// example because the matrix has infinite or NaN elements.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConvergenceError;
+
+impl Error for ConvergenceError {
+    fn description(&self) -> &str {
+        "the algorithm did not converge"
+    }
+}
+
+impl fmt::Display for ConvergenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+/// Compute the eigenvalues and eigenvectors of the square matrix `a`.
+///
+/// Return `(w, v)` where `w` has the (possibly complex) eigenvalues and
+/// column `i` of `v` is the eigenvector of `w[i]`, normalized to unit
+/// length, so that `a v = v diag(w)`. Complex eigenvalues come in
+/// conjugate pairs, with the one with positive imaginary part first.
+///
+/// The matrix is reduced to upper Hessenberg form with Householder
+/// reflections, and then to real Schur form with the shifted QR algorithm;
+/// the eigenvectors are found by back substitution.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the QR
+/// algorithm does not converge.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate ndarray;
+///
+/// use num_complex::Complex;
+/// use ndarray::arr2;
+/// use ndarray::linalg::eig;
+///
+/// # fn main() {
+/// // rotation by 90°
+/// let a = arr2(&[[0., -1.],
+///                [1., 0.]]);
+/// let (w, v) = eig(&a).unwrap();
+/// assert_eq!(w[0], Complex::new(0., 1.));
+/// assert_eq!(w[1], Complex::new(0., -1.));
+/// let ac = a.mapv(|x| Complex::new(x, 0.));
+/// let av = ac.dot(&v);
+/// for j in 0..2 {
+///     for i in 0..2 {
+///         assert!((av[[i, j]] - v[[i, j]] * w[j]).norm() < 1e-12);
+///     }
+/// }
+/// # }
+/// ```
+pub fn eig<A, S>(a: &ArrayBase<S, Ix2>)
+    -> Result<(Array1<Complex<A>>, Array2<Complex<A>>), ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    let (d, e, v) = try!(general_eigen(a, true));
+    let v = v.unwrap();
+    let n = d.len();
+    let mut vectors = Array2::zeros((n, n));
+    let mut j = 0;
+    while j < n {
+        if e[j] == A::zero() {
+            for i in 0..n {
+                vectors[[i, j]] = Complex::new(v[[i, j]], A::zero());
+            }
+            j += 1;
+        } else {
+            // the pair's eigenvectors are v[:, j] ± i v[:, j + 1]
+            for i in 0..n {
+                vectors[[i, j]] = Complex::new(v[[i, j]], v[[i, j + 1]]);
+                vectors[[i, j + 1]] = Complex::new(v[[i, j]], -v[[i, j + 1]]);
+            }
+            j += 2;
+        }
+    }
+    for mut col in vectors.axis_iter_mut(Axis(1)) {
+        let norm = col.fold(A::zero(), |acc, z| acc.hypot(z.norm()));
+        if norm != A::zero() {
+            col.mapv_inplace(|z| z / norm);
+        }
+    }
+    Ok((complex_values(&d, &e), vectors))
+}
+
+/// Compute the (possibly complex) eigenvalues of the square matrix `a`.
+///
+/// This is faster than [`eig`](fn.eig.html), since the eigenvectors are
+/// not computed.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the QR
+/// algorithm does not converge.
+///
+/// **Panics** if `a` is not square.
+pub fn eigvals<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array1<Complex<A>>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    let (d, e, _) = try!(general_eigen(a, false));
+    Ok(complex_values(&d, &e))
+}
+
+fn complex_values<A: NdFloat>(re: &[A], im: &[A]) -> Array1<Complex<A>> {
+    Array::from_iter(re.iter().zip(im).map(|(&x, &y)| Complex::new(x, y)))
+}
+
+/// The real and imaginary parts of the eigenvalues, and the real
+/// eigenvector matrix if it was computed.
+type RealEigen<A> = (Vec<A>, Vec<A>, Option<Array2<A>>);
+
+/// Return the real and imaginary parts of the eigenvalues, and, if
+/// `vectors`, the real matrix `V` with `A V = V D`, where `D` is block
+/// diagonal with a `2 × 2` block `[[re, im], [-im, re]]` for each complex
+/// pair.
+fn general_eigen<A, S>(a: &ArrayBase<S, Ix2>, vectors: bool)
+    -> Result<RealEigen<A>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: eig: matrix must be square, but has shape {:?}",
+            a.shape());
+    if !a.iter().all(|x| x.is_finite()) {
+        return Err(ConvergenceError);
+    }
+    #[cfg(feature="lapack")]
+    {
+        if let Some(result) = lapack::eig(a.view(), vectors) {
+            return Ok(result);
+        }
+    }
+    let n = a.rows();
+    let mut h = a.to_owned();
+    let mut v = if vectors { Array2::eye(n) } else { Array2::zeros((0, 0)) };
+    let mut d = vec![A::zero(); n];
+    let mut e = vec![A::zero(); n];
+    if n > 0 {
+        hessenberg(&mut h, &mut v, vectors);
+        try!(schur(&mut h, &mut v, &mut d, &mut e, vectors));
+    }
+    Ok((d, e, if vectors { Some(v) } else { None }))
+}
+
+/// Reduce `h` to upper Hessenberg form by orthogonal similarity
+/// transformations. If `vectors`, they are accumulated into `v` (which
+/// must be the identity).
+fn hessenberg<A: NdFloat>(h: &mut Array2<A>, v: &mut Array2<A>, vectors: bool) {
+    let n = h.rows();
+    let high = n - 1;
+    let mut ort = vec![A::zero(); n];
+    for m in 1..high {
+        let scale = (m..n).fold(A::zero(), |acc, i| acc + h[[i, m - 1]].abs());
+        if scale == A::zero() {
+            continue;
+        }
+        // the Householder transformation
+        let mut hh = A::zero();
+        for i in (m..n).rev() {
+            ort[i] = h[[i, m - 1]] / scale;
+            hh += ort[i] * ort[i];
+        }
+        let mut g = hh.sqrt();
+        if ort[m] > A::zero() {
+            g = -g;
+        }
+        hh -= ort[m] * g;
+        ort[m] -= g;
+
+        // H = (I - u uᵀ / hh) H (I - u uᵀ / hh)
+        for j in m..n {
+            let f = (m..n).rev().fold(A::zero(), |acc, i| acc + ort[i] * h[[i, j]]) / hh;
+            for i in m..n {
+                h[[i, j]] -= f * ort[i];
+            }
+        }
+        for i in 0..n {
+            let f = (m..n).rev().fold(A::zero(), |acc, j| acc + ort[j] * h[[i, j]]) / hh;
+            for j in m..n {
+                h[[i, j]] -= f * ort[j];
+            }
+        }
+        ort[m] = scale * ort[m];
+        h[[m, m - 1]] = scale * g;
+    }
+
+    if !vectors {
+        return;
+    }
+
+    // accumulate the transformations
+    for m in (1..high).rev() {
+        if h[[m, m - 1]] == A::zero() {
+            continue;
+        }
+        for i in m + 1..n {
+            ort[i] = h[[i, m - 1]];
+        }
+        for j in m..n {
+            let mut g = (m..n).fold(A::zero(), |acc, i| acc + ort[i] * v[[i, j]]);
+            // double division avoids possible underflow
+            g = (g / ort[m]) / h[[m, m - 1]];
+            for i in m..n {
+                v[[i, j]] += g * ort[i];
+            }
+        }
+    }
+}
+
+/// Reduce the Hessenberg matrix `h` to real Schur form with the shifted QR
+/// algorithm, storing the eigenvalues in `d` and `e`. If `vectors`, the
+/// transformations are accumulated into `v`, and finally `v` is turned into
+/// the eigenvectors by back substitution.
+fn schur<A: NdFloat>(h: &mut Array2<A>, v: &mut Array2<A>, d: &mut [A], e: &mut [A],
+                     vectors: bool)
+    -> Result<(), ConvergenceError>
+{
+    let nn = h.rows();
+    let eps = A::epsilon();
+    let two = A::one() + A::one();
+    let mut exshift = A::zero();
+    let (mut r, mut s, mut z) = (A::zero(), A::zero(), A::zero());
+    let (mut p, mut q, mut w, mut x, mut y);
+
+    let mut norm = A::zero();
+    for i in 0..nn {
+        for j in i.saturating_sub(1)..nn {
+            norm += h[[i, j]].abs();
+        }
+    }
+
+    let mut iter = 0;
+    let mut total_iter = 0;
+    // the number of eigenvalues not found yet
+    let mut remaining = nn;
+    while remaining > 0 {
+        let n = remaining - 1;
+        // look for a single small subdiagonal element
+        let mut l = n;
+        while l > 0 {
+            s = h[[l - 1, l - 1]].abs() + h[[l, l]].abs();
+            if s == A::zero() {
+                s = norm;
+            }
+            if h[[l, l - 1]].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // one root found
+            h[[n, n]] += exshift;
+            d[n] = h[[n, n]];
+            e[n] = A::zero();
+            remaining -= 1;
+            iter = 0;
+        } else if l == n - 1 {
+            // two roots found
+            w = h[[n, n - 1]] * h[[n - 1, n]];
+            p = (h[[n - 1, n - 1]] - h[[n, n]]) / two;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[[n, n]] += exshift;
+            h[[n - 1, n - 1]] += exshift;
+            x = h[[n, n]];
+            if q >= A::zero() {
+                // a real pair
+                z = if p >= A::zero() { p + z } else { p - z };
+                d[n - 1] = x + z;
+                d[n] = d[n - 1];
+                if z != A::zero() {
+                    d[n] = x - w / z;
+                }
+                e[n - 1] = A::zero();
+                e[n] = A::zero();
+                x = h[[n, n - 1]];
+                s = x.abs() + z.abs();
+                if s == A::zero() {
+                    // the block is zero, and already triangular
+                    remaining -= 2;
+                    iter = 0;
+                    continue;
+                }
+                p = x / s;
+                q = z / s;
+                r = p.hypot(q);
+                p /= r;
+                q /= r;
+                // row, column and accumulated modifications
+                for j in n - 1..nn {
+                    z = h[[n - 1, j]];
+                    h[[n - 1, j]] = q * z + p * h[[n, j]];
+                    h[[n, j]] = q * h[[n, j]] - p * z;
+                }
+                for i in 0..n + 1 {
+                    z = h[[i, n - 1]];
+                    h[[i, n - 1]] = q * z + p * h[[i, n]];
+                    h[[i, n]] = q * h[[i, n]] - p * z;
+                }
+                if vectors {
+                    for i in 0..nn {
+                        z = v[[i, n - 1]];
+                        v[[i, n - 1]] = q * z + p * v[[i, n]];
+                        v[[i, n]] = q * v[[i, n]] - p * z;
+                    }
+                }
+            } else {
+                // a complex pair
+                d[n - 1] = x + p;
+                d[n] = x + p;
+                e[n - 1] = z;
+                e[n] = -z;
+            }
+            remaining -= 2;
+            iter = 0;
+        } else {
+            // no convergence yet; form the shift
+            x = h[[n, n]];
+            y = h[[n - 1, n - 1]];
+            w = h[[n, n - 1]] * h[[n - 1, n]];
+
+            // Wilkinson's original ad hoc shift
+            if iter == 10 {
+                exshift += x;
+                for i in 0..n + 1 {
+                    h[[i, i]] -= x;
+                }
+                s = h[[n, n - 1]].abs() + h[[n - 1, n - 2]].abs();
+                x = A::from(0.75).unwrap() * s;
+                y = x;
+                w = A::from(-0.4375).unwrap() * s * s;
+            }
+            // MATLAB's new ad hoc shift
+            if iter == 30 {
+                s = (y - x) / two;
+                s = s * s + w;
+                if s > A::zero() {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / two + s);
+                    for i in 0..n + 1 {
+                        h[[i, i]] -= s;
+                    }
+                    exshift += s;
+                    x = A::from(0.964).unwrap();
+                    y = x;
+                    w = x;
+                }
+            }
+            iter += 1;
+            total_iter += 1;
+            if total_iter > 30 * nn {
+                return Err(ConvergenceError);
+            }
+
+            // look for two consecutive small subdiagonal elements
+            let mut m = n - 2;
+            loop {
+                z = h[[m, m]];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[[m + 1, m]] + h[[m, m + 1]];
+                q = h[[m + 1, m + 1]] - z - r - s;
+                r = h[[m + 2, m + 1]];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[[m, m - 1]].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[[m - 1, m - 1]].abs() + z.abs()
+                                        + h[[m + 1, m + 1]].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..n + 1 {
+                h[[i, i - 2]] = A::zero();
+                if i > m + 2 {
+                    h[[i, i - 3]] = A::zero();
+                }
+            }
+
+            // double QR step involving rows l..n and columns m..n
+            for k in m..n {
+                let notlast = k != n - 1;
+                if k != m {
+                    p = h[[k, k - 1]];
+                    q = h[[k + 1, k - 1]];
+                    r = if notlast { h[[k + 2, k - 1]] } else { A::zero() };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == A::zero() {
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+                s = (p * p + q * q + r * r).sqrt();
+                if p < A::zero() {
+                    s = -s;
+                }
+                if s == A::zero() {
+                    continue;
+                }
+                if k != m {
+                    h[[k, k - 1]] = -s * x;
+                } else if l != m {
+                    h[[k, k - 1]] = -h[[k, k - 1]];
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                z = r / s;
+                q /= p;
+                r /= p;
+
+                for j in k..nn {
+                    p = h[[k, j]] + q * h[[k + 1, j]];
+                    if notlast {
+                        p += r * h[[k + 2, j]];
+                        h[[k + 2, j]] -= p * z;
+                    }
+                    h[[k, j]] -= p * x;
+                    h[[k + 1, j]] -= p * y;
+                }
+                for i in 0..cmp::min(n, k + 3) + 1 {
+                    p = x * h[[i, k]] + y * h[[i, k + 1]];
+                    if notlast {
+                        p += z * h[[i, k + 2]];
+                        h[[i, k + 2]] -= p * r;
+                    }
+                    h[[i, k]] -= p;
+                    h[[i, k + 1]] -= p * q;
+                }
+                if vectors {
+                    for i in 0..nn {
+                        p = x * v[[i, k]] + y * v[[i, k + 1]];
+                        if notlast {
+                            p += z * v[[i, k + 2]];
+                            v[[i, k + 2]] -= p * r;
+                        }
+                        v[[i, k]] -= p;
+                        v[[i, k + 1]] -= p * q;
+                    }
+                }
+            }
+        }
+    }
+
+    if !vectors || norm == A::zero() {
+        return Ok(());
+    }
+
+    // back substitute to find the vectors of the upper triangular form
+    for n in (0..nn).rev() {
+        p = d[n];
+        q = e[n];
+        if q == A::zero() {
+            // a real vector
+            let mut l = n;
+            h[[n, n]] = A::one();
+            for i in (0..n).rev() {
+                w = h[[i, i]] - p;
+                r = (l..n + 1).fold(A::zero(), |acc, j| acc + h[[i, j]] * h[[j, n]]);
+                if e[i] < A::zero() {
+                    z = w;
+                    s = r;
+                    continue;
+                }
+                l = i;
+                if e[i] == A::zero() {
+                    h[[i, n]] = if w != A::zero() { -r / w } else { -r / (eps * norm) };
+                } else {
+                    // solve the real equations
+                    x = h[[i, i + 1]];
+                    y = h[[i + 1, i]];
+                    q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                    let t = (x * s - z * r) / q;
+                    h[[i, n]] = t;
+                    h[[i + 1, n]] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+                // overflow control
+                let t = h[[i, n]].abs();
+                if (eps * t) * t > A::one() {
+                    for j in i..n + 1 {
+                        h[[j, n]] /= t;
+                    }
+                }
+            }
+        } else if q < A::zero() {
+            // a complex vector, in columns n - 1 and n
+            let mut l = n - 1;
+            // the last vector component is imaginary so the matrix is triangular
+            if h[[n, n - 1]].abs() > h[[n - 1, n]].abs() {
+                h[[n - 1, n - 1]] = q / h[[n, n - 1]];
+                h[[n - 1, n]] = -(h[[n, n]] - p) / h[[n, n - 1]];
+            } else {
+                let (cr, ci) = cdiv(A::zero(), -h[[n - 1, n]], h[[n - 1, n - 1]] - p, q);
+                h[[n - 1, n - 1]] = cr;
+                h[[n - 1, n]] = ci;
+            }
+            h[[n, n - 1]] = A::zero();
+            h[[n, n]] = A::one();
+            for i in (0..n - 1).rev() {
+                let mut ra = A::zero();
+                let mut sa = A::zero();
+                for j in l..n + 1 {
+                    ra += h[[i, j]] * h[[j, n - 1]];
+                    sa += h[[i, j]] * h[[j, n]];
+                }
+                w = h[[i, i]] - p;
+                if e[i] < A::zero() {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                    continue;
+                }
+                l = i;
+                if e[i] == A::zero() {
+                    let (cr, ci) = cdiv(-ra, -sa, w, q);
+                    h[[i, n - 1]] = cr;
+                    h[[i, n]] = ci;
+                } else {
+                    // solve the complex equations
+                    x = h[[i, i + 1]];
+                    y = h[[i + 1, i]];
+                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                    let vi = (d[i] - p) * two * q;
+                    if vr == A::zero() && vi == A::zero() {
+                        vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+                    let (cr, ci) = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                    h[[i, n - 1]] = cr;
+                    h[[i, n]] = ci;
+                    if x.abs() > z.abs() + q.abs() {
+                        h[[i + 1, n - 1]] = (-ra - w * h[[i, n - 1]] + q * h[[i, n]]) / x;
+                        h[[i + 1, n]] = (-sa - w * h[[i, n]] - q * h[[i, n - 1]]) / x;
+                    } else {
+                        let (cr, ci) = cdiv(-r - y * h[[i, n - 1]], -s - y * h[[i, n]], z, q);
+                        h[[i + 1, n - 1]] = cr;
+                        h[[i + 1, n]] = ci;
+                    }
+                }
+                // overflow control
+                let t = h[[i, n - 1]].abs().max(h[[i, n]].abs());
+                if (eps * t) * t > A::one() {
+                    for j in i..n + 1 {
+                        h[[j, n - 1]] /= t;
+                        h[[j, n]] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    // back transformation to get the eigenvectors of the original matrix
+    for j in (0..nn).rev() {
+        for i in 0..nn {
+            z = (0..j + 1).fold(A::zero(), |acc, k| acc + v[[i, k]] * h[[k, j]]);
+            v[[i, j]] = z;
+        }
+    }
+    Ok(())
+}
+
+/// Complex division `(xr + i xi) / (yr + i yi)`, scaled to avoid overflow.
+fn cdiv<A: NdFloat>(xr: A, xi: A, yr: A, yi: A) -> (A, A) {
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
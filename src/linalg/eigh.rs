@@ -0,0 +1,306 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+
+use imp_prelude::*;
+use NdFloat;
+use super::eig::ConvergenceError;
+#[cfg(feature="lapack")]
+use super::lapack;
+
+/// Upper bound on the number of QL iterations per eigenvalue; convergence
+/// is cubic, so this is only reached for pathological input, which is
+/// reported as a `ConvergenceError`.
+const MAX_ITER: usize = 60;
+
+/// Compute the eigenvalues and eigenvectors of the symmetric matrix `a`.
+///
+/// Return `(w, v)` where `w` has the eigenvalues in increasing order and
+/// column `i` of the orthogonal matrix `v` is the eigenvector of `w[i]`, so
+/// that `a = v diag(w) vᵀ`.
+///
+/// The matrix is reduced to tridiagonal form with Householder reflections,
+/// followed by the implicit QL algorithm.
+///
+/// `a` must be symmetric; this is not checked.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the QL
+/// algorithm does not converge.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::eigh;
+///
+/// let a = arr2(&[[2., 1.],
+///                [1., 2.]]);
+/// let (w, v) = eigh(&a).unwrap();
+/// assert!(w.all_close(&arr1(&[1., 3.]), 1e-12));
+/// assert!(a.dot(&v).all_close(&(&v * &w), 1e-12));
+/// ```
+pub fn eigh<A, S>(a: &ArrayBase<S, Ix2>) -> Result<(Array1<A>, Array2<A>), ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    let (w, v) = try!(symmetric_eigen(a, true));
+    Ok((w, v.unwrap()))
+}
+
+/// Compute the eigenvalues of the symmetric matrix `a`, in increasing
+/// order.
+///
+/// This is faster than [`eigh`](fn.eigh.html), since the eigenvectors are
+/// not accumulated.
+///
+/// `a` must be symmetric; this is not checked.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the QL
+/// algorithm does not converge.
+///
+/// **Panics** if `a` is not square.
+pub fn eigvalsh<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array1<A>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    Ok(try!(symmetric_eigen(a, false)).0)
+}
+
+fn symmetric_eigen<A, S>(a: &ArrayBase<S, Ix2>, vectors: bool)
+    -> Result<(Array1<A>, Option<Array2<A>>), ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: eigh: matrix must be square, but has shape {:?}",
+            a.shape());
+    if !a.iter().all(|x| x.is_finite()) {
+        return Err(ConvergenceError);
+    }
+    let n = a.rows();
+    if n == 0 {
+        return Ok((Array1::zeros(0), if vectors { Some(Array2::zeros((0, 0))) } else { None }));
+    }
+    #[cfg(feature="lapack")]
+    {
+        if let Some(result) = lapack::eigh(a.view(), vectors) {
+            return Ok(result);
+        }
+    }
+    let mut v = a.to_owned();
+    let mut d = vec![A::zero(); n];
+    let mut e = vec![A::zero(); n];
+    tridiagonalize(&mut v, &mut d, &mut e, vectors);
+    try!(tridiagonal_ql(&mut d, &mut e, if vectors { Some(&mut v) } else { None }));
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| d[i].partial_cmp(&d[j]).unwrap_or(Ordering::Equal));
+    let w = order.iter().map(|&i| d[i]).collect();
+    let v = if vectors { Some(v.select(Axis(1), &order)) } else { None };
+    Ok((Array1::from_vec(w), v))
+}
+
+/// Reduce the symmetric matrix `v` to tridiagonal form with Householder
+/// reflections: on return `d` is the diagonal and `e[1..]` the
+/// subdiagonal. If `vectors`, `v` is overwritten with the accumulated
+/// orthogonal transformation.
+fn tridiagonalize<A: NdFloat>(v: &mut Array2<A>, d: &mut [A], e: &mut [A], vectors: bool) {
+    let n = d.len();
+    for j in 0..n {
+        d[j] = v[[n - 1, j]];
+    }
+    for i in (1..n).rev() {
+        let scale = d[..i].iter().fold(A::zero(), |acc, x| acc + x.abs());
+        let mut h = A::zero();
+        if scale == A::zero() {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[[i - 1, j]];
+                v[[i, j]] = A::zero();
+                v[[j, i]] = A::zero();
+            }
+        } else {
+            // generate the Householder vector
+            for x in &mut d[..i] {
+                *x /= scale;
+                h += *x * *x;
+            }
+            let mut f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > A::zero() {
+                g = -g;
+            }
+            e[i] = scale * g;
+            h -= f * g;
+            d[i - 1] = f - g;
+            for x in &mut e[..i] {
+                *x = A::zero();
+            }
+            // apply the similarity transformation to the remaining columns
+            for j in 0..i {
+                f = d[j];
+                v[[j, i]] = f;
+                g = e[j] + v[[j, j]] * f;
+                for k in j + 1..i {
+                    g += v[[k, j]] * d[k];
+                    e[k] += v[[k, j]] * f;
+                }
+                e[j] = g;
+            }
+            f = A::zero();
+            for j in 0..i {
+                e[j] /= h;
+                f += e[j] * d[j];
+            }
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] -= hh * d[j];
+            }
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+                for k in j..i {
+                    v[[k, j]] -= f * e[k] + g * d[k];
+                }
+                d[j] = v[[i - 1, j]];
+                v[[i, j]] = A::zero();
+            }
+        }
+        d[i] = h;
+    }
+
+    if !vectors {
+        for j in 0..n {
+            d[j] = v[[j, j]];
+        }
+        e[0] = A::zero();
+        return;
+    }
+    // accumulate the transformations
+    for i in 0..n - 1 {
+        v[[n - 1, i]] = v[[i, i]];
+        v[[i, i]] = A::one();
+        let h = d[i + 1];
+        if h != A::zero() {
+            for k in 0..i + 1 {
+                d[k] = v[[k, i + 1]] / h;
+            }
+            for j in 0..i + 1 {
+                let mut g = A::zero();
+                for k in 0..i + 1 {
+                    g += v[[k, i + 1]] * v[[k, j]];
+                }
+                for k in 0..i + 1 {
+                    v[[k, j]] -= g * d[k];
+                }
+            }
+        }
+        for k in 0..i + 1 {
+            v[[k, i + 1]] = A::zero();
+        }
+    }
+    for j in 0..n {
+        d[j] = v[[n - 1, j]];
+        v[[n - 1, j]] = A::zero();
+    }
+    v[[n - 1, n - 1]] = A::one();
+    e[0] = A::zero();
+}
+
+/// Diagonalize the symmetric tridiagonal matrix with diagonal `d` and
+/// subdiagonal `e[1..]` with the implicit QL algorithm. On return `d` has
+/// the eigenvalues; the rotations are accumulated into the columns of `v`,
+/// if given.
+///
+/// Return an error if an eigenvalue has not converged after `MAX_ITER`
+/// iterations.
+fn tridiagonal_ql<A: NdFloat>(d: &mut [A], e: &mut [A], mut v: Option<&mut Array2<A>>)
+    -> Result<(), ConvergenceError>
+{
+    let n = d.len();
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = A::zero();
+
+    let two = A::one() + A::one();
+    let eps = A::epsilon();
+    let mut f = A::zero();
+    let mut tst1 = A::zero();
+    for l in 0..n {
+        // find a small subdiagonal element
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > eps * tst1 {
+            m += 1;
+        }
+        if m > l {
+            let mut iter = 0;
+            loop {
+                if iter == MAX_ITER {
+                    return Err(ConvergenceError);
+                }
+                iter += 1;
+
+                // compute the implicit shift
+                let mut g = d[l];
+                let mut p = (d[l + 1] - g) / (two * e[l]);
+                let mut r = p.hypot(A::one());
+                if p < A::zero() {
+                    r = -r;
+                }
+                d[l] = e[l] / (p + r);
+                d[l + 1] = e[l] * (p + r);
+                let dl1 = d[l + 1];
+                let mut h = g - d[l];
+                for x in &mut d[l + 2..] {
+                    *x -= h;
+                }
+                f += h;
+
+                // implicit QL transformation
+                p = d[m];
+                let mut c = A::one();
+                let mut c2 = c;
+                let mut c3 = c;
+                let el1 = e[l + 1];
+                let mut s = A::zero();
+                let mut s2 = A::zero();
+                for i in (l..m).rev() {
+                    c3 = c2;
+                    c2 = c;
+                    s2 = s;
+                    g = c * e[i];
+                    h = c * p;
+                    r = p.hypot(e[i]);
+                    e[i + 1] = s * r;
+                    s = e[i] / r;
+                    c = p / r;
+                    p = c * d[i] - s * g;
+                    d[i + 1] = h + s * (c * g + s * d[i]);
+                    if let Some(ref mut v) = v {
+                        for k in 0..n {
+                            let h = v[[k, i + 1]];
+                            v[[k, i + 1]] = s * v[[k, i]] + c * h;
+                            v[[k, i]] = c * v[[k, i]] - s * h;
+                        }
+                    }
+                }
+                p = -s * s2 * c3 * el1 * e[l] / dl1;
+                e[l] = s * p;
+                d[l] = c * p;
+                if e[l].abs() <= eps * tst1 {
+                    break;
+                }
+            }
+        }
+        d[l] += f;
+        e[l] = A::zero();
+    }
+    Ok(())
+}
@@ -0,0 +1,194 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use error::{from_kind, ErrorKind, ShapeError};
+use LinalgScalar;
+
+/// Evaluate a sum of products over the axes of `operands`, as described
+/// by the subscripts in `spec` (Einstein summation convention).
+///
+/// `spec` has one comma separated group of axis labels per operand,
+/// optionally followed by `->` and the labels of the result's axes. Labels
+/// are ASCII letters, and each label in an operand's group names the
+/// corresponding axis of that operand; whitespace is ignored.
+///
+/// - Axes with the same label (in any operand) are iterated in lock step,
+///   so they must have the same length. A label repeated within one
+///   operand takes the diagonal of those axes.
+/// - Labels that do not appear in the result are summed over.
+/// - Without `->`, the result has the labels that appear exactly once,
+///   in alphabetical order.
+///
+/// The result is a new array in standard layout. For example `"ij,jk->ik"`
+/// is a matrix product, `"ii->"` the trace, `"ij->ji"` a transposition and
+/// `"bij,bjk->bik"` a batched matrix product.
+///
+/// Return an error if the axis lengths of the operands do not agree with
+/// `spec`.
+///
+/// **Panics** if `spec` is malformed: if it does not have one group of
+/// labels per operand, a group does not match the number of axes of its
+/// operand, or the result's labels are repeated or do not appear in any
+/// operand.
+///
+/// ```
+/// use ndarray::{arr0, arr1, arr2};
+/// use ndarray::linalg::einsum;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let b = arr2(&[[0., 1.],
+///                [1., 0.]]);
+/// let x = arr1(&[1., -1.]);
+///
+/// let ab = einsum("ij,jk->ik", &[a.view().into_dyn(), b.view().into_dyn()]).unwrap();
+/// assert_eq!(ab, a.dot(&b).into_dyn());
+///
+/// let trace = einsum("ii", &[a.view().into_dyn()]).unwrap();
+/// assert_eq!(trace, arr0(5.).into_dyn());
+///
+/// // bilinear form x^T A x
+/// let q = einsum("i,ij,j", &[x.view().into_dyn(), a.view().into_dyn(),
+///                            x.view().into_dyn()]).unwrap();
+/// assert_eq!(q, arr0(0.).into_dyn());
+/// ```
+pub fn einsum<A>(spec: &str, operands: &[ArrayViewD<A>]) -> Result<ArrayD<A>, ShapeError>
+    where A: LinalgScalar,
+{
+    let (inputs, output) = parse_spec(spec, operands.len());
+
+    // All distinct labels: the result's first, then the summed ones
+    let mut labels = output.clone();
+    for group in &inputs {
+        for &l in group {
+            if !labels.contains(&l) {
+                labels.push(l);
+            }
+        }
+    }
+
+    // Length of each label's axes, and the stride that a step along the
+    // label takes in each operand (the sum of the strides of its axes).
+    let mut len = vec![None; labels.len()];
+    let mut strides = vec![vec![0; labels.len()]; operands.len()];
+    for (k, (group, a)) in inputs.iter().zip(operands).enumerate() {
+        if group.len() != a.ndim() {
+            panic!("ndarray: einsum: subscripts {:?} do not match operand {} with {} axes",
+                   group.iter().collect::<String>(), k, a.ndim());
+        }
+        for (axis, &l) in group.iter().enumerate() {
+            let i = labels.iter().position(|&x| x == l).unwrap();
+            match len[i] {
+                Some(n) if n != a.shape()[axis] => {
+                    return Err(from_kind(ErrorKind::IncompatibleShape));
+                }
+                _ => len[i] = Some(a.shape()[axis]),
+            }
+            strides[k][i] += a.strides()[axis];
+        }
+    }
+    let len = len.into_iter().map(|n| n.unwrap()).collect::<Vec<_>>();
+
+    let out_shape = len[..output.len()].to_vec();
+    let mut out = ArrayD::zeros(out_shape);
+    // Summed labels have stride zero in the result
+    let mut out_strides = vec![0; labels.len()];
+    for (i, &s) in out.strides().iter().enumerate() {
+        out_strides[i] = s;
+    }
+
+    if len.contains(&0) {
+        return Ok(out);
+    }
+
+    // Visit each combination of label indices exactly once, keeping the
+    // element offsets of every operand and the result up to date.
+    let mut index = vec![0; labels.len()];
+    let mut offsets = vec![0isize; operands.len()];
+    let mut out_offset = 0isize;
+    let out_ptr = out.as_mut_ptr();
+    loop {
+        unsafe {
+            let mut prod = A::one();
+            for (a, &offset) in operands.iter().zip(&offsets) {
+                prod = prod * *a.as_ptr().offset(offset);
+            }
+            let elt = out_ptr.offset(out_offset);
+            *elt = *elt + prod;
+        }
+        // step the last label fastest
+        let mut i = labels.len();
+        loop {
+            if i == 0 {
+                return Ok(out);
+            }
+            i -= 1;
+            index[i] += 1;
+            for (offset, s) in offsets.iter_mut().zip(&strides) {
+                *offset += s[i];
+            }
+            out_offset += out_strides[i];
+            if index[i] < len[i] {
+                break;
+            }
+            let back = index[i] as isize;
+            index[i] = 0;
+            for (offset, s) in offsets.iter_mut().zip(&strides) {
+                *offset -= back * s[i];
+            }
+            out_offset -= back * out_strides[i];
+        }
+    }
+}
+
+/// Split `spec` into the labels of each operand and of the result.
+fn parse_spec(spec: &str, n_operands: usize) -> (Vec<Vec<char>>, Vec<char>) {
+    let spec = spec.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+    let (lhs, rhs) = match spec.find("->") {
+        Some(i) => (&spec[..i], Some(&spec[i + 2..])),
+        None => (&spec[..], None),
+    };
+    let inputs = lhs.split(',').map(|group| labels_of(group, &spec))
+                    .collect::<Vec<_>>();
+    if inputs.len() != n_operands {
+        panic!("ndarray: einsum: {:?} has {} operand subscripts, but {} operands were given",
+               spec, inputs.len(), n_operands);
+    }
+    let output = match rhs {
+        Some(group) => {
+            let output = labels_of(group, &spec);
+            for (i, l) in output.iter().enumerate() {
+                if output[..i].contains(l) || !inputs.iter().any(|g| g.contains(l)) {
+                    panic!("ndarray: einsum: invalid output subscript {:?} in {:?}", l, spec);
+                }
+            }
+            output
+        }
+        None => {
+            let mut output = Vec::new();
+            for &l in inputs.iter().flatten() {
+                let count = inputs.iter().flatten().filter(|&&x| x == l).count();
+                if count == 1 {
+                    output.push(l);
+                }
+            }
+            output.sort();
+            output
+        }
+    };
+    (inputs, output)
+}
+
+fn labels_of(group: &str, spec: &str) -> Vec<char> {
+    group.chars().inspect(|c| {
+        if !c.is_ascii_alphabetic() {
+            panic!("ndarray: einsum: invalid subscript {:?} in {:?}", c, spec);
+        }
+    }).collect()
+}
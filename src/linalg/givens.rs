@@ -0,0 +1,73 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use {NdFloat, Zip};
+
+/// Compute the Givens rotation `G = [[c, -s], [s, c]]` that maps `(f, g)`
+/// to `(r, 0)`, and return `(c, s, r)`.
+///
+/// `r` is the 2-norm of `(f, g)`, computed without overflow. If both are
+/// zero, the rotation is the identity.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{make_givens, apply_givens};
+/// use ndarray::Axis;
+///
+/// // zero the subdiagonal element of an upper Hessenberg matrix
+/// let mut a = arr2(&[[3f64, 1.],
+///                    [4., 2.]]);
+/// let (c, s, r) = make_givens(a[[0, 0]], a[[1, 0]]);
+/// apply_givens(&mut a, Axis(0), 0, 1, c, s);
+/// assert!((a[[0, 0]] - r).abs() < 1e-12);
+/// assert!(a[[1, 0]].abs() < 1e-12);
+/// ```
+pub fn make_givens<A: NdFloat>(f: A, g: A) -> (A, A, A) {
+    if f == A::zero() && g == A::zero() {
+        return (A::one(), A::zero(), A::zero());
+    }
+    let r = f.hypot(g);
+    (f / r, -g / r, r)
+}
+
+/// Apply the Givens rotation with `c` and `s` to the subviews `p` and `q`
+/// of `a` along `axis`, in place:
+/// `(a_p, a_q) ← (c a_p − s a_q, s a_p + c a_q)`.
+///
+/// With `Axis(0)` this rotates rows `p` and `q` of a matrix, which is `G a`
+/// in the plane of `p` and `q`; with `Axis(1)` it rotates columns, which is
+/// `a Gᵀ`. For a vector it rotates the elements `p` and `q`.
+///
+/// **Panics** if `p` and `q` are equal or out of bounds.
+pub fn apply_givens<A, S, D>(a: &mut ArrayBase<S, D>, axis: Axis, p: Ix, q: Ix, c: A, s: A)
+    where A: NdFloat,
+          S: DataMut<Elem=A>,
+          D: RemoveAxis,
+{
+    let len = a.len_of(axis);
+    assert!(p != q && p < len && q < len,
+            "ndarray: apply_givens: indices {} and {} for an axis of length {}",
+            p, q, len);
+    let rotate = |x: &mut A, y: &mut A| {
+        let (xp, yq) = (*x, *y);
+        *x = c * xp - s * yq;
+        *y = s * xp + c * yq;
+    };
+    if p < q {
+        let (top, bottom) = a.view_mut().split_at(axis, q);
+        Zip::from(top.into_subview(axis, p))
+            .and(bottom.into_subview(axis, 0))
+            .apply(rotate);
+    } else {
+        let (top, bottom) = a.view_mut().split_at(axis, p);
+        Zip::from(bottom.into_subview(axis, 0))
+            .and(top.into_subview(axis, q))
+            .apply(rotate);
+    }
+}
@@ -0,0 +1,98 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use NdFloat;
+
+/// Turn `x` into the Householder vector of the reflector `H = I - tau v vᵀ`
+/// that maps `x` to `beta e₀`, and return `(tau, beta)`.
+///
+/// On return, `x[0]` is `beta` and `x[1..]` is `v[1..]`; the first element
+/// of `v` is one. If `x[1..]` is already zero, `tau` is zero (`H = I`).
+/// `H` is symmetric and orthogonal, and `|beta|` is the 2-norm of `x`.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::linalg::{make_householder, apply_householder_left};
+///
+/// let x = arr1(&[3f64, 4.]);
+/// let mut v = x.clone();
+/// let (tau, beta) = make_householder(v.view_mut());
+/// assert!((beta.abs() - 5.).abs() < 1e-12);
+///
+/// // H x = beta e₀
+/// v[0] = 1.;
+/// let mut y = x.into_shape((2, 1)).unwrap();
+/// apply_householder_left(v.view(), tau, y.view_mut());
+/// assert!(y.column(0).all_close(&arr1(&[beta, 0.]), 1e-12));
+/// ```
+pub fn make_householder<A>(x: ArrayViewMut1<A>) -> (A, A)
+    where A: NdFloat,
+{
+    if x.len() == 0 {
+        return (A::zero(), A::zero());
+    }
+    let (mut head, mut tail) = x.split_at(Axis(0), 1);
+    let x0 = head[0];
+    let tail_norm = tail.norm_l2();
+    if tail_norm == A::zero() {
+        return (A::zero(), x0);
+    }
+    let norm = x0.hypot(tail_norm);
+    let beta = if x0 >= A::zero() { -norm } else { norm };
+    let tau = (beta - x0) / beta;
+    let scale = A::one() / (x0 - beta);
+    tail.mapv_inplace(|y| y * scale);
+    head[0] = beta;
+    (tau, beta)
+}
+
+/// Apply the reflector `I - tau v vᵀ` to `a` from the left, in place.
+///
+/// `v` has as many elements as `a` has rows.
+///
+/// **Panics** if the length of `v` is not the number of rows of `a`.
+pub fn apply_householder_left<A>(v: ArrayView1<A>, tau: A, mut a: ArrayViewMut2<A>)
+    where A: NdFloat,
+{
+    assert!(v.len() == a.rows(),
+            "ndarray: apply_householder_left: vector of length {} for a matrix of shape {:?}",
+            v.len(), a.shape());
+    if tau == A::zero() {
+        return;
+    }
+    // w = vᵀ a, then a -= tau v w
+    let mut w = Array1::zeros(a.cols());
+    for (&vi, row) in v.iter().zip(a.outer_iter()) {
+        w.scaled_add(vi, &row);
+    }
+    for (&vi, mut row) in v.iter().zip(a.outer_iter_mut()) {
+        row.scaled_add(-tau * vi, &w);
+    }
+}
+
+/// Apply the reflector `I - tau v vᵀ` to `a` from the right, in place.
+///
+/// `v` has as many elements as `a` has columns.
+///
+/// **Panics** if the length of `v` is not the number of columns of `a`.
+pub fn apply_householder_right<A>(v: ArrayView1<A>, tau: A, mut a: ArrayViewMut2<A>)
+    where A: NdFloat,
+{
+    assert!(v.len() == a.cols(),
+            "ndarray: apply_householder_right: vector of length {} for a matrix of shape {:?}",
+            v.len(), a.shape());
+    if tau == A::zero() {
+        return;
+    }
+    // w = a v, then a -= tau w vᵀ
+    let w = a.dot(&v);
+    for (&wi, mut row) in w.iter().zip(a.outer_iter_mut()) {
+        row.scaled_add(-tau * wi, &v);
+    }
+}
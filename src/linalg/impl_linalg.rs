@@ -6,7 +6,6 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use itertools::free::enumerate;
 
 use imp_prelude::*;
 use numeric_util;
@@ -17,7 +16,6 @@ use {
 
 use std::any::TypeId;
 
-#[cfg(feature="blas")]
 use std::cmp;
 #[cfg(feature="blas")]
 use std::mem::swap;
@@ -48,7 +46,18 @@ impl<A, S> ArrayBase<S, Ix1>
     /// The dot product is a sum of the elementwise products (no conjugation
     /// of complex operands, and thus not their inner product).
     ///
+    /// Contiguous inputs use an unrolled loop that the compiler can
+    /// vectorize; other inputs are summed with blocked accumulators.
+    ///
     /// **Panics** if the arrays are not of the same length.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1., 2., 3.]);
+    /// let b = arr1(&[4., 5., 6.]);
+    /// assert_eq!(a.dot(&b), 32.);
+    /// ```
     pub fn dot<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
         where S2: Data<Elem=A>,
               A: LinalgScalar,
@@ -56,6 +65,77 @@ impl<A, S> ArrayBase<S, Ix1>
         self.dot_impl(rhs)
     }
 
+    /// Compute the outer product of one-dimensional arrays.
+    ///
+    /// If `self` has length *M* and `rhs` has length *N*, return an array
+    /// with shape *M* × *N* whose element at `[i, j]` is `self[i] * rhs[j]`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr1(&[1., 2.]);
+    /// let b = arr1(&[1., 0., -1.]);
+    /// assert_eq!(a.outer(&b), arr2(&[[1., 0., -1.],
+    ///                                [2., 0., -2.]]));
+    /// ```
+    pub fn outer<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> Array2<A>
+        where S2: Data<Elem=A>,
+              A: LinalgScalar,
+    {
+        let (m, n) = (self.len(), rhs.len());
+        if m.checked_mul(n).is_none() {
+            panic!("ndarray: shape {} × {} overflows type range", m, n);
+        }
+        let mut v = Vec::with_capacity(m * n);
+        for &x in self.iter() {
+            for &y in rhs.iter() {
+                v.push(x * y);
+            }
+        }
+        unsafe {
+            ArrayBase::from_shape_vec_unchecked((m, n), v)
+        }
+    }
+
+    /// Compute the cross product of vectors of length 3.
+    ///
+    /// Vectors of length 2 are treated as lying in the *xy* plane (with a
+    /// zero *z* component), so that their cross product is `[0, 0, z]`.
+    ///
+    /// **Panics** if the arrays are not both of length 3 or both of length 2.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let x = arr1(&[1., 0., 0.]);
+    /// let y = arr1(&[0., 1., 0.]);
+    /// assert_eq!(x.cross(&y), arr1(&[0., 0., 1.]));
+    ///
+    /// let a = arr1(&[1., 2.]);
+    /// let b = arr1(&[3., 4.]);
+    /// assert_eq!(a.cross(&b), arr1(&[0., 0., -2.]));
+    /// ```
+    pub fn cross<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> Array1<A>
+        where S2: Data<Elem=A>,
+              A: LinalgScalar,
+    {
+        let (a, b) = (self, rhs);
+        match (a.len(), b.len()) {
+            (3, 3) => {
+                arr1(&[a[1] * b[2] - a[2] * b[1],
+                       a[2] * b[0] - a[0] * b[2],
+                       a[0] * b[1] - a[1] * b[0]])
+            }
+            (2, 2) => {
+                arr1(&[A::zero(), A::zero(), a[0] * b[1] - a[1] * b[0]])
+            }
+            (m, n) => {
+                panic!("ndarray: cross product requires vectors of length 3 or 2, \
+                        got lengths {} and {}", m, n)
+            }
+        }
+    }
+
     fn dot_generic<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
         where S2: Data<Elem=A>,
               A: LinalgScalar,
@@ -67,13 +147,26 @@ impl<A, S> ArrayBase<S, Ix1>
                 return numeric_util::unrolled_dot(self_s, rhs_s);
             }
         }
-        let mut sum = A::zero();
-        for i in 0..self.len() {
-            unsafe {
-                sum = sum.clone() + self.uget(i).clone() * rhs.uget(i).clone();
+        // fourfold blocked accumulators for strided inputs
+        let len = self.len();
+        let (mut p0, mut p1, mut p2, mut p3) =
+            (A::zero(), A::zero(), A::zero(), A::zero());
+        let mut i = 0;
+        unsafe {
+            while i + 4 <= len {
+                p0 = p0 + *self.uget(i) * *rhs.uget(i);
+                p1 = p1 + *self.uget(i + 1) * *rhs.uget(i + 1);
+                p2 = p2 + *self.uget(i + 2) * *rhs.uget(i + 2);
+                p3 = p3 + *self.uget(i + 3) * *rhs.uget(i + 3);
+                i += 4;
             }
+            let mut sum = (p0 + p2) + (p1 + p3);
+            while i < len {
+                sum = sum + *self.uget(i) * *rhs.uget(i);
+                i += 1;
+            }
+            sum
         }
-        sum
     }
 
     #[cfg(not(feature="blas"))]
@@ -263,16 +356,12 @@ impl<A, S, S2> Dot<ArrayBase<S2, Ix1>> for ArrayBase<S, Ix2>
         unsafe {
             res_elems.set_len(m as usize);
         }
-        for (i, rr) in enumerate(&mut res_elems) {
-            unsafe {
-                *rr = (0..a).fold(A::zero(),
-                    move |s, k| s + *self.uget(Ix2(i, k)) * *rhs.uget(k)
-                );
-            }
-        }
-        unsafe {
+        let mut res = unsafe {
             ArrayBase::from_shape_vec_unchecked(m, res_elems)
-        }
+        };
+        // beta is zero so the uninitialized elements are never read
+        mat_vec_impl(A::one(), &self.view(), &rhs.view(), A::zero(), &mut res.view_mut());
+        res
     }
 }
 
@@ -287,14 +376,82 @@ impl<A, S, D> ArrayBase<S, D>
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
     /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1., 2.],
+    ///                    [3., 4.]]);
+    /// a.scaled_add(2., &arr1(&[1., -1.]));
+    /// assert_eq!(a, arr2(&[[3., 0.],
+    ///                      [5., 2.]]));
+    /// ```
     pub fn scaled_add<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
         where S: DataMut,
               S2: Data<Elem=A>,
               A: LinalgScalar,
               E: Dimension,
+    {
+        self.scaled_add_impl(alpha, rhs);
+    }
+
+    fn scaled_add_generic<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
+        where S: DataMut,
+              S2: Data<Elem=A>,
+              A: LinalgScalar,
+              E: Dimension,
     {
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
+
+    #[cfg(not(feature="blas"))]
+    fn scaled_add_impl<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
+        where S: DataMut,
+              S2: Data<Elem=A>,
+              A: LinalgScalar,
+              E: Dimension,
+    {
+        self.scaled_add_generic(alpha, rhs);
+    }
+
+    #[cfg(feature="blas")]
+    fn scaled_add_impl<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
+        where S: DataMut,
+              S2: Data<Elem=A>,
+              A: LinalgScalar,
+              E: Dimension,
+    {
+        // Use only for large enough arrays whose elements are contiguous and
+        // in the same memory order, so that they can be treated as vectors.
+        if self.len() >= DOT_BLAS_CUTOFF && self.len() <= blas_index::max_value() as usize
+            && self.shape() == rhs.shape() && self.strides() == rhs.strides()
+        {
+            macro_rules! axpy {
+                ($ty:ty, $func:ident) => {{
+            if same_type::<A, $ty>() {
+                if let Some(x) = rhs.as_slice_memory_order() {
+                    if let Some(y) = self.as_slice_memory_order_mut() {
+                        unsafe {
+                            blas_sys::c::$func(
+                                y.len() as blas_index,
+                                cast_as(&alpha),
+                                x.as_ptr() as *const $ty,
+                                1,
+                                y.as_mut_ptr() as *mut $ty,
+                                1);
+                        }
+                        return;
+                    }
+                }
+            }
+                }}
+            }
+
+            axpy!{f32, cblas_saxpy};
+            axpy!{f64, cblas_daxpy};
+        }
+        self.scaled_add_generic(alpha, rhs);
+    }
 }
 
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
@@ -443,28 +600,112 @@ fn mat_mul_general<A>(alpha: A,
             );
         }
     } else {
-        // initialize memory if beta is zero
-        if beta.is_zero() {
-            c.fill(beta);
+        mat_mul_blocked(alpha, lhs, rhs, beta, c);
+    }
+}
+
+/// Side of the cache tiles of `c` (rows and columns) in `mat_mul_blocked`.
+const TILE_MN: usize = 64;
+/// Length of the cache tiles along the shared axis in `mat_mul_blocked`.
+const TILE_K: usize = 256;
+/// Side of the register blocks of `c` in `mat_mul_blocked`.
+const MR: usize = 4;
+
+/// C ← α A B + β C for element types without a specialized kernel.
+///
+/// The product is computed in tiles that fit in cache, and each tile in
+/// MR × MR blocks of accumulators that are kept in registers. Operands are
+/// read in place using their strides.
+fn mat_mul_blocked<A>(alpha: A,
+                      lhs: &ArrayView2<A>,
+                      rhs: &ArrayView2<A>,
+                      beta: A,
+                      c: &mut ArrayViewMut2<A>)
+    where A: LinalgScalar,
+{
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    // initialize memory if beta is zero
+    if beta.is_zero() {
+        c.fill(beta);
+    } else {
+        c.mapv_inplace(|x| x * beta);
+    }
+
+    let mut i0 = 0;
+    while i0 < m {
+        let i1 = cmp::min(i0 + TILE_MN, m);
+        let mut p0 = 0;
+        while p0 < k {
+            let p1 = cmp::min(p0 + TILE_K, k);
+            let mut j0 = 0;
+            while j0 < n {
+                let j1 = cmp::min(j0 + TILE_MN, n);
+                let mut i = i0;
+                while i < i1 {
+                    let mr = cmp::min(MR, i1 - i);
+                    let mut j = j0;
+                    while j < j1 {
+                        let nr = cmp::min(MR, j1 - j);
+                        unsafe {
+                            mat_mul_kernel(alpha, lhs, rhs, c, (i, mr), (j, nr), (p0, p1));
+                        }
+                        j += nr;
+                    }
+                    i += mr;
+                }
+                j0 = j1;
+            }
+            p0 = p1;
         }
+        i0 = i1;
+    }
+}
 
-        let mut i = 0;
-        let mut j = 0;
-        loop {
-            unsafe {
-                let elt = c.uget_mut((i, j));
-                *elt = *elt * beta + alpha * (0..k).fold(A::zero(),
-                    move |s, x| s + *lhs.uget((i, x)) * *rhs.uget((x, j)));
+/// Add α times the product of rows `i..i + mr` of `lhs` and columns
+/// `j..j + nr` of `rhs`, over the shared axis range `p0..p1`, to `c`.
+///
+/// Unsafe because the ranges are not bounds checked.
+#[inline(always)]
+unsafe fn mat_mul_kernel<A>(alpha: A,
+                            lhs: &ArrayView2<A>,
+                            rhs: &ArrayView2<A>,
+                            c: &mut ArrayViewMut2<A>,
+                            (i, mr): (usize, usize),
+                            (j, nr): (usize, usize),
+                            (p0, p1): (usize, usize))
+    where A: LinalgScalar,
+{
+    let mut acc = [[A::zero(); MR]; MR];
+    if mr == MR && nr == MR {
+        // full block: fixed trip counts let the loops unroll
+        for p in p0..p1 {
+            let mut a = [A::zero(); MR];
+            let mut b = [A::zero(); MR];
+            for r in 0..MR {
+                a[r] = *lhs.uget((i + r, p));
+                b[r] = *rhs.uget((p, j + r));
             }
-            j += 1;
-            if j == n {
-                j = 0;
-                i += 1;
-                if i == m {
-                    break;
+            for (acc_r, &ar) in acc.iter_mut().zip(&a) {
+                for (x, &bs) in acc_r.iter_mut().zip(&b) {
+                    *x = *x + ar * bs;
                 }
             }
         }
+    } else {
+        for p in p0..p1 {
+            for (r, acc_r) in acc[..mr].iter_mut().enumerate() {
+                let a = *lhs.uget((i + r, p));
+                for (s, x) in acc_r[..nr].iter_mut().enumerate() {
+                    *x = *x + a * *rhs.uget((p, j + s));
+                }
+            }
+        }
+    }
+    for (r, acc_r) in acc[..mr].iter().enumerate() {
+        for (s, &x) in acc_r[..nr].iter().enumerate() {
+            let elt = c.uget_mut((i + r, j + s));
+            *elt = *elt + alpha * x;
+        }
     }
 }
 
@@ -495,6 +736,298 @@ pub fn general_mat_mul<A, S1, S2, S3>(alpha: A,
     }
 }
 
+/// General matrix-vector multiplication.
+///
+/// Compute y ← α A x + β y
+///
+/// The array shapes must agree in the way that
+/// if `a` is *M* × *N*, then `x` has length *N* and `y` has length *M*.
+///
+/// Each element of `y` is computed as the dot product of a row of `a`
+/// with `x`; when `beta` is zero, `y` is only written, never read.
+///
+/// ***Panics*** if array shapes are not compatible
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::general_mat_vec_mul;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let x = arr1(&[1., -1.]);
+/// let mut y = arr1(&[1., 1.]);
+/// general_mat_vec_mul(2., &a, &x, 1., &mut y);
+/// assert_eq!(y, arr1(&[-1., -1.]));
+/// ```
+pub fn general_mat_vec_mul<A, S1, S2, S3>(alpha: A,
+                                          a: &ArrayBase<S1, Ix2>,
+                                          x: &ArrayBase<S2, Ix1>,
+                                          beta: A,
+                                          y: &mut ArrayBase<S3, Ix1>)
+    where S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          S3: DataMut<Elem=A>,
+          A: LinalgScalar,
+{
+    let ((m, k), k2) = (a.dim(), x.dim());
+    let m2 = y.dim();
+    if k != k2 || m != m2 {
+        general_dot_shape_error(m, k, k2, 1, m2, 1);
+    } else {
+        mat_vec_impl(alpha, &a.view(), &x.view(), beta, &mut y.view_mut());
+    }
+}
+
+// mat_vec_impl uses ArrayView arguments to send all array kinds into
+// the same instantiated implementation.
+#[cfg(not(feature="blas"))]
+use self::mat_vec_general as mat_vec_impl;
+
+#[cfg(feature="blas")]
+fn mat_vec_impl<A>(alpha: A,
+                   a: &ArrayView2<A>,
+                   x: &ArrayView1<A>,
+                   beta: A,
+                   y: &mut ArrayViewMut1<A>)
+    where A: LinalgScalar,
+{
+    // size cutoff for using BLAS
+    let cut = GEMM_BLAS_CUTOFF;
+    let (m, k) = a.dim();
+    if !(m > cut || k > cut) ||
+        !(same_type::<A, f32>() || same_type::<A, f64>()) {
+        return mat_vec_general(alpha, a, x, beta, y);
+    }
+    // Use the transpose of an f-order matrix
+    let (a_, trans) = if a.strides()[0] == 1 && m > 1 {
+        (a.t(), CblasTrans)
+    } else {
+        (a.view(), CblasNoTrans)
+    };
+    macro_rules! gemv {
+        ($ty:ty, $gemv:ident) => {
+            if blas_row_major_2d::<$ty, _>(&a_)
+                && blas_compat_1d::<$ty, _>(x) && x.strides()[0] != 0
+                && blas_compat_1d::<$ty, _>(y) && y.strides()[0] != 0
+            {
+                let (rows, cols) = a_.dim();
+                // adjust stride, it may be 1 for a single row
+                let a_stride = cmp::max(a_.strides()[0] as blas_index, cols as blas_index);
+                unsafe {
+                    let (x_ptr, _, incx) = blas_1d_params(x.ptr, x.len(), x.strides()[0]);
+                    let (y_ptr, _, incy) = blas_1d_params(y.ptr, y.len(), y.strides()[0]);
+                    // gemv is y ← αA^Op x + βy
+                    blas_sys::c::$gemv(
+                        CblasRowMajor,
+                        trans,
+                        rows as blas_index, // m, rows of a
+                        cols as blas_index, // n, cols of a
+                        cast_as(&alpha),    // alpha
+                        a_.ptr as *const _, // a
+                        a_stride,           // lda
+                        x_ptr as *const _,  // x
+                        incx,
+                        cast_as(&beta),     // beta
+                        y_ptr as *mut _,    // y
+                        incy,
+                    );
+                }
+                return;
+            }
+        }
+    }
+    gemv!(f32, cblas_sgemv);
+    gemv!(f64, cblas_dgemv);
+    mat_vec_general(alpha, a, x, beta, y)
+}
+
+/// y ← α A x + β y
+fn mat_vec_general<A>(alpha: A,
+                      a: &ArrayView2<A>,
+                      x: &ArrayView1<A>,
+                      beta: A,
+                      y: &mut ArrayViewMut1<A>)
+    where A: LinalgScalar,
+{
+    for (row, elt) in a.outer_iter().zip(y) {
+        let d = row.dot(x);
+        if beta.is_zero() {
+            *elt = alpha * d;
+        } else {
+            *elt = alpha * d + beta * *elt;
+        }
+    }
+}
+
+/// Kronecker product of matrices.
+///
+/// If `a` is *M* × *N* and `b` is *P* × *Q*, return the *MP* × *NQ* matrix
+/// made of the blocks `a[[i, j]] * b`, where block `(i, j)` starts at
+/// row `i * P` and column `j * Q`.
+///
+/// The result is written row by row, in standard layout.
+///
+/// **Panics** if the shape of the result overflows `usize`.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::kron;
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// let b = arr2(&[[0, 1],
+///                [1, 0]]);
+/// assert_eq!(kron(&a, &b), arr2(&[[0, 1, 0, 2],
+///                                 [1, 0, 2, 0],
+///                                 [0, 3, 0, 4],
+///                                 [3, 0, 4, 0]]));
+/// ```
+pub fn kron<A, S1, S2>(a: &ArrayBase<S1, Ix2>, b: &ArrayBase<S2, Ix2>) -> Array2<A>
+    where S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          A: LinalgScalar,
+{
+    let ((m, n), (p, q)) = (a.dim(), b.dim());
+    let rows = m.checked_mul(p);
+    let cols = n.checked_mul(q);
+    let len = rows.and_then(|r| cols.and_then(|c| r.checked_mul(c)));
+    let (rows, cols) = match (rows, cols, len) {
+        (Some(r), Some(c), Some(_)) => (r, c),
+        _ => panic!("ndarray: kron: shape ({} × {}) × ({} × {}) overflows type range",
+                    m, p, n, q),
+    };
+    let mut v = Vec::with_capacity(rows * cols);
+    for a_row in a.inner_iter() {
+        for b_row in b.inner_iter() {
+            for &x in a_row {
+                for &y in b_row {
+                    v.push(x * y);
+                }
+            }
+        }
+    }
+    unsafe {
+        ArrayBase::from_shape_vec_unchecked((rows, cols), v)
+    }
+}
+
+/// Tensor product contracting the axes `a_axes` of `a` with the axes
+/// `b_axes` of `b`.
+///
+/// The contracted axes are paired up in order; each pair must have the same
+/// length. The result has the remaining axes of `a` followed by the
+/// remaining axes of `b`, each in their original order.
+///
+/// The operands are permuted and viewed as matrices, which are then
+/// multiplied with the usual matrix multiplication. An operand is only
+/// copied if its permuted axes can not be viewed as a matrix in place.
+///
+/// **Panics** if `a_axes` and `b_axes` have different lengths, an axis is
+/// out of bounds or repeated, or the lengths of paired axes are not equal.
+///
+/// ```
+/// use ndarray::{arr2, Array, Axis};
+/// use ndarray::linalg::tensordot;
+///
+/// let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+/// let b = Array::from_iter(0..6).into_shape((3, 2)).unwrap();
+///
+/// // contract axis 1 of `a` with axis 0 of `b`, and axis 0 of `a`
+/// // with axis 1 of `b`
+/// let c = tensordot(&a, &b, &[Axis(1), Axis(0)], &[Axis(0), Axis(1)]);
+/// assert_eq!(c.shape(), &[4]);
+///
+/// // with no contracted axes, the result is the outer product
+/// let c = tensordot(&b, &b, &[], &[]);
+/// assert_eq!(c.shape(), &[3, 2, 3, 2]);
+///
+/// // matrix multiplication
+/// let m = arr2(&[[1, 2], [3, 4]]);
+/// let c = tensordot(&m, &m, &[Axis(1)], &[Axis(0)]);
+/// assert_eq!(c, m.dot(&m).into_dyn());
+/// ```
+pub fn tensordot<A, S1, S2, D1, D2>(a: &ArrayBase<S1, D1>,
+                                    b: &ArrayBase<S2, D2>,
+                                    a_axes: &[Axis],
+                                    b_axes: &[Axis]) -> ArrayD<A>
+    where S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D1: Dimension,
+          D2: Dimension,
+          A: LinalgScalar,
+{
+    if a_axes.len() != b_axes.len() {
+        panic!("ndarray: tensordot: {} axes of the first operand can not be paired with \
+                {} axes of the second", a_axes.len(), b_axes.len());
+    }
+    for (ax, bx) in a_axes.iter().zip(b_axes) {
+        if a.len_of(*ax) != b.len_of(*bx) {
+            panic!("ndarray: tensordot: contracted axes {} and {} have lengths {} and {}",
+                   ax.axis(), bx.axis(), a.len_of(*ax), b.len_of(*bx));
+        }
+    }
+    // `a` as free × contracted axes, `b` as contracted × free axes
+    let a_free = free_axes(a.ndim(), a_axes);
+    let b_free = free_axes(b.ndim(), b_axes);
+    let a_perm = a_free.iter().cloned().chain(a_axes.iter().map(|ax| ax.axis()))
+                       .collect::<Vec<_>>();
+    let b_perm = b_axes.iter().map(|ax| ax.axis()).chain(b_free.iter().cloned())
+                       .collect::<Vec<_>>();
+    let a_ = permuted_view(a, &a_perm);
+    let b_ = permuted_view(b, &b_perm);
+    let m = a_free.iter().fold(1, |m, &i| m * a.shape()[i]);
+    let n = b_free.iter().fold(1, |n, &i| n * b.shape()[i]);
+    let k = a_axes.iter().fold(1, |k, ax| k * a.len_of(*ax));
+
+    let mut shape = a_free.iter().map(|&i| a.shape()[i]).collect::<Vec<_>>();
+    shape.extend(b_free.iter().map(|&i| b.shape()[i]));
+    let c = with_matrix_view(a_, (m, k), |a2| {
+        with_matrix_view(b_, (k, n), |b2| a2.dot(&b2))
+    });
+    c.into_shape(shape).unwrap()
+}
+
+/// Return the axes of an array with `ndim` axes that are not in `axes`.
+///
+/// **Panics** if an axis of `axes` is out of bounds or repeated.
+fn free_axes(ndim: usize, axes: &[Axis]) -> Vec<usize> {
+    let mut free = vec![true; ndim];
+    for ax in axes {
+        if ax.axis() >= ndim || !free[ax.axis()] {
+            panic!("ndarray: tensordot: axis {} is out of bounds or repeated", ax.axis());
+        }
+        free[ax.axis()] = false;
+    }
+    (0..ndim).filter(|&i| free[i]).collect()
+}
+
+/// Return a view of `a` with its axes in the order of `perm`.
+fn permuted_view<'a, A, S, D>(a: &'a ArrayBase<S, D>, perm: &[usize]) -> ArrayViewD<'a, A>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    let mut v = a.view().into_dyn();
+    for (i, &p) in perm.iter().enumerate() {
+        v.dim.slice_mut()[i] = a.shape()[p];
+        v.strides.slice_mut()[i] = a.strides()[p] as Ix;
+    }
+    v
+}
+
+/// Call `f` with `a` viewed as a matrix of shape `shape`, copying the
+/// elements of `a` into standard layout first if needed.
+fn with_matrix_view<A, F, R>(a: ArrayViewD<A>, shape: (Ix, Ix), f: F) -> R
+    where A: Copy,
+          F: FnOnce(ArrayView2<A>) -> R,
+{
+    if a.is_standard_layout() {
+        f(a.into_shape(shape).unwrap())
+    } else {
+        let v = a.iter().cloned().collect::<Vec<_>>();
+        f(ArrayView::from_shape(shape, &v).unwrap())
+    }
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
 fn same_type<A: 'static, B: 'static>() -> bool {
@@ -0,0 +1,280 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Decompositions through LAPACK, used for `f32` and `f64` elements when
+//! the `lapack` crate feature is enabled.
+//!
+//! Each function returns `None` if the element type is not supported, or
+//! if LAPACK fails, and the caller falls back to the pure Rust
+//! implementation.
+
+use std::any::TypeId;
+use std::cmp;
+use std::os::raw::{c_char, c_int};
+
+use imp_prelude::*;
+use {NdFloat, ShapeBuilder};
+
+// Fortran LAPACK routines; the library is linked through `lapack-sys`.
+extern "C" {
+    fn sgetrf_(m: *const c_int, n: *const c_int, a: *mut f32, lda: *const c_int,
+               ipiv: *mut c_int, info: *mut c_int);
+    fn dgetrf_(m: *const c_int, n: *const c_int, a: *mut f64, lda: *const c_int,
+               ipiv: *mut c_int, info: *mut c_int);
+
+    fn sgeqrf_(m: *const c_int, n: *const c_int, a: *mut f32, lda: *const c_int,
+               tau: *mut f32, work: *mut f32, lwork: *const c_int, info: *mut c_int);
+    fn dgeqrf_(m: *const c_int, n: *const c_int, a: *mut f64, lda: *const c_int,
+               tau: *mut f64, work: *mut f64, lwork: *const c_int, info: *mut c_int);
+
+    fn sgesdd_(jobz: *const c_char, m: *const c_int, n: *const c_int, a: *mut f32,
+               lda: *const c_int, s: *mut f32, u: *mut f32, ldu: *const c_int, vt: *mut f32,
+               ldvt: *const c_int, work: *mut f32, lwork: *const c_int, iwork: *mut c_int,
+               info: *mut c_int);
+    fn dgesdd_(jobz: *const c_char, m: *const c_int, n: *const c_int, a: *mut f64,
+               lda: *const c_int, s: *mut f64, u: *mut f64, ldu: *const c_int, vt: *mut f64,
+               ldvt: *const c_int, work: *mut f64, lwork: *const c_int, iwork: *mut c_int,
+               info: *mut c_int);
+
+    fn ssyevd_(jobz: *const c_char, uplo: *const c_char, n: *const c_int, a: *mut f32,
+               lda: *const c_int, w: *mut f32, work: *mut f32, lwork: *const c_int,
+               iwork: *mut c_int, liwork: *const c_int, info: *mut c_int);
+    fn dsyevd_(jobz: *const c_char, uplo: *const c_char, n: *const c_int, a: *mut f64,
+               lda: *const c_int, w: *mut f64, work: *mut f64, lwork: *const c_int,
+               iwork: *mut c_int, liwork: *const c_int, info: *mut c_int);
+
+    fn sgeev_(jobvl: *const c_char, jobvr: *const c_char, n: *const c_int, a: *mut f32,
+              lda: *const c_int, wr: *mut f32, wi: *mut f32, vl: *mut f32, ldvl: *const c_int,
+              vr: *mut f32, ldvr: *const c_int, work: *mut f32, lwork: *const c_int,
+              info: *mut c_int);
+    fn dgeev_(jobvl: *const c_char, jobvr: *const c_char, n: *const c_int, a: *mut f64,
+              lda: *const c_int, wr: *mut f64, wi: *mut f64, vl: *mut f64, ldvl: *const c_int,
+              vr: *mut f64, ldvr: *const c_int, work: *mut f64, lwork: *const c_int,
+              info: *mut c_int);
+}
+
+/// Call the single or double precision LAPACK routine, depending on `A`;
+/// the element pointers are cast to the routine's type.
+macro_rules! lapack {
+    ($A:ty, $single:ident, $double:ident ($($arg:expr),*)) => {
+        if same_type::<$A, f32>() {
+            $single($($arg as _),*)
+        } else {
+            $double($($arg as _),*)
+        }
+    }
+}
+
+fn same_type<A: 'static, B: 'static>() -> bool {
+    TypeId::of::<A>() == TypeId::of::<B>()
+}
+
+/// Return `true` if LAPACK can be used for `A` and matrices of shape `dim`.
+fn supported<A: 'static>((m, n): (Ix, Ix)) -> bool {
+    (same_type::<A, f32>() || same_type::<A, f64>()) &&
+        m <= c_int::max_value() as usize && n <= c_int::max_value() as usize &&
+        m.checked_mul(n).map_or(false, |len| len <= c_int::max_value() as usize)
+}
+
+fn column_major<A: Copy>(a: ArrayView2<A>) -> Vec<A> {
+    a.t().iter().cloned().collect()
+}
+
+fn from_column_major<A>((m, n): (Ix, Ix), v: Vec<A>) -> Array2<A> {
+    Array::from_shape_vec((m, n).f(), v).unwrap()
+}
+
+/// The workspace size returned by a query, in `work[0]`.
+fn work_size<A: NdFloat>(work: A) -> usize {
+    cmp::max(1, work.to_usize().unwrap_or(1))
+}
+
+/// LU decomposition with partial pivoting, `?getrf`: return the packed
+/// factors, the row permutation and whether it is odd.
+pub fn lu<A: NdFloat>(a: ArrayView2<A>) -> Option<(Array2<A>, Vec<Ix>, bool)> {
+    let (m, n) = a.dim();
+    if !supported::<A>((m, n)) || cmp::min(m, n) == 0 {
+        return None;
+    }
+    let mut buf = column_major(a);
+    let mut ipiv = vec![0 as c_int; cmp::min(m, n)];
+    let (mi, ni, lda) = (m as c_int, n as c_int, m as c_int);
+    let mut info = 0;
+    unsafe {
+        lapack!(A, sgetrf_, dgetrf_(&mi, &ni, buf.as_mut_ptr(), &lda, ipiv.as_mut_ptr(),
+                                    &mut info));
+    }
+    // info > 0 is an exact zero pivot, which is a valid factorization
+    if info < 0 {
+        return None;
+    }
+    let mut perm = (0..m).collect::<Vec<_>>();
+    let mut odd = false;
+    for (i, &p) in ipiv.iter().enumerate() {
+        let p = p as usize - 1;
+        if p != i {
+            perm.swap(i, p);
+            odd = !odd;
+        }
+    }
+    Some((from_column_major((m, n), buf), perm, odd))
+}
+
+/// QR decomposition, `?geqrf`: return the packed factors (with the
+/// Householder vectors below the diagonal) and the scalar factors of the
+/// reflections.
+pub fn qr<A: NdFloat>(a: ArrayView2<A>) -> Option<(Array2<A>, Vec<A>)> {
+    let (m, n) = a.dim();
+    if !supported::<A>((m, n)) || cmp::min(m, n) == 0 {
+        return None;
+    }
+    let mut buf = column_major(a);
+    let mut tau = vec![A::zero(); cmp::min(m, n)];
+    let (mi, ni, lda) = (m as c_int, n as c_int, m as c_int);
+    let mut info = 0;
+    let mut query = [A::zero()];
+    unsafe {
+        lapack!(A, sgeqrf_, dgeqrf_(&mi, &ni, buf.as_mut_ptr(), &lda, tau.as_mut_ptr(),
+                                    query.as_mut_ptr(), &-1, &mut info));
+    }
+    let lwork = work_size(query[0]);
+    let mut work = vec![A::zero(); lwork];
+    unsafe {
+        lapack!(A, sgeqrf_, dgeqrf_(&mi, &ni, buf.as_mut_ptr(), &lda, tau.as_mut_ptr(),
+                                    work.as_mut_ptr(), &(lwork as c_int), &mut info));
+    }
+    if info != 0 {
+        return None;
+    }
+    Some((from_column_major((m, n), buf), tau))
+}
+
+/// Thin singular value decomposition, `?gesdd`: return `U`, the singular
+/// values in decreasing order and `Vᵀ`, without the vectors unless
+/// `vectors` is true.
+pub fn svd<A: NdFloat>(a: ArrayView2<A>, vectors: bool)
+    -> Option<(Option<Array2<A>>, Array1<A>, Option<Array2<A>>)>
+{
+    let (m, n) = a.dim();
+    let k = cmp::min(m, n);
+    if !supported::<A>((m, n)) || k == 0 {
+        return None;
+    }
+    let mut buf = column_major(a);
+    let mut s = vec![A::zero(); k];
+    let (mut u, mut vt) = if vectors {
+        (vec![A::zero(); m * k], vec![A::zero(); k * n])
+    } else {
+        (vec![A::zero()], vec![A::zero()])
+    };
+    let jobz = (if vectors { b'S' } else { b'N' }) as c_char;
+    let (mi, ni, lda) = (m as c_int, n as c_int, m as c_int);
+    let (ldu, ldvt) = if vectors { (m as c_int, k as c_int) } else { (1, 1) };
+    let mut iwork = vec![0 as c_int; 8 * k];
+    let mut info = 0;
+    let mut query = [A::zero()];
+    unsafe {
+        lapack!(A, sgesdd_, dgesdd_(&jobz, &mi, &ni, buf.as_mut_ptr(), &lda, s.as_mut_ptr(),
+                                    u.as_mut_ptr(), &ldu, vt.as_mut_ptr(), &ldvt,
+                                    query.as_mut_ptr(), &-1, iwork.as_mut_ptr(), &mut info));
+    }
+    let lwork = work_size(query[0]);
+    let mut work = vec![A::zero(); lwork];
+    unsafe {
+        lapack!(A, sgesdd_, dgesdd_(&jobz, &mi, &ni, buf.as_mut_ptr(), &lda, s.as_mut_ptr(),
+                                    u.as_mut_ptr(), &ldu, vt.as_mut_ptr(), &ldvt,
+                                    work.as_mut_ptr(), &(lwork as c_int), iwork.as_mut_ptr(),
+                                    &mut info));
+    }
+    if info != 0 {
+        return None;
+    }
+    let s = Array1::from_vec(s);
+    if vectors {
+        Some((Some(from_column_major((m, k), u)), s, Some(from_column_major((k, n), vt))))
+    } else {
+        Some((None, s, None))
+    }
+}
+
+/// Symmetric eigendecomposition, `?syevd`: return the eigenvalues in
+/// increasing order, and the eigenvectors as columns if `vectors` is true.
+pub fn eigh<A: NdFloat>(a: ArrayView2<A>, vectors: bool) -> Option<(Array1<A>, Option<Array2<A>>)> {
+    let n = a.rows();
+    if !supported::<A>((n, n)) || n == 0 {
+        return None;
+    }
+    let mut buf = column_major(a);
+    let mut w = vec![A::zero(); n];
+    let jobz = (if vectors { b'V' } else { b'N' }) as c_char;
+    let uplo = b'L' as c_char;
+    let (ni, lda) = (n as c_int, n as c_int);
+    let mut info = 0;
+    let mut query = [A::zero()];
+    let mut iquery = [0 as c_int];
+    unsafe {
+        lapack!(A, ssyevd_, dsyevd_(&jobz, &uplo, &ni, buf.as_mut_ptr(), &lda, w.as_mut_ptr(),
+                                    query.as_mut_ptr(), &-1, iquery.as_mut_ptr(), &-1,
+                                    &mut info));
+    }
+    let lwork = work_size(query[0]);
+    let liwork = cmp::max(1, iquery[0]);
+    let mut work = vec![A::zero(); lwork];
+    let mut iwork = vec![0 as c_int; liwork as usize];
+    unsafe {
+        lapack!(A, ssyevd_, dsyevd_(&jobz, &uplo, &ni, buf.as_mut_ptr(), &lda, w.as_mut_ptr(),
+                                    work.as_mut_ptr(), &(lwork as c_int), iwork.as_mut_ptr(),
+                                    &liwork, &mut info));
+    }
+    if info != 0 {
+        return None;
+    }
+    let v = if vectors { Some(from_column_major((n, n), buf)) } else { None };
+    Some((Array1::from_vec(w), v))
+}
+
+/// General eigendecomposition, `?geev`: return the real and imaginary
+/// parts of the eigenvalues, and if `vectors` is true, the real matrix
+/// with the eigenvectors, where the columns `j` and `j + 1` hold the real
+/// and imaginary parts of the vector of a complex pair.
+pub fn eig<A: NdFloat>(a: ArrayView2<A>, vectors: bool)
+    -> Option<(Vec<A>, Vec<A>, Option<Array2<A>>)>
+{
+    let n = a.rows();
+    if !supported::<A>((n, n)) || n == 0 {
+        return None;
+    }
+    let mut buf = column_major(a);
+    let mut wr = vec![A::zero(); n];
+    let mut wi = vec![A::zero(); n];
+    let mut vl = [A::zero()];
+    let mut vr = if vectors { vec![A::zero(); n * n] } else { vec![A::zero()] };
+    let jobvl = b'N' as c_char;
+    let jobvr = (if vectors { b'V' } else { b'N' }) as c_char;
+    let (ni, lda, ldvl) = (n as c_int, n as c_int, 1 as c_int);
+    let ldvr = if vectors { n as c_int } else { 1 };
+    let mut info = 0;
+    let mut query = [A::zero()];
+    unsafe {
+        lapack!(A, sgeev_, dgeev_(&jobvl, &jobvr, &ni, buf.as_mut_ptr(), &lda, wr.as_mut_ptr(),
+                                  wi.as_mut_ptr(), vl.as_mut_ptr(), &ldvl, vr.as_mut_ptr(), &ldvr,
+                                  query.as_mut_ptr(), &-1, &mut info));
+    }
+    let lwork = work_size(query[0]);
+    let mut work = vec![A::zero(); lwork];
+    unsafe {
+        lapack!(A, sgeev_, dgeev_(&jobvl, &jobvr, &ni, buf.as_mut_ptr(), &lda, wr.as_mut_ptr(),
+                                  wi.as_mut_ptr(), vl.as_mut_ptr(), &ldvl, vr.as_mut_ptr(), &ldvr,
+                                  work.as_mut_ptr(), &(lwork as c_int), &mut info));
+    }
+    if info != 0 {
+        return None;
+    }
+    let v = if vectors { Some(from_column_major((n, n), vr)) } else { None };
+    Some((wr, wi, v))
+}
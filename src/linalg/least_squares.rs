@@ -0,0 +1,141 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use NdFloat;
+use super::eig::ConvergenceError;
+use super::svd::{svd, singular_value_cutoff};
+
+/// The solution of a linear least squares problem, with information about
+/// the quality of the fit.
+///
+/// Created with [`least_squares`](fn.least_squares.html).
+#[derive(Clone, Debug)]
+pub struct LeastSquares<A, D: Dimension> {
+    solution: Array<A, D>,
+    residuals: Option<Array1<A>>,
+    rank: usize,
+    singular_values: Array1<A>,
+}
+
+impl<A, D: Dimension> LeastSquares<A, D> {
+    /// Return the solution `x`, which has the shape of `b`, with `n` rows.
+    pub fn solution(&self) -> &Array<A, D> {
+        &self.solution
+    }
+
+    /// Return the solution `x`.
+    pub fn into_solution(self) -> Array<A, D> {
+        self.solution
+    }
+
+    /// Return the sum of squared residuals `|a x - b|²` of each column of
+    /// `b` (a single element if `b` is a vector).
+    ///
+    /// This is `None` unless the system is overdetermined (`m > n`) and `a`
+    /// has full rank; otherwise the residuals are zero or the solution is
+    /// not unique.
+    pub fn residuals(&self) -> Option<&Array1<A>> {
+        self.residuals.as_ref()
+    }
+
+    /// Return the effective rank of `a`: the number of singular values
+    /// above the cutoff.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Return the singular values of `a`, in decreasing order.
+    pub fn singular_values(&self) -> &Array1<A> {
+        &self.singular_values
+    }
+}
+
+/// Compute the least squares solution `x` of `a x = b` for the `m × n`
+/// matrix `a`, minimizing `|a x - b|`.
+///
+/// `b` is either a vector, or a matrix with a right hand side in each
+/// column. If the solution is not unique, because `a` is rank deficient or
+/// the system is underdetermined (`m < n`), the solution of minimal norm is
+/// returned.
+///
+/// The solution is computed from the singular value decomposition of `a`;
+/// singular values not greater than `rcond σ₁` are treated as zero, where
+/// `σ₁` is the largest singular value. If `rcond` is `None`, `max(m, n) ε`
+/// is used, where `ε` is the machine epsilon.
+///
+/// Each call factorizes `a` again. To solve with the same full rank matrix
+/// many times, keep its QR decomposition and use
+/// [`QR::solve`](struct.QR.html#method.solve).
+///
+/// Return an error if the singular value decomposition can not be
+/// computed, see [`svd`](fn.svd.html).
+///
+/// **Panics** if `b` is not one or two dimensional, or if `b` has a
+/// different number of rows than `a`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::least_squares;
+///
+/// // fit a line y = c₀ + c₁ t through three points
+/// let t = arr1(&[0., 1., 2.]);
+/// let y = arr1(&[1., 2., 4.]);
+/// let mut a = arr2(&[[1., 0.], [1., 0.], [1., 0.]]);
+/// a.column_mut(1).assign(&t);
+/// let fit = least_squares(&a, &y, None).unwrap();
+/// assert!(fit.solution().all_close(&arr1(&[5. / 6., 1.5]), 1e-12));
+/// assert_eq!(fit.rank(), 2);
+/// assert!(fit.residuals().unwrap().all_close(&arr1(&[1. / 6.]), 1e-12));
+/// ```
+pub fn least_squares<A, S, S2, D>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, D>, rcond: Option<A>)
+    -> Result<LeastSquares<A, D>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert!(b.ndim() == 1 || b.ndim() == 2,
+            "ndarray: least_squares: right hand side must be one or two dimensional, \
+             but has shape {:?}", b.shape());
+    let (m, n) = a.dim();
+    assert!(b.shape()[0] == m,
+            "ndarray: least_squares: right hand side has shape {:?}, but the matrix has {} rows",
+            b.shape(), m);
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    let b2 = Array::from_iter(b.iter().cloned()).into_shape((m, k)).unwrap();
+
+    let (u, s, vt) = try!(svd(a));
+    let cutoff = singular_value_cutoff(rcond, (m, n), &s);
+    let rank = s.iter().filter(|&&x| x > cutoff).count();
+    // x = V Σ⁺ Uᵀ b, using only the singular values above the cutoff
+    let mut c = u.t().dot(&b2);
+    for (mut row, &sv) in c.outer_iter_mut().zip(&s) {
+        if sv > cutoff {
+            row.mapv_inplace(|x| x / sv);
+        } else {
+            row.fill(A::zero());
+        }
+    }
+    let x = vt.t().dot(&c);
+
+    let residuals = if m > n && rank == n {
+        let r = a.dot(&x) - &b2;
+        Some(r.fold_axis(Axis(0), A::zero(), |&acc, &x| acc + x * x))
+    } else {
+        None
+    };
+    let mut dim = b.raw_dim();
+    dim.slice_mut()[0] = n;
+    Ok(LeastSquares {
+        solution: x.into_shape(dim).unwrap(),
+        residuals: residuals,
+        rank: rank,
+        singular_values: s,
+    })
+}
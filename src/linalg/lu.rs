@@ -0,0 +1,374 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use imp_prelude::*;
+use NdFloat;
+#[cfg(feature="lapack")]
+use super::lapack;
+use super::solve::SingularError;
+use super::triangular::right_hand_side;
+
+/// An LU decomposition with partial pivoting, `P A = L U`, of an `m × n`
+/// matrix `A`.
+///
+/// `P` is a permutation matrix, `L` is `m × k` lower triangular with a unit
+/// diagonal and `U` is `k × n` upper triangular, where `k = min(m, n)`.
+///
+/// Created with [`lu`](fn.lu.html).
+#[derive(Clone, Debug)]
+pub struct LU<A> {
+    /// `L` below the diagonal (without its unit diagonal), `U` on and above
+    lu: Array2<A>,
+    /// Row `i` of `P A` is row `perm[i]` of `A`
+    perm: Vec<Ix>,
+    /// Whether `P` is an odd permutation
+    odd: bool,
+}
+
+/// Compute the LU decomposition of `a` with partial pivoting.
+///
+/// In each step, the row with the element of greatest magnitude in the
+/// pivot column is used as the pivot row. The decomposition always exists;
+/// for a singular matrix, `U` has a zero on its diagonal.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::lu;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let f = lu(&a);
+/// assert_eq!(f.perm(), &[1, 0]);
+/// assert_eq!(f.l(), arr2(&[[1., 0.],
+///                          [1. / 3., 1.]]));
+/// assert!(f.p().dot(&a).all_close(&f.l().dot(&f.u()), 1e-12));
+/// ```
+pub fn lu<A, S>(a: &ArrayBase<S, Ix2>) -> LU<A>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    #[cfg(feature="lapack")]
+    {
+        if let Some((lu, perm, odd)) = lapack::lu(a.view()) {
+            return LU { lu: lu, perm: perm, odd: odd };
+        }
+    }
+    let mut lu = a.to_owned();
+    let (perm, odd) = factor_inplace(lu.view_mut());
+    LU {
+        lu: lu,
+        perm: perm,
+        odd: odd,
+    }
+}
+
+/// Compute the LU decomposition of `a` with partial pivoting, overwriting
+/// `a` with the factors, and return the permutation.
+///
+/// On return, `a` holds the factors in the layout of
+/// [`LU::packed`](struct.LU.html#method.packed): `L` below the diagonal
+/// (its unit diagonal is not stored) and `U` on and above it. Row `i` of
+/// `P A` is row `perm[i]` of `A`. This avoids allocating the factors, for
+/// repeated factorizations of same-sized matrices.
+///
+/// ```
+/// use ndarray::{arr1, arr2, Axis};
+/// use ndarray::linalg::{lu_inplace, subst_fw, subst_bw, Diag};
+///
+/// let mut a = arr2(&[[1., 2.],
+///                    [3., 4.]]);
+/// let perm = lu_inplace(&mut a);
+/// assert_eq!(perm, vec![1, 0]);
+///
+/// // solve a x = b with the packed factors
+/// let b = arr1(&[5., 11.]);
+/// let pb = b.select(Axis(0), &perm);
+/// let y = subst_fw(&a, &pb, Diag::Unit).unwrap();
+/// let x = subst_bw(&a, &y, Diag::NonUnit).unwrap();
+/// assert!(x.all_close(&arr1(&[1., 2.]), 1e-12));
+/// ```
+pub fn lu_inplace<A, S>(a: &mut ArrayBase<S, Ix2>) -> Vec<Ix>
+    where A: NdFloat,
+          S: DataMut<Elem=A>,
+{
+    #[cfg(feature="lapack")]
+    {
+        if let Some((lu, perm, _)) = lapack::lu(a.view()) {
+            a.assign(&lu);
+            return perm;
+        }
+    }
+    factor_inplace(a.view_mut()).0
+}
+
+/// Overwrite `lu` with its packed LU factors, and return the permutation
+/// and whether it is odd.
+fn factor_inplace<A: NdFloat>(mut lu: ArrayViewMut2<A>) -> (Vec<Ix>, bool) {
+    let (m, n) = lu.dim();
+    let mut perm = (0..m).collect::<Vec<_>>();
+    let mut odd = false;
+    for k in 0..cmp::min(m, n) {
+        let mut p = k;
+        for i in k + 1..m {
+            if lu[[i, k]].abs() > lu[[p, k]].abs() {
+                p = i;
+            }
+        }
+        if p != k {
+            lu.swap_rows(k, p);
+            perm.swap(k, p);
+            odd = !odd;
+        }
+        let pivot = lu[[k, k]];
+        if pivot == A::zero() {
+            // the column is already zero below the diagonal
+            continue;
+        }
+        let (top, mut rest) = lu.view_mut().split_at(Axis(0), k + 1);
+        let pivot_row = top.row(k).split_at(Axis(0), k + 1).1;
+        for mut row in rest.outer_iter_mut() {
+            let (mut l, mut u) = row.view_mut().split_at(Axis(0), k + 1);
+            let factor = l[k] / pivot;
+            l[k] = factor;
+            u.scaled_add(-factor, &pivot_row);
+        }
+    }
+    (perm, odd)
+}
+
+/// Compute the determinant of the square matrix `a`, from its LU
+/// decomposition.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::det;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let d: f64 = det(&a);
+/// assert!((d - -2.).abs() < 1e-12);
+/// ```
+pub fn det<A, S>(a: &ArrayBase<S, Ix2>) -> A
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    lu(a).det()
+}
+
+/// Compute the sign and the natural logarithm of the absolute value of the
+/// determinant of the square matrix `a`, from its LU decomposition.
+///
+/// See [`LU::slogdet`](struct.LU.html#method.slogdet).
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::Array2;
+/// use ndarray::linalg::{det, slogdet};
+///
+/// // the determinant, 1e-400, underflows
+/// let a = Array2::<f64>::eye(100) * 1e-4;
+/// assert_eq!(det(&a), 0.);
+/// let (sign, logabsdet) = slogdet(&a);
+/// assert_eq!(sign, 1.);
+/// assert!((logabsdet - -400. * 10f64.ln()).abs() < 1e-9);
+/// ```
+pub fn slogdet<A, S>(a: &ArrayBase<S, Ix2>) -> (A, A)
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    lu(a).slogdet()
+}
+
+impl<A: NdFloat> LU<A> {
+    /// Return the lower triangular factor `L`, with a unit diagonal.
+    pub fn l(&self) -> Array2<A> {
+        let (m, n) = self.lu.dim();
+        let k = cmp::min(m, n);
+        let mut l = Array2::zeros((m, k));
+        for ((i, j), x) in l.indexed_iter_mut() {
+            if i > j {
+                *x = self.lu[[i, j]];
+            } else if i == j {
+                *x = A::one();
+            }
+        }
+        l
+    }
+
+    /// Return the upper triangular factor `U`.
+    pub fn u(&self) -> Array2<A> {
+        let (m, n) = self.lu.dim();
+        let k = cmp::min(m, n);
+        let mut u = Array2::zeros((k, n));
+        for ((i, j), x) in u.indexed_iter_mut() {
+            if i <= j {
+                *x = self.lu[[i, j]];
+            }
+        }
+        u
+    }
+
+    /// Return the permutation matrix `P`.
+    pub fn p(&self) -> Array2<A> {
+        let m = self.perm.len();
+        let mut p = Array2::zeros((m, m));
+        for (i, &j) in self.perm.iter().enumerate() {
+            p[[i, j]] = A::one();
+        }
+        p
+    }
+
+    /// Return the row permutation: row `i` of `P A` is row `perm()[i]` of
+    /// `A`.
+    pub fn perm(&self) -> &[Ix] {
+        &self.perm
+    }
+
+    /// Return a view of the factors packed in one matrix: `L` below the
+    /// diagonal (without its unit diagonal) and `U` on and above it.
+    pub fn packed(&self) -> ArrayView2<A> {
+        self.lu.view()
+    }
+
+    /// Return `true` if `U` has a zero on its diagonal, which for a square
+    /// matrix means that it is singular.
+    pub fn is_singular(&self) -> bool {
+        self.lu.diag().iter().any(|&x| x == A::zero())
+    }
+
+    /// Return the determinant of `A`, the product of the diagonal of `U`
+    /// with the sign of the permutation.
+    ///
+    /// **Panics** if `A` is not square.
+    pub fn det(&self) -> A {
+        self.assert_square("det");
+        let det = self.lu.diag().fold(A::one(), |acc, &x| acc * x);
+        if self.odd { -det } else { det }
+    }
+
+    /// Return `(sign, logabsdet)` such that the determinant of `A` is
+    /// `sign * logabsdet.exp()`.
+    ///
+    /// `sign` is `1`, `-1` or, for a singular matrix, `0` (and then
+    /// `logabsdet` is negative infinity). Unlike [`.det()`](#method.det),
+    /// this does not overflow or underflow for large or badly scaled
+    /// matrices.
+    ///
+    /// **Panics** if `A` is not square.
+    pub fn slogdet(&self) -> (A, A) {
+        self.assert_square("slogdet");
+        let mut sign = if self.odd { -A::one() } else { A::one() };
+        let mut logabsdet = A::zero();
+        for &x in self.lu.diag() {
+            if x == A::zero() {
+                return (A::zero(), A::neg_infinity());
+            }
+            if x < A::zero() {
+                sign = -sign;
+            }
+            logabsdet += x.abs().ln();
+        }
+        (sign, logabsdet)
+    }
+
+    /// Solve the linear system `A x = b` with the decomposition, which can
+    /// be reused for any number of right hand sides.
+    ///
+    /// `b` is either a vector, or a matrix with a right hand side in each
+    /// column; the solution `x` has the same shape as `b`.
+    ///
+    /// Return an error if `A` is singular.
+    ///
+    /// **Panics** if `A` is not square, if `b` is not one or two
+    /// dimensional, or if `b` does not have `n` rows.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::lu;
+    ///
+    /// let f = lu(&arr2(&[[3., 1.],
+    ///                    [1., 2.]]));
+    /// assert!(f.solve(&arr1(&[9., 8.])).unwrap().all_close(&arr1(&[2., 3.]), 1e-12));
+    /// assert!(f.solve(&arr1(&[1., 2.])).unwrap().all_close(&arr1(&[0., 1.]), 1e-12));
+    /// ```
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, SingularError>
+        where S: Data<Elem=A>,
+              D: Dimension,
+    {
+        self.assert_square("LU::solve");
+        let mut x = right_hand_side("LU::solve", self.lu.rows(), b);
+        if self.is_singular() {
+            return Err(SingularError);
+        }
+        lu_solve_inplace(self, &mut x);
+        Ok(x.into_shape(b.raw_dim()).unwrap())
+    }
+
+    fn assert_square(&self, name: &str) {
+        assert!(self.lu.is_square(), "ndarray: {}: matrix must be square, but has shape {:?}",
+                name, self.lu.shape());
+    }
+}
+
+/// Overwrite `b` with the solution `X` of `A X = B`, where `f` is the LU
+/// decomposition of the square, nonsingular matrix `A`.
+pub fn lu_solve_inplace<A: NdFloat>(f: &LU<A>, b: &mut Array2<A>) {
+    let n = f.lu.rows();
+    *b = b.select(Axis(0), &f.perm);
+    // forward substitution with L, then back substitution with U
+    for i in 1..n {
+        let (top, mut bottom) = b.view_mut().split_at(Axis(0), i);
+        let mut row = bottom.row_mut(0);
+        for j in 0..i {
+            row.scaled_add(-f.lu[[i, j]], &top.row(j));
+        }
+    }
+    for i in (0..n).rev() {
+        let (mut top, bottom) = b.view_mut().split_at(Axis(0), i + 1);
+        let mut row = top.row_mut(i);
+        for j in i + 1..n {
+            row.scaled_add(-f.lu[[i, j]], &bottom.row(j - i - 1));
+        }
+        let pivot = f.lu[[i, i]];
+        row.mapv_inplace(|x| x / pivot);
+    }
+}
+
+/// Overwrite `b` with the solution `X` of `Aᵀ X = B`, where `f` is the LU
+/// decomposition of the square, nonsingular matrix `A`.
+pub fn lu_solve_transpose_inplace<A: NdFloat>(f: &LU<A>, b: &mut Array2<A>) {
+    let n = f.lu.rows();
+    // Aᵀ = Uᵀ Lᵀ P: forward substitution with Uᵀ, then back substitution
+    // with Lᵀ
+    for i in 0..n {
+        let (top, mut bottom) = b.view_mut().split_at(Axis(0), i);
+        let mut row = bottom.row_mut(0);
+        for j in 0..i {
+            row.scaled_add(-f.lu[[j, i]], &top.row(j));
+        }
+        let pivot = f.lu[[i, i]];
+        row.mapv_inplace(|x| x / pivot);
+    }
+    for i in (0..n).rev() {
+        let (mut top, bottom) = b.view_mut().split_at(Axis(0), i + 1);
+        let mut row = top.row_mut(i);
+        for j in i + 1..n {
+            row.scaled_add(-f.lu[[j, i]], &bottom.row(j - i - 1));
+        }
+    }
+    let mut inverse_perm = vec![0; n];
+    for (i, &p) in f.perm.iter().enumerate() {
+        inverse_perm[p] = i;
+    }
+    *b = b.select(Axis(0), &inverse_perm);
+}
@@ -0,0 +1,127 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::mem;
+
+use imp_prelude::*;
+use NdFloat;
+use super::impl_linalg::general_mat_mul;
+use super::lu::{lu, lu_solve_inplace};
+use super::solve::{inverse, SingularError};
+
+/// The degree of the diagonal Padé approximant used by `expm`; with the
+/// scaling below, the relative error is below `1e-16`.
+const PADE_DEGREE: usize = 6;
+
+/// Compute the matrix exponential `exp(a) = I + a + a²/2! + …` of the square
+/// matrix `a`.
+///
+/// This uses scaling and squaring: `a` is scaled by a power of two, `2ˢ`,
+/// until its ∞-norm is at most one half, the exponential of the scaled
+/// matrix is computed with a diagonal Padé approximant, and the result is
+/// squared `s` times.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::expm;
+///
+/// // the generator of rotations
+/// let t = 0.5f64;
+/// let a = arr2(&[[0., -t],
+///                [t, 0.]]);
+/// let r = arr2(&[[t.cos(), -t.sin()],
+///                [t.sin(), t.cos()]]);
+/// assert!(expm(&a).all_close(&r, 1e-14));
+/// ```
+pub fn expm<A, S>(a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: expm: matrix must be square, but has shape {:?}",
+            a.shape());
+    let n = a.rows();
+    let norm = a.outer_iter()
+                .map(|row| row.fold(A::zero(), |acc, x| acc + x.abs()))
+                .fold(A::zero(), A::max);
+    let two = A::one() + A::one();
+    let mut squarings = 0;
+    let mut scale = A::one();
+    while norm / scale > A::one() / two {
+        scale *= two;
+        squarings += 1;
+    }
+    let a = a.mapv(|x| x / scale);
+
+    // numerator N and denominator D of the Padé approximant D⁻¹ N, with
+    // N = Σ c_k aᵏ and D = Σ (-1)ᵏ c_k aᵏ
+    let q = PADE_DEGREE;
+    let eye = Array2::<A>::eye(n);
+    let mut c = A::one() / two;
+    let mut x = a.clone();
+    let mut num = &eye + &(&a * c);
+    let mut den = &eye - &(&a * c);
+    for k in 2..q + 1 {
+        c = c * A::from(q - k + 1).unwrap() / A::from(k * (2 * q - k + 1)).unwrap();
+        x = a.dot(&x);
+        num.scaled_add(c, &x);
+        den.scaled_add(if k % 2 == 0 { c } else { -c }, &x);
+    }
+    // D is well conditioned for |a| ≤ 1/2, so it is never singular
+    lu_solve_inplace(&lu(&den), &mut num);
+    for _ in 0..squarings {
+        num = num.dot(&num);
+    }
+    num
+}
+
+/// Compute the integer power `aᵏ` of the square matrix `a`.
+///
+/// This uses repeated squaring, with about `2 log₂ |k|` matrix products
+/// into reused buffers. `a⁰` is the identity, and a negative power is a
+/// power of the inverse, which is an error if `a` is singular.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::matrix_power;
+///
+/// // the Fibonacci numbers
+/// let a = arr2(&[[1., 1.],
+///                [1., 0.]]);
+/// assert_eq!(matrix_power(&a, 10).unwrap(), arr2(&[[89., 55.],
+///                                                  [55., 34.]]));
+/// let inv = matrix_power(&a, -1).unwrap();
+/// assert!(inv.all_close(&arr2(&[[0., 1.], [1., -1.]]), 1e-15));
+/// ```
+pub fn matrix_power<A, S>(a: &ArrayBase<S, Ix2>, k: i32) -> Result<Array2<A>, SingularError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: matrix_power: matrix must be square, but has shape {:?}",
+            a.shape());
+    let n = a.rows();
+    let mut base = if k < 0 { try!(inverse(a)) } else { a.to_owned() };
+    let mut e = k.unsigned_abs();
+    let mut result = Array2::eye(n);
+    let mut tmp = Array2::zeros((n, n));
+    while e > 0 {
+        if e & 1 == 1 {
+            general_mat_mul(A::one(), &result, &base, A::zero(), &mut tmp);
+            mem::swap(&mut result, &mut tmp);
+        }
+        e >>= 1;
+        if e > 0 {
+            general_mat_mul(A::one(), &base, &base, A::zero(), &mut tmp);
+            mem::swap(&mut base, &mut tmp);
+        }
+    }
+    Ok(result)
+}
@@ -11,5 +11,46 @@
 
 pub use self::impl_linalg::Dot;
 pub use self::impl_linalg::general_mat_mul;
+pub use self::impl_linalg::general_mat_vec_mul;
+pub use self::impl_linalg::tensordot;
+pub use self::impl_linalg::kron;
+pub use self::einsum::einsum;
+pub use self::banded::Banded;
+pub use self::cholesky::{cholesky, cholesky_inplace, Cholesky, NotPositiveDefiniteError};
+pub use self::eig::{eig, eigvals, ConvergenceError};
+pub use self::eigh::{eigh, eigvalsh};
+pub use self::givens::{make_givens, apply_givens};
+pub use self::householder::{make_householder, apply_householder_left, apply_householder_right};
+pub use self::least_squares::{least_squares, LeastSquares};
+pub use self::lu::{lu, lu_inplace, det, slogdet, LU};
+pub use self::matfun::{expm, matrix_power};
+pub use self::norm::{norm, cond, Norm};
+pub use self::polynomial::{vander, polyfit, polyval};
+pub use self::qr::{qr, qr_inplace, QR};
+pub use self::solve::{solve, inverse, SingularError};
+pub use self::svd::{svd, svd_values, rank, pinv};
+pub use self::symmetric::Symmetric;
+pub use self::triangular::{subst_fw, subst_bw, Diag, Lower, Upper};
+pub use self::tridiagonal::solve_tridiagonal;
 
 mod impl_linalg;
+mod einsum;
+mod banded;
+mod cholesky;
+mod eig;
+mod eigh;
+mod givens;
+mod householder;
+#[cfg(feature="lapack")]
+mod lapack;
+mod least_squares;
+mod lu;
+mod matfun;
+mod norm;
+mod polynomial;
+mod qr;
+mod solve;
+mod svd;
+mod symmetric;
+mod triangular;
+mod tridiagonal;
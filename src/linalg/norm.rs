@@ -0,0 +1,183 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use NdFloat;
+use super::lu::{lu, lu_solve_inplace, lu_solve_transpose_inplace, LU};
+use super::solve::inverse;
+use super::svd::svd_values;
+
+/// Upper bound on the number of iterations of the 1-norm estimator; it
+/// usually stops after two or three.
+const MAX_ESTIMATE_ITER: usize = 5;
+
+/// A matrix norm.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Norm {
+    /// The maximum absolute column sum
+    One,
+    /// The largest singular value
+    Two,
+    /// The maximum absolute row sum
+    Inf,
+    /// The square root of the sum of the squared elements
+    Frobenius,
+}
+
+/// Compute the norm of the matrix `a`.
+///
+/// `Norm::Two`, the spectral norm, is computed from the singular values,
+/// and is NaN if they can not be computed (see
+/// [`svd_values`](fn.svd_values.html)); the others need a single pass over
+/// the elements. The norm of an empty matrix is zero.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{norm, Norm};
+///
+/// let a = arr2(&[[1., -2.],
+///                [-3., 4.]]);
+/// assert_eq!(norm(&a, Norm::One), 6.);
+/// assert_eq!(norm(&a, Norm::Inf), 7.);
+/// assert!((norm(&a, Norm::Frobenius) - 30f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn norm<A, S>(a: &ArrayBase<S, Ix2>, kind: Norm) -> A
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    match kind {
+        Norm::One => norm_one(a),
+        Norm::Two => {
+            match svd_values(a) {
+                Ok(ref s) if s.len() > 0 => s[0],
+                Ok(_) => A::zero(),
+                Err(_) => A::nan(),
+            }
+        }
+        Norm::Inf => norm_one(&a.t()),
+        Norm::Frobenius => a.norm_l2(),
+    }
+}
+
+/// Compute the condition number `|a| |a⁻¹|` of the square matrix `a` in the
+/// given norm.
+///
+/// - `Norm::Two` is exact, the ratio of the largest to the smallest
+///   singular value, or NaN if they can not be computed.
+/// - `Norm::One` and `Norm::Inf` are estimated from the LU decomposition
+///   with Hager's method, which only needs a few triangular solves instead
+///   of `a⁻¹`. The estimate is a lower bound, and rarely off by more than a
+///   factor of three.
+/// - `Norm::Frobenius` is exact, and computes `a⁻¹`.
+///
+/// The condition number of a singular matrix is infinite.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{cond, Norm};
+///
+/// let a = arr2(&[[1f64, 0.],
+///                [0., 1e-6]]);
+/// assert!((cond(&a, Norm::Two) - 1e6).abs() < 1e-3);
+/// assert!((cond(&a, Norm::One) - 1e6).abs() < 1e-3);
+/// ```
+pub fn cond<A, S>(a: &ArrayBase<S, Ix2>, norm: Norm) -> A
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: cond: matrix must be square, but has shape {:?}",
+            a.shape());
+    if a.rows() == 0 {
+        return A::zero();
+    }
+    match norm {
+        Norm::Two => {
+            let s = match svd_values(a) {
+                Ok(s) => s,
+                Err(_) => return A::nan(),
+            };
+            let smallest = s[s.len() - 1];
+            if smallest == A::zero() { A::infinity() } else { s[0] / smallest }
+        }
+        Norm::One | Norm::Inf => {
+            // the ∞-norm of a is the 1-norm of aᵀ
+            let at;
+            let a = if norm == Norm::One { a.view() } else { at = a.t(); at };
+            let f = lu(&a);
+            if f.is_singular() {
+                return A::infinity();
+            }
+            norm_one(&a) * estimate_inverse_norm_one(&f)
+        }
+        Norm::Frobenius => {
+            match inverse(a) {
+                Ok(inv) => a.norm_l2() * inv.norm_l2(),
+                Err(_) => A::infinity(),
+            }
+        }
+    }
+}
+
+fn norm_one<A, S>(a: &ArrayBase<S, Ix2>) -> A
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    a.axis_iter(Axis(1))
+     .map(|col| col.fold(A::zero(), |acc, x| acc + x.abs()))
+     .fold(A::zero(), A::max)
+}
+
+/// Estimate `|A⁻¹|₁` from the LU decomposition of the nonsingular matrix
+/// `A`, with Hager's method as refined by Higham.
+fn estimate_inverse_norm_one<A: NdFloat>(f: &LU<A>) -> A {
+    let n = f.packed().rows();
+    let nf = A::from(n).unwrap();
+    let abs_sum = |x: &Array2<A>| x.fold(A::zero(), |acc, y| acc + y.abs());
+
+    // maximize |A⁻¹ x|₁ over |x|₁ = 1, a convex function, by moving to the
+    // unit vector in the direction of the largest gradient element
+    let mut x = Array2::from_elem((n, 1), A::one() / nf);
+    let mut estimate = A::zero();
+    let mut last_j = None;
+    for _ in 0..MAX_ESTIMATE_ITER {
+        lu_solve_inplace(f, &mut x);
+        let new_estimate = abs_sum(&x);
+        if last_j.is_some() && new_estimate <= estimate {
+            break;
+        }
+        estimate = new_estimate;
+        x.mapv_inplace(|y| if y >= A::zero() { A::one() } else { -A::one() });
+        lu_solve_transpose_inplace(f, &mut x);
+        let mut j = 0;
+        for i in 1..n {
+            if x[[i, 0]].abs() > x[[j, 0]].abs() {
+                j = i;
+            }
+        }
+        if last_j == Some(j) {
+            break;
+        }
+        last_j = Some(j);
+        x.fill(A::zero());
+        x[[j, 0]] = A::one();
+    }
+
+    // an alternating vector guards against the rare cases where the
+    // iteration gets stuck far from the maximum
+    let mut x = Array2::zeros((n, 1));
+    for i in 0..n {
+        let sign = if i % 2 == 0 { A::one() } else { -A::one() };
+        let denom = if n > 1 { A::from(n - 1).unwrap() } else { A::one() };
+        x[[i, 0]] = sign * (A::one() + A::from(i).unwrap() / denom);
+    }
+    lu_solve_inplace(f, &mut x);
+    let alternative = (A::one() + A::one()) * abs_sum(&x) / (A::from(3).unwrap() * nf);
+    estimate.max(alternative)
+}
@@ -0,0 +1,110 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use {LinalgScalar, NdFloat};
+use super::least_squares::least_squares;
+
+/// Return the Vandermonde matrix of the points `x` with `n` columns: column
+/// `j` is `x` to the power `j`, so element `[i, j]` is `x[i]ʲ`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::vander;
+///
+/// assert_eq!(vander(&arr1(&[1., 2., 3.]), 3), arr2(&[[1., 1., 1.],
+///                                                   [1., 2., 4.],
+///                                                   [1., 3., 9.]]));
+/// ```
+pub fn vander<A, S>(x: &ArrayBase<S, Ix1>, n: usize) -> Array2<A>
+    where A: LinalgScalar,
+          S: Data<Elem=A>,
+{
+    let mut v = Array2::zeros((x.len(), n));
+    for (mut row, &t) in v.outer_iter_mut().zip(x) {
+        let mut power = A::one();
+        for elt in row.iter_mut() {
+            *elt = power;
+            power = power * t;
+        }
+    }
+    v
+}
+
+/// Fit a polynomial of degree `deg` to the points `(x[i], y[i])` by least
+/// squares, and return its coefficients in order of increasing power:
+/// `p(t) = c[0] + c[1] t + … + c[deg] t^deg`.
+///
+/// The least squares problem is solved with
+/// [`least_squares`](fn.least_squares.html) on the Vandermonde matrix of
+/// `x`, with its columns scaled to unit norm for a better conditioned
+/// problem. With fewer than `deg + 1` distinct points the fit is not
+/// unique, and the coefficients of minimal (scaled) norm are returned.
+///
+/// **Panics** if `x` and `y` have different lengths, or if `x` has
+/// infinite or NaN elements.
+///
+/// ```
+/// use ndarray::{arr1, Array};
+/// use ndarray::linalg::{polyfit, polyval};
+///
+/// let x = Array::linspace(-1., 1., 11);
+/// let y = x.mapv(|t| 2. - t + 3. * t * t);
+/// let c = polyfit(&x, &y, 2);
+/// assert!(c.all_close(&arr1(&[2., -1., 3.]), 1e-12));
+/// assert!(polyval(&c, &x).all_close(&y, 1e-12));
+/// ```
+pub fn polyfit<A, S, S2>(x: &ArrayBase<S, Ix1>, y: &ArrayBase<S2, Ix1>, deg: usize) -> Array1<A>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+{
+    assert!(x.len() == y.len(),
+            "ndarray: polyfit: x and y must have the same length, but have {} and {}",
+            x.len(), y.len());
+    let mut v = vander(x, deg + 1);
+    let scale = v.axis_iter(Axis(1))
+                 .map(|col| {
+                     let norm = col.dot(&col).sqrt();
+                     if norm > A::zero() { norm } else { A::one() }
+                 })
+                 .collect::<Vec<_>>();
+    for (mut col, &s) in v.axis_iter_mut(Axis(1)).zip(&scale) {
+        col.mapv_inplace(|e| e / s);
+    }
+    let mut c = least_squares(&v, y, None)
+                    .expect("ndarray: polyfit: x must not have infinite or NaN elements")
+                    .into_solution();
+    for (elt, &s) in c.iter_mut().zip(&scale) {
+        *elt /= s;
+    }
+    c
+}
+
+/// Evaluate the polynomial with coefficients `c`, in order of increasing
+/// power, at each element of `x`: `p(t) = c[0] + c[1] t + c[2] t² + …`.
+///
+/// This uses Horner's method. The polynomial with no coefficients is zero.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::polyval;
+///
+/// // 1 + 2 t²
+/// let c = arr1(&[1., 0., 2.]);
+/// assert_eq!(polyval(&c, &arr2(&[[0., 1.], [2., -3.]])),
+///            arr2(&[[1., 3.], [9., 19.]]));
+/// ```
+pub fn polyval<A, S, S2, D>(c: &ArrayBase<S, Ix1>, x: &ArrayBase<S2, D>) -> Array<A, D>
+    where A: LinalgScalar,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    x.mapv(|t| c.iter().rev().fold(A::zero(), |acc, &ci| acc * t + ci))
+}
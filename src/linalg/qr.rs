@@ -0,0 +1,229 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use imp_prelude::*;
+use {ComplexField, NdFloat};
+#[cfg(feature="lapack")]
+use super::lapack;
+use super::householder::{make_householder, apply_householder_left};
+use super::solve::SingularError;
+use super::triangular::{back_substitution, right_hand_side, Diag};
+
+/// A QR decomposition, `A = Q R`, of an `m × n` matrix `A`.
+///
+/// `Q` is an `m × m` orthogonal matrix and `R` is `m × n` upper triangular.
+/// With `k = min(m, n)`, only the first `k` columns of `Q` and the first
+/// `k` rows of `R` are needed to reconstruct `A`; these are the *thin*
+/// factors.
+///
+/// `Q` is stored as the product of `k` Householder reflections.
+///
+/// Created with [`qr`](fn.qr.html).
+#[derive(Clone, Debug)]
+pub struct QR<A> {
+    /// The Householder vectors below the diagonal (without their unit first
+    /// element), `R` on and above
+    qr: Array2<A>,
+    /// The scalar factor of each Householder reflection
+    tau: Vec<A>,
+}
+
+/// Compute the QR decomposition of `a` with Householder reflections.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::qr;
+///
+/// let a = arr2(&[[3., 1.],
+///                [4., 2.],
+///                [0., 2.]]);
+/// let f = qr(&a);
+/// let (q, r) = (f.q_thin(), f.r_thin());
+/// assert_eq!(q.dim(), (3, 2));
+/// assert!(q.dot(&r).all_close(&a, 1e-12));
+/// assert!(q.t().dot(&q).all_close(&arr2(&[[1., 0.], [0., 1.]]), 1e-12));
+/// assert_eq!(r[[1, 0]], 0.);
+/// ```
+pub fn qr<A, S>(a: &ArrayBase<S, Ix2>) -> QR<A>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    #[cfg(feature="lapack")]
+    {
+        if let Some((qr, tau)) = lapack::qr(a.view()) {
+            return QR { qr: qr, tau: tau };
+        }
+    }
+    let mut qr = a.to_owned();
+    let tau = factor_inplace(qr.view_mut());
+    QR {
+        qr: qr,
+        tau: tau,
+    }
+}
+
+/// Compute the QR decomposition of `a` with Householder reflections,
+/// overwriting `a` with the factors, and return the scalar factors of the
+/// reflections.
+///
+/// On return, `a` holds `R` on and above the diagonal, and below the
+/// diagonal of column `k` the Householder vector `v` of reflection `k`
+/// without its unit first element (the layout of LAPACK). `Q` is the
+/// product `H₀ H₁ …` of the reflections `H_k = I - tau[k] v vᵀ`, which can
+/// be applied with
+/// [`apply_householder_left`](fn.apply_householder_left.html). This avoids
+/// allocating the factors, for repeated factorizations of same-sized
+/// matrices.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::qr_inplace;
+///
+/// let mut a = arr2(&[[3f64, 1.],
+///                    [4., 2.]]);
+/// let tau = qr_inplace(&mut a);
+/// assert_eq!(tau.len(), 2);
+/// // the first column is reflected to (±5, 0)
+/// assert!((a[[0, 0]] * a[[0, 0]] - 25.).abs() < 1e-12);
+/// ```
+pub fn qr_inplace<A, S>(a: &mut ArrayBase<S, Ix2>) -> Vec<A>
+    where A: NdFloat,
+          S: DataMut<Elem=A>,
+{
+    #[cfg(feature="lapack")]
+    {
+        if let Some((qr, tau)) = lapack::qr(a.view()) {
+            a.assign(&qr);
+            return tau;
+        }
+    }
+    factor_inplace(a.view_mut())
+}
+
+/// Overwrite `qr` with its packed QR factors, and return the scalar factors
+/// of the reflections.
+fn factor_inplace<A: NdFloat>(mut qr: ArrayViewMut2<A>) -> Vec<A> {
+    let (m, n) = qr.dim();
+    let mut tau = Vec::with_capacity(cmp::min(m, n));
+    for k in 0..cmp::min(m, n) {
+        let (mut left, right) = qr.view_mut().split_at(Axis(1), k + 1);
+        let (t, _) = make_householder(left.column_mut(k).split_at(Axis(0), k).1);
+        tau.push(t);
+        let v = reflector(left.column(k), k);
+        apply_householder_left(v.view(), t, right.split_at(Axis(0), k).1);
+    }
+    tau
+}
+
+impl<A: NdFloat> QR<A> {
+    /// Return the `m × m` orthogonal factor `Q`.
+    pub fn q(&self) -> Array2<A> {
+        self.q_cols(self.qr.rows())
+    }
+
+    /// Return the first `min(m, n)` columns of `Q`.
+    pub fn q_thin(&self) -> Array2<A> {
+        self.q_cols(self.tau.len())
+    }
+
+    /// Return the `m × n` upper triangular factor `R`.
+    pub fn r(&self) -> Array2<A> {
+        self.r_rows(self.qr.rows())
+    }
+
+    /// Return the first `min(m, n)` rows of `R`.
+    pub fn r_thin(&self) -> Array2<A> {
+        self.r_rows(self.tau.len())
+    }
+
+    /// Solve the least squares problem, minimizing `|A x - b|`, with the
+    /// decomposition: `x = R⁻¹ Qᵀ b`. The decomposition can be reused for
+    /// any number of right hand sides.
+    ///
+    /// `A` must have full column rank; for a rank deficient matrix use
+    /// [`least_squares`](fn.least_squares.html). For a square matrix this
+    /// is the solution of `A x = b`.
+    ///
+    /// `b` is either a vector, or a matrix with a right hand side in each
+    /// column; the solution `x` has `n` rows and the shape of `b`
+    /// otherwise.
+    ///
+    /// Return an error if `R` has a zero on its diagonal.
+    ///
+    /// **Panics** if `A` has fewer rows than columns, if `b` is not one or
+    /// two dimensional, or if `b` does not have `m` rows.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::qr;
+    ///
+    /// // fit lines y = c₀ + c₁ t through two sets of points
+    /// let f = qr(&arr2(&[[1., 0.],
+    ///                    [1., 1.],
+    ///                    [1., 2.]]));
+    /// let c = f.solve(&arr1(&[1., 2., 4.])).unwrap();
+    /// assert!(c.all_close(&arr1(&[5. / 6., 1.5]), 1e-12));
+    /// let c = f.solve(&arr1(&[0., 1., 2.])).unwrap();
+    /// assert!(c.all_close(&arr1(&[0., 1.]), 1e-12));
+    /// ```
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, SingularError>
+        where A: ComplexField,
+              S: Data<Elem=A>,
+              D: Dimension,
+    {
+        let (m, n) = self.qr.dim();
+        assert!(m >= n, "ndarray: QR::solve: matrix must have at least as many rows as \
+                columns, but has shape {:?}", self.qr.shape());
+        let mut y = right_hand_side("QR::solve", m, b);
+        // Qᵀ b = H_{k-1} … H₁ H₀ b
+        for (k, &t) in self.tau.iter().enumerate() {
+            let v = reflector(self.qr.column(k), k);
+            apply_householder_left(v.view(), t, y.view_mut().split_at(Axis(0), k).1);
+        }
+        let mut x = y.view().split_at(Axis(0), n).0.to_owned();
+        try!(back_substitution(self.qr.view().split_at(Axis(0), n).0, x.view_mut(),
+                               Diag::NonUnit));
+        let mut dim = b.raw_dim();
+        dim.slice_mut()[0] = n;
+        Ok(x.into_shape(dim).unwrap())
+    }
+
+    fn q_cols(&self, cols: usize) -> Array2<A> {
+        let m = self.qr.rows();
+        let mut q = Array2::zeros((m, cols));
+        for i in 0..cols {
+            q[[i, i]] = A::one();
+        }
+        // Q = H₀ H₁ … applied to the identity, from the right end
+        for (k, &t) in self.tau.iter().enumerate().rev() {
+            let v = reflector(self.qr.column(k), k);
+            apply_householder_left(v.view(), t, q.view_mut().split_at(Axis(0), k).1);
+        }
+        q
+    }
+
+    fn r_rows(&self, rows: usize) -> Array2<A> {
+        let mut r = Array2::zeros((rows, self.qr.cols()));
+        for ((i, j), x) in r.indexed_iter_mut() {
+            if i <= j {
+                *x = self.qr[[i, j]];
+            }
+        }
+        r
+    }
+}
+
+/// Return the Householder vector stored in `col` below row `k`, with its
+/// unit first element.
+fn reflector<A: NdFloat>(col: ArrayView1<A>, k: Ix) -> Array1<A> {
+    let mut v = col.split_at(Axis(0), k).1.to_owned();
+    v[0] = A::one();
+    v
+}
@@ -0,0 +1,119 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::fmt;
+use std::error::Error;
+
+use imp_prelude::*;
+use NdFloat;
+use super::lu::{lu, lu_solve_inplace};
+
+/// An error returned when a matrix that must be nonsingular (invertible) is
+/// singular.
+///
+/// Only exact singularity is detected, when a pivot is exactly zero: for a
+/// matrix that is singular up to rounding errors, the result is returned
+/// but may be dominated by them. Use [`cond`](fn.cond.html) or
+/// [`rank`](fn.rank.html) to check how close to singular a matrix is.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SingularError;
+
+impl Error for SingularError {
+    fn description(&self) -> &str {
+        "the matrix is singular"
+    }
+}
+
+impl fmt::Display for SingularError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+/// Solve the linear system `a x = b` for the square matrix `a`.
+///
+/// `b` is either a vector, or a matrix with a right hand side in each
+/// column; the solution `x` has the same shape as `b`. The matrix is
+/// factorized with [`lu`](fn.lu.html), followed by forward and back
+/// substitution; to solve with the same matrix many times, keep the
+/// decomposition and use [`LU::solve`](struct.LU.html#method.solve).
+///
+/// Return an error if `a` is singular; see
+/// [`SingularError`](struct.SingularError.html) for which matrices are
+/// detected.
+///
+/// **Panics** if `a` is not square, if `b` is not one or two dimensional,
+/// or if `b` has a different number of rows than `a`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::solve;
+///
+/// let a = arr2(&[[3., 1.],
+///                [1., 2.]]);
+/// let b = arr1(&[9., 8.]);
+/// let x = solve(&a, &b).unwrap();
+/// assert!(x.all_close(&arr1(&[2., 3.]), 1e-12));
+/// ```
+pub fn solve<A, S, S2, D>(a: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, D>)
+    -> Result<Array<A, D>, SingularError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert!(a.is_square(), "ndarray: solve: matrix must be square, but has shape {:?}",
+            a.shape());
+    assert!(b.ndim() == 1 || b.ndim() == 2,
+            "ndarray: solve: right hand side must be one or two dimensional, but has shape {:?}",
+            b.shape());
+    let n = a.rows();
+    assert!(b.shape()[0] == n,
+            "ndarray: solve: right hand side has shape {:?}, but the matrix has {} rows",
+            b.shape(), n);
+    lu(a).solve(b)
+}
+
+/// Compute the inverse of the square matrix `a`.
+///
+/// The matrix is factorized once with [`lu`](fn.lu.html), and the
+/// factorization is used to solve against the identity.
+///
+/// Return an error if `a` is singular; see
+/// [`SingularError`](struct.SingularError.html) for which matrices are
+/// detected.
+///
+/// **Panics** if `a` is not square.
+///
+/// ```
+/// use ndarray::{arr2, Array2};
+/// use ndarray::linalg::{inverse, SingularError};
+///
+/// let a = arr2(&[[4., 7.],
+///                [2., 6.]]);
+/// let inv = inverse(&a).unwrap();
+/// assert!(a.dot(&inv).all_close(&Array2::eye(2), 1e-12));
+///
+/// let s = arr2(&[[1., 2.],
+///                [2., 4.]]);
+/// assert_eq!(inverse(&s), Err(SingularError));
+/// ```
+pub fn inverse<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array2<A>, SingularError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    assert!(a.is_square(), "ndarray: inverse: matrix must be square, but has shape {:?}",
+            a.shape());
+    let f = lu(a);
+    if f.is_singular() {
+        return Err(SingularError);
+    }
+    let mut inv = Array2::eye(a.rows());
+    lu_solve_inplace(&f, &mut inv);
+    Ok(inv)
+}
@@ -0,0 +1,293 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::{self, Ordering};
+
+use imp_prelude::*;
+use NdFloat;
+use super::eig::ConvergenceError;
+use super::givens::apply_givens;
+#[cfg(feature="lapack")]
+use super::lapack;
+
+/// Upper bound on the number of Jacobi sweeps; convergence is quadratic, so
+/// this is only reached for pathological input, which is reported as a
+/// `ConvergenceError`.
+const MAX_SWEEPS: usize = 100;
+
+/// Compute the singular value decomposition `A = U Σ Vᵀ` of the `m × n`
+/// matrix `a`.
+///
+/// Return `(U, s, Vᵀ)`, where, with `k = min(m, n)`, `U` is `m × k` with
+/// orthonormal columns, `s` has the `k` singular values in decreasing
+/// order, and `Vᵀ` is `k × n` with orthonormal rows (the thin
+/// decomposition).
+///
+/// The decomposition is computed with one-sided Jacobi rotations, which
+/// gives accurate small singular values.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the Jacobi
+/// iteration does not converge.
+///
+/// ```
+/// use ndarray::{arr1, arr2, Array2};
+/// use ndarray::linalg::svd;
+///
+/// let a = arr2(&[[3., 0.],
+///                [4., 0.],
+///                [0., 2.]]);
+/// let (u, s, vt) = svd(&a).unwrap();
+/// assert!(s.all_close(&arr1(&[5., 2.]), 1e-12));
+/// assert!((&u * &s).dot(&vt).all_close(&a, 1e-12));
+/// assert!(vt.dot(&vt.t()).all_close(&Array2::eye(2), 1e-12));
+/// ```
+pub fn svd<A, S>(a: &ArrayBase<S, Ix2>)
+    -> Result<(Array2<A>, Array1<A>, Array2<A>), ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    if !a.iter().all(|x| x.is_finite()) {
+        return Err(ConvergenceError);
+    }
+    #[cfg(feature="lapack")]
+    {
+        if let Some((Some(u), s, Some(vt))) = lapack::svd(a.view(), true) {
+            return Ok((u, s, vt));
+        }
+    }
+    let (m, n) = a.dim();
+    if m >= n {
+        let (ut, s, vt) = try!(jacobi_svd(a.t().to_owned(), true));
+        Ok((ut.reversed_axes(), s, vt.unwrap()))
+    } else {
+        // decompose the transpose, Aᵀ = V Σ Uᵀ
+        let (vt, s, ut) = try!(jacobi_svd(a.to_owned(), true));
+        Ok((ut.unwrap().reversed_axes(), s, vt))
+    }
+}
+
+/// Compute the singular values of `a`, in decreasing order.
+///
+/// This is faster than [`svd`](fn.svd.html), since the singular vectors
+/// are not accumulated.
+///
+/// Return an error if `a` has infinite or NaN elements, or if the Jacobi
+/// iteration does not converge.
+pub fn svd_values<A, S>(a: &ArrayBase<S, Ix2>) -> Result<Array1<A>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    if !a.iter().all(|x| x.is_finite()) {
+        return Err(ConvergenceError);
+    }
+    #[cfg(feature="lapack")]
+    {
+        if let Some((_, s, _)) = lapack::svd(a.view(), false) {
+            return Ok(s);
+        }
+    }
+    let (m, n) = a.dim();
+    let g = if m >= n { a.t().to_owned() } else { a.to_owned() };
+    Ok(try!(jacobi_svd(g, false)).1)
+}
+
+/// Estimate the rank of `a`: the number of its singular values that are
+/// greater than `tol`.
+///
+/// If `tol` is `None`, the tolerance `max(m, n) ε σ₁` is used, where `σ₁`
+/// is the largest singular value and `ε` the machine epsilon; this accounts
+/// for rounding errors in the computation of the singular values.
+///
+/// Return an error if the singular values can not be computed, see
+/// [`svd_values`](fn.svd_values.html).
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::rank;
+///
+/// let a = arr2(&[[1., 2., 3.],
+///                [2., 4., 6.],
+///                [1., 0., 1.]]);
+/// assert_eq!(rank(&a, None).unwrap(), 2);
+/// assert_eq!(rank(&a, Some(1.)).unwrap(), 1);
+/// ```
+pub fn rank<A, S>(a: &ArrayBase<S, Ix2>, tol: Option<A>) -> Result<usize, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    let s = try!(svd_values(a));
+    let tol = tol.unwrap_or_else(|| default_tolerance(a.dim(), &s));
+    Ok(s.iter().filter(|&&x| x > tol).count())
+}
+
+/// Compute the Moore–Penrose pseudo-inverse of the `m × n` matrix `a`, an
+/// `n × m` matrix.
+///
+/// With the singular value decomposition `A = U Σ Vᵀ`, this is
+/// `V Σ⁺ Uᵀ`, where `Σ⁺` inverts the singular values greater than
+/// `rcond σ₁` and sets the rest to zero (`σ₁` is the largest singular
+/// value). If `rcond` is `None`, `max(m, n) ε` is used, where `ε` is the
+/// machine epsilon.
+///
+/// For a rank deficient `a`, `pinv(a).dot(b)` is the minimum norm least
+/// squares solution of `a x = b`.
+///
+/// Return an error if the singular value decomposition can not be
+/// computed, see [`svd`](fn.svd.html).
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::pinv;
+///
+/// let a = arr2(&[[1., 2.],
+///                [2., 4.]]);
+/// let p = pinv(&a, None).unwrap();
+/// assert!(a.dot(&p).dot(&a).all_close(&a, 1e-12));
+/// assert!(p.all_close(&(a.t().to_owned() / 25.), 1e-12));
+/// ```
+pub fn pinv<A, S>(a: &ArrayBase<S, Ix2>, rcond: Option<A>)
+    -> Result<Array2<A>, ConvergenceError>
+    where A: NdFloat,
+          S: Data<Elem=A>,
+{
+    let (u, s, vt) = try!(svd(a));
+    let cutoff = singular_value_cutoff(rcond, a.dim(), &s);
+    let mut ut = u.reversed_axes();
+    for (mut row, &sv) in ut.outer_iter_mut().zip(&s) {
+        if sv > cutoff {
+            row.mapv_inplace(|x| x / sv);
+        } else {
+            row.fill(A::zero());
+        }
+    }
+    Ok(vt.t().dot(&ut))
+}
+
+/// The cutoff `rcond σ₁` for the singular values `s` of an `m × n` matrix,
+/// or the default tolerance if `rcond` is `None`.
+pub fn singular_value_cutoff<A: NdFloat>(rcond: Option<A>, dim: (Ix, Ix), s: &Array1<A>) -> A {
+    match rcond {
+        Some(rcond) => rcond * if s.len() > 0 { s[0] } else { A::zero() },
+        None => default_tolerance(dim, s),
+    }
+}
+
+/// The default cutoff for singular values, `max(m, n) ε σ₁`.
+fn default_tolerance<A: NdFloat>((m, n): (Ix, Ix), s: &Array1<A>) -> A {
+    let largest = if s.len() > 0 { s[0] } else { A::zero() };
+    A::from(cmp::max(m, n)).unwrap() * A::epsilon() * largest
+}
+
+/// `(Uᵀ, s, Vᵀ)` as returned by `jacobi_svd`.
+type JacobiSvd<A> = (Array2<A>, Array1<A>, Option<Array2<A>>);
+
+/// One-sided Jacobi SVD of the `l × k` matrix `M = Gᵀ`, `l ≥ k`, given as
+/// its transpose `g` so that the columns of `M` are contiguous rows.
+///
+/// Return `(Uᵀ, s, Vᵀ)` of `M = U Σ Vᵀ`; `Uᵀ` is `k × l`, and `Vᵀ` is
+/// `k × k` if `vectors` is true. Without `vectors`, `Uᵀ` is unspecified.
+///
+/// Return an error if the rows are not orthogonal after `MAX_SWEEPS`
+/// sweeps.
+fn jacobi_svd<A>(mut g: Array2<A>, vectors: bool) -> Result<JacobiSvd<A>, ConvergenceError>
+    where A: NdFloat,
+{
+    let k = g.rows();
+    let mut vt = if vectors { Some(Array2::eye(k)) } else { None };
+    let eps = A::epsilon();
+    // rows this small next to the whole matrix are rounding errors, and can
+    // not be made orthogonal to the others; rotating them never converges
+    let negligible = eps * eps * g.iter().fold(A::zero(), |acc, &x| acc + x * x);
+    let mut sweeps = 0;
+    loop {
+        if sweeps == MAX_SWEEPS {
+            return Err(ConvergenceError);
+        }
+        sweeps += 1;
+        let mut rotated = false;
+        for p in 0..k {
+            for q in p + 1..k {
+                let alpha = g.row(p).dot(&g.row(p));
+                let beta = g.row(q).dot(&g.row(q));
+                let gamma = g.row(p).dot(&g.row(q));
+                if gamma == A::zero() || gamma.abs() <= eps * (alpha * beta).sqrt()
+                    || alpha.min(beta) <= negligible
+                {
+                    continue;
+                }
+                rotated = true;
+                // the rotation that makes rows p and q orthogonal
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + zeta.hypot(A::one()));
+                let c = A::one() / t.hypot(A::one());
+                let s = c * t;
+                apply_givens(&mut g, Axis(0), p, q, c, s);
+                if let Some(ref mut vt) = vt {
+                    apply_givens(vt, Axis(0), p, q, c, s);
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+
+    let mut s = g.outer_iter().map(|row| row.norm_l2()).collect::<Vec<_>>();
+    let mut order = (0..k).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| s[j].partial_cmp(&s[i]).unwrap_or(Ordering::Equal));
+    s = order.iter().map(|&i| s[i]).collect();
+    let s = Array1::from_vec(s);
+    if !vectors {
+        return Ok((g, s, None));
+    }
+    let mut ut = g.select(Axis(0), &order);
+    let vt = vt.unwrap().select(Axis(0), &order);
+    let tiny = if k > 0 { s[0] * eps } else { A::zero() };
+    for (mut row, &sv) in ut.outer_iter_mut().zip(&s) {
+        if sv > tiny {
+            row.mapv_inplace(|x| x / sv);
+        }
+    }
+    complete_orthonormal(&mut ut, |i| s[i] <= tiny);
+    Ok((ut, s, Some(vt)))
+}
+
+/// Replace the rows `i` of `a` with `replace(i)` true by unit vectors that
+/// are orthogonal to all other rows (which are orthonormal).
+fn complete_orthonormal<A, F>(a: &mut Array2<A>, replace: F)
+    where A: NdFloat,
+          F: Fn(usize) -> bool,
+{
+    let (k, l) = a.dim();
+    let mut done = (0..k).map(|i| !replace(i)).collect::<Vec<_>>();
+    let mut candidate = 0;
+    for i in 0..k {
+        if done[i] {
+            continue;
+        }
+        // Gram-Schmidt on the standard basis vectors, until one is
+        // sufficiently independent of the rows so far
+        while candidate < l {
+            let mut x = Array1::zeros(l);
+            x[candidate] = A::one();
+            candidate += 1;
+            for _ in 0..2 {
+                for (row, _) in a.outer_iter().zip(&done).filter(|&(_, &d)| d) {
+                    let c = row.dot(&x);
+                    x.scaled_add(-c, &row);
+                }
+            }
+            let norm = x.norm_l2();
+            if norm > A::from(0.5).unwrap() {
+                a.row_mut(i).assign(&(x / norm));
+                done[i] = true;
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use ComplexField;
+use super::cholesky::{cholesky, Cholesky, NotPositiveDefiniteError};
+use super::triangular::{check_right_hand_side, right_hand_side};
+
+/// A square symmetric matrix, or Hermitian for complex elements, of which
+/// only the lower triangle is stored.
+///
+/// The wrapped matrix is checked to be square once, on construction; the
+/// upper triangle is never read, so it may hold anything. Element `[i, j]`
+/// above the diagonal is the conjugate of element `[j, i]`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::Symmetric;
+///
+/// // the upper triangle is ignored
+/// let a = Symmetric::new(arr2(&[[4., 0.],
+///                               [2., 3.]]));
+/// assert_eq!(a.to_dense(), arr2(&[[4., 2.],
+///                                 [2., 3.]]));
+/// let x = arr1(&[1., -1.]);
+/// let b = a.dot(&x);
+/// assert_eq!(b, arr1(&[2., -1.]));
+/// assert!(a.solve(&b).unwrap().all_close(&x, 1e-12));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Symmetric<M> {
+    matrix: M,
+}
+
+impl<A, S> Symmetric<ArrayBase<S, Ix2>>
+    where A: ComplexField,
+          S: Data<Elem=A>,
+{
+    /// Wrap the square matrix `a`.
+    ///
+    /// **Panics** if `a` is not square.
+    pub fn new(a: ArrayBase<S, Ix2>) -> Self {
+        assert!(a.is_square(), "ndarray: Symmetric::new: matrix must be square, \
+                but has shape {:?}", a.shape());
+        Symmetric { matrix: a }
+    }
+
+    /// Return the number of rows (and columns) of the matrix.
+    pub fn dim(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Return a view of the wrapped matrix, including the unused triangle.
+    pub fn view(&self) -> ArrayView2<A> {
+        self.matrix.view()
+    }
+
+    /// Return the wrapped matrix.
+    pub fn into_inner(self) -> ArrayBase<S, Ix2> {
+        self.matrix
+    }
+
+    /// Return the matrix as a dense array, with the upper triangle filled
+    /// in from the lower.
+    pub fn to_dense(&self) -> Array2<A> {
+        let mut a = self.matrix.to_owned();
+        for i in 0..self.dim() {
+            for j in i + 1..self.dim() {
+                a[[i, j]] = a[[j, i]].conj();
+            }
+        }
+        a
+    }
+
+    /// Return the product of the matrix and `x`, reading only the lower
+    /// triangle.
+    ///
+    /// `x` is either a vector or a matrix; the product has the same shape.
+    ///
+    /// **Panics** if `x` is not one or two dimensional, or if `x` does not
+    /// have `n` rows.
+    pub fn dot<S2, D>(&self, x: &ArrayBase<S2, D>) -> Array<A, D>
+        where S2: Data<Elem=A>,
+              D: Dimension,
+    {
+        let n = self.dim();
+        let x2 = right_hand_side("Symmetric::dot", n, x);
+        let mut y = Array2::zeros(x2.dim());
+        // each element below the diagonal contributes twice
+        for i in 0..n {
+            for j in 0..i {
+                let a = self.matrix[[i, j]];
+                y.row_mut(i).scaled_add(a, &x2.row(j));
+                y.row_mut(j).scaled_add(a.conj(), &x2.row(i));
+            }
+            y.row_mut(i).scaled_add(A::from_real(self.matrix[[i, i]].re()), &x2.row(i));
+        }
+        y.into_shape(x.raw_dim()).unwrap()
+    }
+
+    /// Compute the Cholesky decomposition `a = l lᴴ`; see
+    /// [`cholesky`](fn.cholesky.html).
+    ///
+    /// Return an error if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Result<Cholesky<A>, NotPositiveDefiniteError> {
+        cholesky(&self.matrix)
+    }
+
+    /// Solve the linear system `a x = b` for the positive definite matrix
+    /// `a`, with its Cholesky decomposition.
+    ///
+    /// To solve for many right hand sides at different times, keep the
+    /// decomposition from [`.cholesky()`](#method.cholesky) instead.
+    ///
+    /// `b` is either a vector, or a matrix with a right hand side in each
+    /// column; the solution `x` has the same shape as `b`.
+    ///
+    /// Return an error if the matrix is not positive definite.
+    ///
+    /// **Panics** if `b` is not one or two dimensional, or if `b` does not
+    /// have `n` rows.
+    pub fn solve<S2, D>(&self, b: &ArrayBase<S2, D>)
+        -> Result<Array<A, D>, NotPositiveDefiniteError>
+        where S2: Data<Elem=A>,
+              D: Dimension,
+    {
+        check_right_hand_side("Symmetric::solve", self.dim(), b);
+        Ok(try!(self.cholesky()).solve(b))
+    }
+}
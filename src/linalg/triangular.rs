@@ -0,0 +1,312 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use imp_prelude::*;
+use {ComplexField, Si};
+use super::impl_linalg::general_mat_mul;
+use super::solve::SingularError;
+
+/// The number of rows solved at a time; the update of the remaining rows
+/// with each finished block is a matrix product.
+const BLOCK_SIZE: usize = 64;
+
+/// Whether the diagonal of a triangular matrix is stored, or known to be
+/// all ones.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Diag {
+    /// The diagonal is read from the matrix.
+    NonUnit,
+    /// The diagonal is all ones, and is not read from the matrix (so it can
+    /// hold something else, like the packed factors of an LU
+    /// decomposition).
+    Unit,
+}
+
+/// Solve the linear system `l x = b` for the lower triangular matrix `l`,
+/// by forward substitution.
+///
+/// `b` is either a vector, or a matrix with a right hand side in each
+/// column; the solution `x` has the same shape as `b`. All columns are
+/// solved together, a block of rows at a time, so that most of the work is
+/// matrix multiplication.
+///
+/// Only the lower triangle of `l` is read, and its diagonal only with
+/// `Diag::NonUnit`. `l` may be real or complex; to solve with the conjugate
+/// transpose `lᴴ`, pass `lᴴ` to [`subst_bw`](fn.subst_bw.html).
+///
+/// Return an error if `l` is singular (a diagonal element is zero).
+///
+/// **Panics** if `l` is not square, if `b` is not one or two dimensional,
+/// or if `b` does not have as many rows as `l`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{subst_fw, Diag};
+///
+/// let l = arr2(&[[2., 0.],
+///                [1., 4.]]);
+/// let x = subst_fw(&l, &arr1(&[2., 9.]), Diag::NonUnit).unwrap();
+/// assert!(x.all_close(&arr1(&[1., 2.]), 1e-12));
+///
+/// // two right hand sides, and the diagonal taken as ones
+/// let b = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let x = subst_fw(&l, &b, Diag::Unit).unwrap();
+/// assert!(x.all_close(&arr2(&[[1., 2.], [2., 2.]]), 1e-12));
+/// ```
+pub fn subst_fw<A, S, S2, D>(l: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, D>, diag: Diag)
+    -> Result<Array<A, D>, SingularError>
+    where A: ComplexField,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert_square("subst_fw", l);
+    let mut x = right_hand_side("subst_fw", l.rows(), b);
+    try!(forward_substitution(l.view(), x.view_mut(), diag));
+    Ok(x.into_shape(b.raw_dim()).unwrap())
+}
+
+/// Solve the linear system `u x = b` for the upper triangular matrix `u`,
+/// by back substitution.
+///
+/// `b` is either a vector, or a matrix with a right hand side in each
+/// column; the solution `x` has the same shape as `b`. All columns are
+/// solved together, a block of rows at a time, so that most of the work is
+/// matrix multiplication.
+///
+/// Only the upper triangle of `u` is read, and its diagonal only with
+/// `Diag::NonUnit`. `u` may be real or complex.
+///
+/// Return an error if `u` is singular (a diagonal element is zero).
+///
+/// **Panics** if `u` is not square, if `b` is not one or two dimensional,
+/// or if `b` does not have as many rows as `u`.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::{subst_bw, Diag};
+///
+/// let u = arr2(&[[2., 1.],
+///                [0., 4.]]);
+/// let x = subst_bw(&u, &arr1(&[4., 8.]), Diag::NonUnit).unwrap();
+/// assert!(x.all_close(&arr1(&[1., 2.]), 1e-12));
+/// ```
+pub fn subst_bw<A, S, S2, D>(u: &ArrayBase<S, Ix2>, b: &ArrayBase<S2, D>, diag: Diag)
+    -> Result<Array<A, D>, SingularError>
+    where A: ComplexField,
+          S: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert_square("subst_bw", u);
+    let mut x = right_hand_side("subst_bw", u.rows(), b);
+    try!(back_substitution(u.view(), x.view_mut(), diag));
+    Ok(x.into_shape(b.raw_dim()).unwrap())
+}
+
+fn assert_square<S: Data>(name: &str, a: &ArrayBase<S, Ix2>) {
+    assert!(a.is_square(), "ndarray: {}: matrix must be square, but has shape {:?}",
+            name, a.shape());
+}
+
+/// Check the shape of the right hand side `b` of an `n × n` system.
+pub fn check_right_hand_side<S, D>(name: &str, n: usize, b: &ArrayBase<S, D>)
+    where S: Data,
+          D: Dimension,
+{
+    assert!(b.ndim() == 1 || b.ndim() == 2,
+            "ndarray: {}: right hand side must be one or two dimensional, but has shape {:?}",
+            name, b.shape());
+    assert!(b.shape()[0] == n,
+            "ndarray: {}: right hand side has shape {:?}, but the matrix has {} rows",
+            name, b.shape(), n);
+}
+
+/// Check the shape of the right hand side `b` of an `n × n` system, and
+/// return it as an `n × k` matrix.
+pub fn right_hand_side<A, S, D>(name: &str, n: usize, b: &ArrayBase<S, D>) -> Array2<A>
+    where A: Clone,
+          S: Data<Elem=A>,
+          D: Dimension,
+{
+    check_right_hand_side(name, n, b);
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    Array::from_iter(b.iter().cloned()).into_shape((n, k)).unwrap()
+}
+
+/// Overwrite `x` with `l⁻¹ x` for the lower triangular matrix `l`, a block
+/// of rows at a time.
+pub fn forward_substitution<A>(l: ArrayView2<A>, mut x: ArrayViewMut2<A>, diag: Diag)
+    -> Result<(), SingularError>
+    where A: ComplexField,
+{
+    let n = l.rows();
+    let mut start = 0;
+    while start < n {
+        let end = cmp::min(n, start + BLOCK_SIZE);
+        let (solved, rest) = x.view_mut().split_at(Axis(0), start);
+        let block = rest.split_at(Axis(0), end - start).0;
+        let rows = l.split_at(Axis(0), start).1.split_at(Axis(0), end - start).0;
+        let (left, diagonal) = rows.split_at(Axis(1), start);
+        let diagonal = diagonal.split_at(Axis(1), end - start).0;
+        try!(solve_lower_block(left, diagonal, solved.view(), block, diag));
+        start = end;
+    }
+    Ok(())
+}
+
+/// Overwrite `x` with `u⁻¹ x` for the upper triangular matrix `u`.
+pub fn back_substitution<A>(u: ArrayView2<A>, mut x: ArrayViewMut2<A>, diag: Diag)
+    -> Result<(), SingularError>
+    where A: ComplexField,
+{
+    // back substitution with u is forward substitution with u reversed
+    // along both axes
+    let reverse = Si(0, None, -1);
+    let u = u.slice(&[reverse, reverse]);
+    let x = x.slice_mut(&[reverse, Si(0, None, 1)]);
+    forward_substitution(u, x, diag)
+}
+
+/// Solve one block of rows of a lower triangular system in place:
+/// `x ← diagonal⁻¹ (x - left solved)`, where `diagonal` is the lower
+/// triangular diagonal block.
+fn solve_lower_block<A>(left: ArrayView2<A>,
+                        diagonal: ArrayView2<A>,
+                        solved: ArrayView2<A>,
+                        mut x: ArrayViewMut2<A>,
+                        diag: Diag)
+    -> Result<(), SingularError>
+    where A: ComplexField,
+{
+    if left.cols() > 0 {
+        general_mat_mul(-A::one(), &left, &solved, A::one(), &mut x);
+    }
+    for i in 0..x.rows() {
+        let (top, mut bottom) = x.view_mut().split_at(Axis(0), i);
+        let mut row = bottom.row_mut(0);
+        for j in 0..i {
+            row.scaled_add(-diagonal[[i, j]], &top.row(j));
+        }
+        if diag == Diag::NonUnit {
+            let pivot = diagonal[[i, i]];
+            if pivot == A::zero() {
+                return Err(SingularError);
+            }
+            row.mapv_inplace(|y| y / pivot);
+        }
+    }
+    Ok(())
+}
+
+macro_rules! triangular_type {
+    ($name:ident, $doc:expr, $solve_doc:expr, $subst:ident, $in_triangle:expr) => {
+#[doc = $doc]
+///
+/// The wrapped matrix is checked to be square once, on construction; the
+/// other triangle is never read, so it may hold anything.
+#[derive(Clone, Debug)]
+pub struct $name<M> {
+    matrix: M,
+}
+
+impl<A, S> $name<ArrayBase<S, Ix2>>
+    where A: ComplexField,
+          S: Data<Elem=A>,
+{
+    /// Wrap the square matrix `a`.
+    ///
+    /// **Panics** if `a` is not square.
+    pub fn new(a: ArrayBase<S, Ix2>) -> Self {
+        assert_square(concat!(stringify!($name), "::new"), &a);
+        $name { matrix: a }
+    }
+
+    /// Return the number of rows (and columns) of the matrix.
+    pub fn dim(&self) -> usize {
+        self.matrix.rows()
+    }
+
+    /// Return a view of the wrapped matrix, including the unused triangle.
+    pub fn view(&self) -> ArrayView2<A> {
+        self.matrix.view()
+    }
+
+    /// Return the wrapped matrix.
+    pub fn into_inner(self) -> ArrayBase<S, Ix2> {
+        self.matrix
+    }
+
+    /// Return the matrix as a dense array, with zeros in the other
+    /// triangle.
+    pub fn to_dense(&self) -> Array2<A> {
+        let mut a = self.matrix.to_owned();
+        for ((i, j), x) in a.indexed_iter_mut() {
+            if !$in_triangle(i, j) {
+                *x = A::zero();
+            }
+        }
+        a
+    }
+
+    /// Return the product of the matrix and `x`, reading only the
+    /// triangle.
+    ///
+    /// `x` is either a vector or a matrix; the product has the same shape.
+    ///
+    /// **Panics** if `x` is not one or two dimensional, or if `x` does not
+    /// have `n` rows.
+    pub fn dot<S2, D>(&self, x: &ArrayBase<S2, D>) -> Array<A, D>
+        where S2: Data<Elem=A>,
+              D: Dimension,
+    {
+        let n = self.dim();
+        let x2 = right_hand_side(concat!(stringify!($name), "::dot"), n, x);
+        let mut y = Array2::zeros(x2.dim());
+        for (i, mut row) in y.outer_iter_mut().enumerate() {
+            for j in (0..n).filter(|&j| $in_triangle(i, j)) {
+                row.scaled_add(self.matrix[[i, j]], &x2.row(j));
+            }
+        }
+        y.into_shape(x.raw_dim()).unwrap()
+    }
+
+    #[doc = $solve_doc]
+    ///
+    /// Return an error if the matrix is singular (a diagonal element is
+    /// zero).
+    ///
+    /// **Panics** if `b` is not one or two dimensional, or if `b` does not
+    /// have `n` rows.
+    pub fn solve<S2, D>(&self, b: &ArrayBase<S2, D>, diag: Diag)
+        -> Result<Array<A, D>, SingularError>
+        where S2: Data<Elem=A>,
+              D: Dimension,
+    {
+        let name = concat!(stringify!($name), "::solve");
+        let mut x = right_hand_side(name, self.dim(), b);
+        try!($subst(self.matrix.view(), x.view_mut(), diag));
+        Ok(x.into_shape(b.raw_dim()).unwrap())
+    }
+}
+    }
+}
+
+triangular_type!(Lower,
+                 "A square lower triangular matrix.",
+                 "Solve the linear system `a x = b` by forward substitution, like \
+                  [`subst_fw`](fn.subst_fw.html).",
+                 forward_substitution, |i, j| i >= j);
+triangular_type!(Upper,
+                 "A square upper triangular matrix.",
+                 "Solve the linear system `a x = b` by back substitution, like \
+                  [`subst_bw`](fn.subst_bw.html).",
+                 back_substitution, |i, j| i <= j);
@@ -0,0 +1,98 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+use NdFloat;
+use super::solve::SingularError;
+
+/// Solve the linear system `a x = b` for the tridiagonal `n × n` matrix `a`
+/// with subdiagonal `lower`, diagonal `diag` and superdiagonal `upper`, in
+/// `O(n)` time.
+///
+/// `lower` and `upper` have `n - 1` elements: `lower[i]` is `a[[i + 1, i]]`
+/// and `upper[i]` is `a[[i, i + 1]]`. `b` is either a vector, or a matrix
+/// with a right hand side in each column; the solution `x` has the same
+/// shape as `b`.
+///
+/// This is the Thomas algorithm, Gaussian elimination without pivoting,
+/// which is stable when `a` is diagonally dominant or symmetric positive
+/// definite, as for many discretized differential equations. Return an
+/// error if a pivot is exactly zero.
+///
+/// **Panics** if the diagonals have inconsistent lengths, if `b` is not one
+/// or two dimensional, or if `b` does not have `n` rows.
+///
+/// ```
+/// use ndarray::Array;
+/// use ndarray::linalg::solve_tridiagonal;
+///
+/// // -u'' = 1 on (0, 1) with u(0) = u(1) = 0, by finite differences
+/// let n = 9;
+/// let h = 1. / (n + 1) as f64;
+/// let lower = Array::from_elem(n - 1, -1.);
+/// let diag = Array::from_elem(n, 2.);
+/// let b = Array::from_elem(n, h * h);
+/// let u = solve_tridiagonal(&lower, &diag, &lower, &b).unwrap();
+/// // the exact solution is u(t) = t (1 - t) / 2
+/// let t = Array::linspace(h, 1. - h, n);
+/// assert!(u.all_close(&(&t * &(1. - &t) / 2.), 1e-12));
+/// ```
+pub fn solve_tridiagonal<A, S1, S2, S3, S4, D>(lower: &ArrayBase<S1, Ix1>,
+                                               diag: &ArrayBase<S2, Ix1>,
+                                               upper: &ArrayBase<S3, Ix1>,
+                                               b: &ArrayBase<S4, D>)
+    -> Result<Array<A, D>, SingularError>
+    where A: NdFloat,
+          S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          S3: Data<Elem=A>,
+          S4: Data<Elem=A>,
+          D: Dimension,
+{
+    let n = diag.len();
+    let m = n.saturating_sub(1);
+    assert!(lower.len() == m && upper.len() == m,
+            "ndarray: solve_tridiagonal: the off-diagonals must have {} elements, but have {} and {}",
+            m, lower.len(), upper.len());
+    assert!(b.ndim() == 1 || b.ndim() == 2,
+            "ndarray: solve_tridiagonal: right hand side must be one or two dimensional, \
+             but has shape {:?}", b.shape());
+    assert!(b.shape()[0] == n,
+            "ndarray: solve_tridiagonal: right hand side has shape {:?}, but the matrix has {} rows",
+            b.shape(), n);
+    let k = if b.ndim() == 2 { b.shape()[1] } else { 1 };
+    let mut x = Array::from_iter(b.iter().cloned()).into_shape((n, k)).unwrap();
+
+    // forward elimination; upper_mod holds the superdiagonal of the
+    // eliminated matrix divided by its (unit) diagonal
+    let mut upper_mod = Vec::with_capacity(n);
+    let mut pivot = if n > 0 { diag[0] } else { A::one() };
+    for i in 0..n {
+        if i > 0 {
+            pivot = diag[i] - lower[i - 1] * upper_mod[i - 1];
+        }
+        if pivot == A::zero() {
+            return Err(SingularError);
+        }
+        if i + 1 < n {
+            upper_mod.push(upper[i] / pivot);
+        }
+        let (prev, mut rest) = x.view_mut().split_at(Axis(0), i);
+        let mut row = rest.row_mut(0);
+        if i > 0 {
+            row.scaled_add(-lower[i - 1], &prev.row(i - 1));
+        }
+        row.mapv_inplace(|y| y / pivot);
+    }
+    // back substitution
+    for i in (0..m).rev() {
+        let (mut top, bottom) = x.view_mut().split_at(Axis(0), i + 1);
+        top.row_mut(i).scaled_add(-upper_mod[i], &bottom.row(0));
+    }
+    Ok(x.into_shape(b.raw_dim()).unwrap())
+}
@@ -6,8 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 use libnum::{Zero, One, Float};
+use num_complex::Complex;
 use std::fmt;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Neg};
 use std::ops::{
     AddAssign,
     SubAssign,
@@ -59,3 +60,56 @@ pub trait NdFloat :
 
 impl NdFloat for f32 { }
 impl NdFloat for f64 { }
+
+/// Real or complex floating-point element types: `f32`, `f64`,
+/// `Complex<f32>` and `Complex<f64>`.
+///
+/// Trait `ComplexField` is what the linear algebra routines that support
+/// complex (Hermitian) matrices are generic over. For the real types the
+/// conjugate is the identity, so an algorithm written with conjugate
+/// transposes covers the real (symmetric) case too.
+///
+/// This trait can only be implemented by the four types above.
+pub trait ComplexField :
+    Neg<Output=Self> + PartialEq +
+    fmt::Debug +
+    ScalarOperand + LinalgScalar + Send + Sync
+{
+    /// The real type: `Self` for `f32` and `f64`, `T` for `Complex<T>`.
+    type Real: NdFloat;
+
+    /// Return the complex conjugate.
+    fn conj(self) -> Self;
+
+    /// Return the real part.
+    fn re(self) -> Self::Real;
+
+    /// Return the magnitude (absolute value).
+    fn modulus(self) -> Self::Real;
+
+    /// Return the element with real part `re` and imaginary part zero.
+    fn from_real(re: Self::Real) -> Self;
+}
+
+macro_rules! impl_complex_field {
+    ($real:ty) => {
+        impl ComplexField for $real {
+            type Real = $real;
+            fn conj(self) -> Self { self }
+            fn re(self) -> $real { self }
+            fn modulus(self) -> $real { self.abs() }
+            fn from_real(re: $real) -> Self { re }
+        }
+
+        impl ComplexField for Complex<$real> {
+            type Real = $real;
+            fn conj(self) -> Self { Complex::new(self.re, -self.im) }
+            fn re(self) -> $real { self.re }
+            fn modulus(self) -> $real { self.re.hypot(self.im) }
+            fn from_real(re: $real) -> Self { Complex::new(re, 0.) }
+        }
+    }
+}
+
+impl_complex_field!(f32);
+impl_complex_field!(f64);
@@ -0,0 +1,84 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Elementwise methods for arrays of complex numbers.
+
+use libnum::{Float, Num};
+use num_complex::Complex;
+
+use imp_prelude::*;
+
+/// # Elementwise methods for arrays of complex numbers
+///
+/// Arithmetic operators work for arrays of `Complex<f32>` and
+/// `Complex<f64>` like for any other element type (including arithmetic
+/// with complex scalars).
+///
+/// ```
+/// extern crate num_complex;
+/// extern crate ndarray;
+///
+/// use num_complex::Complex;
+/// use ndarray::arr1;
+///
+/// # fn main() {
+/// let a = arr1(&[Complex::new(3., 4.), Complex::new(0., -1.)]);
+/// assert_eq!(a.re(), arr1(&[3., 0.]));
+/// assert_eq!(a.im(), arr1(&[4., -1.]));
+/// assert_eq!(a.norm(), arr1(&[5., 1.]));
+/// assert_eq!(&a * &a.conj(), arr1(&[Complex::new(25., 0.), Complex::new(1., 0.)]));
+/// # }
+/// ```
+impl<T, S, D> ArrayBase<S, D>
+    where S: Data<Elem=Complex<T>>,
+          D: Dimension,
+{
+    /// Return an array with the real part of each element.
+    pub fn re(&self) -> Array<T, D>
+        where T: Clone,
+    {
+        self.map(|z| z.re.clone())
+    }
+
+    /// Return an array with the imaginary part of each element.
+    pub fn im(&self) -> Array<T, D>
+        where T: Clone,
+    {
+        self.map(|z| z.im.clone())
+    }
+
+    /// Return an array with the complex conjugate of each element.
+    pub fn conj(&self) -> Array<Complex<T>, D>
+        where T: Clone + Num + ::std::ops::Neg<Output=T>,
+    {
+        self.map(|z| z.conj())
+    }
+
+    /// Return an array with the magnitude (absolute value) of each element.
+    pub fn norm(&self) -> Array<T, D>
+        where T: Float,
+    {
+        self.map(|z| z.norm())
+    }
+
+    /// Return an array with the argument (phase angle, in radians, in the
+    /// range `-π..=π`) of each element.
+    pub fn arg(&self) -> Array<T, D>
+        where T: Float,
+    {
+        self.map(|z| z.arg())
+    }
+
+    /// Replace each element with its complex conjugate, in place.
+    pub fn iconj(&mut self)
+        where S: DataMut,
+              T: Clone + Num + ::std::ops::Neg<Output=T>,
+    {
+        self.unordered_foreach_mut(|z| *z = z.conj());
+    }
+}
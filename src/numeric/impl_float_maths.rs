@@ -0,0 +1,195 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Elementwise mathematical functions for arrays of floats.
+
+use libnum::{Float, NumCast};
+
+use imp_prelude::*;
+use Zip;
+
+macro_rules! float_to_int {
+    ($($name:ident, $round:ident, $desc:expr;)+) => {
+        $(
+    /// Round each element
+    #[doc=$desc]
+    /// and convert it to the numeric type `B`, usually an integer.
+    ///
+    /// Return `None` if any rounded element (like `NaN`) can not be
+    /// represented by `B`.
+    pub fn $name<B>(&self) -> Option<Array<B, D>>
+        where B: NumCast,
+    {
+        let mut v = Vec::with_capacity(self.len());
+        for &x in self.iter() {
+            match B::from(x.$round()) {
+                Some(r) => v.push(r),
+                None => return None,
+            }
+        }
+        unsafe {
+            Some(ArrayBase::from_shape_vec_unchecked(self.raw_dim(), v))
+        }
+    }
+        )+
+    };
+}
+
+macro_rules! float_maths {
+    ($($(#[$meta:meta])* fn $name:ident;)+) => {
+        $(
+            $(#[$meta])*
+            pub fn $name(&self) -> Array<A, D> {
+                self.mapv(A::$name)
+            }
+        )+
+    };
+}
+
+/// # Elementwise mathematical functions for arrays of floats
+///
+/// Each method applies the corresponding `Float` method to every element and
+/// returns the result as a new array of the same shape.
+///
+/// ```
+/// use ndarray::arr2;
+///
+/// let a = arr2(&[[0., 1.],
+///                [4., 9.]]);
+/// assert_eq!(a.sqrt(), arr2(&[[0., 1.],
+///                             [2., 3.]]));
+/// assert!(a.exp().ln().all_close(&a, 1e-12));
+///
+/// // grid indices of coordinates, with a cell size of 2.5
+/// let x = arr2(&[[0.4, 2.6],
+///                [7.5, 9.9]]);
+/// assert_eq!((x / 2.5).floor_as::<usize>(), Some(arr2(&[[0, 1],
+///                                                        [3, 3]])));
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+    where A: Float,
+          S: Data<Elem=A>,
+          D: Dimension,
+{
+    float_maths! {
+        /// Largest integer less than or equal to each element.
+        fn floor;
+        /// Smallest integer greater than or equal to each element.
+        fn ceil;
+        /// Nearest integer to each element, rounding half-way cases away
+        /// from zero.
+        fn round;
+        /// Integer part of each element.
+        fn trunc;
+        /// Fractional part of each element.
+        fn fract;
+        /// Reciprocal (`1/x`) of each element.
+        fn recip;
+        /// Square root of each element.
+        fn sqrt;
+        /// Cube root of each element.
+        fn cbrt;
+        /// `e^x` of each element.
+        fn exp;
+        /// `2^x` of each element.
+        fn exp2;
+        /// `e^x - 1` of each element, accurate for small `x`.
+        fn exp_m1;
+        /// Natural logarithm of each element.
+        fn ln;
+        /// Base 2 logarithm of each element.
+        fn log2;
+        /// Base 10 logarithm of each element.
+        fn log10;
+        /// `ln(1 + x)` of each element, accurate for small `x`.
+        fn ln_1p;
+        /// Sine of each element (in radians).
+        fn sin;
+        /// Cosine of each element (in radians).
+        fn cos;
+        /// Tangent of each element (in radians).
+        fn tan;
+        /// Arcsine of each element.
+        fn asin;
+        /// Arccosine of each element.
+        fn acos;
+        /// Arctangent of each element.
+        fn atan;
+        /// Hyperbolic sine of each element.
+        fn sinh;
+        /// Hyperbolic cosine of each element.
+        fn cosh;
+        /// Hyperbolic tangent of each element.
+        fn tanh;
+        /// Inverse hyperbolic sine of each element.
+        fn asinh;
+        /// Inverse hyperbolic cosine of each element.
+        fn acosh;
+        /// Inverse hyperbolic tangent of each element.
+        fn atanh;
+        /// Convert each element from radians to degrees.
+        fn to_degrees;
+        /// Convert each element from degrees to radians.
+        fn to_radians;
+    }
+
+    float_to_int! {
+        floor_as, floor, "down (see [`.floor()`](#method.floor))";
+        ceil_as, ceil, "up (see [`.ceil()`](#method.ceil))";
+        round_as, round, "to the nearest integer (see [`.round()`](#method.round))";
+        trunc_as, trunc, "towards zero (see [`.trunc()`](#method.trunc))";
+    }
+
+    /// Raise each element to the integer power `n`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1., 2., 3.]);
+    /// assert_eq!(a.powi(2), arr1(&[1., 4., 9.]));
+    /// ```
+    pub fn powi(&self, n: i32) -> Array<A, D> {
+        self.mapv(|x| x.powi(n))
+    }
+
+    /// Raise each element to the floating point power `n`.
+    pub fn powf(&self, n: A) -> Array<A, D> {
+        self.mapv(|x| x.powf(n))
+    }
+
+    /// Logarithm of each element with respect to `base`.
+    pub fn log(&self, base: A) -> Array<A, D> {
+        self.mapv(|x| x.log(base))
+    }
+
+    /// Raise each element to the power of the corresponding element of
+    /// `exponents`.
+    ///
+    /// If their shapes disagree, `exponents` is broadcast to the shape of
+    /// `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.powf_array(&arr1(&[2., 0.5])), arr2(&[[1., 2f64.sqrt()],
+    ///                                                    [9., 2.]]));
+    /// ```
+    pub fn powf_array<S2, E>(&self, exponents: &ArrayBase<S2, E>) -> Array<A, D>
+        where S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let exponents = exponents.broadcast_unwrap(self.raw_dim());
+        let mut res = self.to_owned();
+        Zip::from(&mut res).and(&exponents).apply(|x, &n| *x = x.powf(n));
+        res
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Elementwise overflow-aware arithmetic for integer arrays.
+
+use libnum::{
+    CheckedAdd,
+    CheckedSub,
+    CheckedMul,
+    Saturating,
+    WrappingAdd,
+    WrappingSub,
+    WrappingMul,
+};
+
+use imp_prelude::*;
+use Zip;
+
+macro_rules! checked_ops {
+    ($($mth:ident, $trt:ident, $desc:expr;)+) => {
+        $(
+    /// Perform elementwise checked
+    #[doc=$desc]
+    /// of `self` and `rhs`, and return the result as a new array, or `None`
+    /// if any element overflows.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn $mth<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Option<Array<A, D>>
+        where A: $trt,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        let mut v = Vec::with_capacity(self.len());
+        for (x, y) in self.iter().zip(rhs.iter()) {
+            match x.$mth(y) {
+                Some(r) => v.push(r),
+                None => return None,
+            }
+        }
+        unsafe {
+            Some(ArrayBase::from_shape_vec_unchecked(self.raw_dim(), v))
+        }
+    }
+        )+
+    };
+}
+
+macro_rules! total_ops {
+    ($($mth:ident, $trt:ident, $desc:expr, $doc_mode:expr, $call:expr;)+) => {
+        $(
+    /// Perform elementwise
+    #[doc=$doc_mode]
+    #[doc=$desc]
+    /// of `self` and `rhs`, and return the result as a new array.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn $mth<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+        where A: Clone + $trt,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        let mut res = self.to_owned();
+        Zip::from(&mut res).and(&rhs).apply(|x, y| *x = $call(&*x, y));
+        res
+    }
+        )+
+    };
+}
+
+/// # Overflow-aware Arithmetic for Integer Arrays
+///
+/// ```
+/// use ndarray::arr1;
+///
+/// let a = arr1(&[100u8, 200]);
+/// let b = arr1(&[100u8, 100]);
+/// assert_eq!(a.checked_add(&b), None);
+/// assert_eq!(a.saturating_add(&b), arr1(&[200, 255]));
+/// assert_eq!(a.wrapping_add(&b), arr1(&[200, 44]));
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    checked_ops! {
+        checked_add, CheckedAdd, "addition";
+        checked_sub, CheckedSub, "subtraction";
+        checked_mul, CheckedMul, "multiplication";
+    }
+
+    total_ops! {
+        saturating_add, Saturating, "addition", "saturating",
+            |x: &A, y: &A| x.clone().saturating_add(y.clone());
+        saturating_sub, Saturating, "subtraction", "saturating",
+            |x: &A, y: &A| x.clone().saturating_sub(y.clone());
+        wrapping_add, WrappingAdd, "addition", "wrapping",
+            |x: &A, y: &A| x.wrapping_add(y);
+        wrapping_sub, WrappingSub, "subtraction", "wrapping",
+            |x: &A, y: &A| x.wrapping_sub(y);
+        wrapping_mul, WrappingMul, "multiplication", "wrapping",
+            |x: &A, y: &A| x.wrapping_mul(y);
+    }
+}
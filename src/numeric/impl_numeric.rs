@@ -6,8 +6,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::ops::Add;
-use libnum::{self, Zero, Float};
+use std::cmp;
+use std::ops::{Add, Mul, Sub};
+use libnum::{self, Zero, Float, Signed, Num, NumCast, ToPrimitive};
 use itertools::free::enumerate;
 
 use imp_prelude::*;
@@ -15,6 +16,7 @@ use numeric_util;
 
 use {
     LinalgScalar,
+    Zip,
 };
 
 /// Numerical methods for arrays.
@@ -24,6 +26,9 @@ impl<A, S, D> ArrayBase<S, D>
 {
     /// Return the sum of all elements in the array.
     ///
+    /// Contiguous elements are summed pairwise in blocks, which is both fast
+    /// and accurate for floating point numbers.
+    ///
     /// ```
     /// use ndarray::arr2;
     ///
@@ -35,12 +40,12 @@ impl<A, S, D> ArrayBase<S, D>
         where A: Clone + Add<Output=A> + libnum::Zero,
     {
         if let Some(slc) = self.as_slice_memory_order() {
-            return numeric_util::unrolled_sum(slc);
+            return numeric_util::pairwise_sum(slc);
         }
         let mut sum = A::zero();
         for row in self.inner_iter() {
             if let Some(slc) = row.as_slice() {
-                sum = sum + numeric_util::unrolled_sum(slc);
+                sum = sum + numeric_util::pairwise_sum(slc);
             } else {
                 sum = sum + row.iter().fold(A::zero(), |acc, elt| acc + elt.clone());
             }
@@ -48,6 +53,318 @@ impl<A, S, D> ArrayBase<S, D>
         sum
     }
 
+    /// Return the sum of all elements in the array, accumulated in the
+    /// type `B`.
+    ///
+    /// Each element is converted to `B` before it is added, so that for
+    /// example the sum of an `u8` image can be accumulated in an `u64`
+    /// without overflow, and without first mapping to a copy.
+    ///
+    /// **Panics** if an element can not be represented in `B`.
+    ///
+    /// ```
+    /// use ndarray::Array;
+    ///
+    /// let a = Array::<u8, _>::from_elem((16, 16), 255);
+    /// assert_eq!(a.scalar_sum_as::<u64>(), 255 * 256);
+    /// ```
+    pub fn scalar_sum_as<B>(&self) -> B
+        where A: Clone + ToPrimitive,
+              B: Num + NumCast,
+    {
+        self.fold(B::zero(), |acc, x| acc + cast_elem(x))
+    }
+
+    /// Return the product of all elements in the array.
+    ///
+    /// The product of an empty array is one.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.product(), 24.);
+    /// ```
+    pub fn product(&self) -> A
+        where A: Clone + Mul<Output=A> + libnum::One,
+    {
+        if let Some(slc) = self.as_slice_memory_order() {
+            return numeric_util::unrolled_prod(slc);
+        }
+        let mut prod = A::one();
+        for row in self.inner_iter() {
+            if let Some(slc) = row.as_slice() {
+                prod = prod * numeric_util::unrolled_prod(slc);
+            } else {
+                prod = prod * row.iter().fold(A::one(), |acc, elt| acc * elt.clone());
+            }
+        }
+        prod
+    }
+
+    /// Return the sums along the diagonals of the 2-D subarrays spanned by
+    /// `axis1` and `axis2`; the result has the remaining axes.
+    ///
+    /// The diagonal is the elements at index `i` along `axis1` and
+    /// `i + offset` along `axis2`, so a positive `offset` selects a diagonal
+    /// above the main diagonal and a negative one below. The diagonals are
+    /// strided views, and each element is visited once.
+    ///
+    /// **Panics** if the axes are out of bounds or equal.
+    ///
+    /// ```
+    /// use ndarray::{Array, Axis, arr1};
+    ///
+    /// let a = Array::from_iter(0..12).into_shape((2, 2, 3)).unwrap();
+    /// assert_eq!(a.trace_axes(0, Axis(0), Axis(1)), arr1(&[9, 11, 13]).into_dyn());
+    /// assert_eq!(a.trace_axes(1, Axis(1), Axis(2)), arr1(&[1 + 5, 7 + 11]).into_dyn());
+    /// ```
+    pub fn trace_axes(&self, offset: isize, axis1: Axis, axis2: Axis) -> Array<A, IxDyn>
+        where A: Clone + Add<Output=A> + Zero,
+    {
+        let (a1, a2) = (axis1.axis(), axis2.axis());
+        assert!(a1 != a2 && a1 < self.ndim() && a2 < self.ndim(),
+                "ndarray: trace_axes: the axes must be distinct and in bounds, but are {} and {}",
+                a1, a2);
+        let diag = offset_diagonal(self.view().into_dyn(), offset, axis1, axis2);
+        let mut shape = self.shape().to_vec();
+        shape.remove(cmp::max(a1, a2));
+        shape.remove(cmp::min(a1, a2));
+        let mut sum = Array::<A, _>::zeros(shape);
+        for i in 0..cmp::min(diag.len_of(axis1), diag.len_of(axis2)) {
+            let elems = diag.subview(Axis(cmp::max(a1, a2)), i)
+                            .into_subview(Axis(cmp::min(a1, a2)), i);
+            Zip::from(&mut sum).and(&elems).apply(|s, x| *s = s.clone() + x.clone());
+        }
+        sum
+    }
+
+    /// Return a reference to the smallest element of the array, or `None`
+    /// if the array is empty.
+    ///
+    /// If an element doesn't compare with itself (like `NaN`), the first
+    /// such element is returned.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use std::f64;
+    ///
+    /// let a = arr2(&[[1., 5.],
+    ///                [-3., 4.]]);
+    /// assert_eq!(a.min(), Some(&-3.));
+    /// assert_eq!(a.max(), Some(&5.));
+    /// assert!(arr2(&[[1., f64::NAN]]).max().unwrap().is_nan());
+    /// ```
+    pub fn min(&self) -> Option<&A>
+        where A: PartialOrd,
+    {
+        extremum(self.iter().map(|x| ((), x)), |x, y| x < y).map(|(_, x)| x)
+    }
+
+    /// Return a reference to the largest element of the array, or `None`
+    /// if the array is empty.
+    ///
+    /// If an element doesn't compare with itself (like `NaN`), the first
+    /// such element is returned.
+    pub fn max(&self) -> Option<&A>
+        where A: PartialOrd,
+    {
+        extremum(self.iter().map(|x| ((), x)), |x, y| x > y).map(|(_, x)| x)
+    }
+
+    /// Return the smallest elements along `axis`.
+    ///
+    /// Elements that don't compare with themselves (like `NaN`) are
+    /// propagated, like for [`.min()`](#method.min).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5],
+    ///                [-3, 4]]);
+    /// assert_eq!(a.min_axis(Axis(0)), arr1(&[-3, 4]));
+    /// assert_eq!(a.max_axis(Axis(1)), arr1(&[5, 4]));
+    /// ```
+    pub fn min_axis(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Clone + PartialOrd,
+              D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "ndarray: min_axis: axis of length zero");
+        self.map_axis(axis, |lane| {
+            extremum(lane.into_iter().map(|x| ((), x)), |x, y| x < y).unwrap().1.clone()
+        })
+    }
+
+    /// Return the largest elements along `axis`.
+    ///
+    /// Elements that don't compare with themselves (like `NaN`) are
+    /// propagated, like for [`.max()`](#method.max).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn max_axis(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Clone + PartialOrd,
+              D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "ndarray: max_axis: axis of length zero");
+        self.map_axis(axis, |lane| {
+            extremum(lane.into_iter().map(|x| ((), x)), |x, y| x > y).unwrap().1.clone()
+        })
+    }
+
+    /// Return the range (maximum minus minimum) of the elements along
+    /// `axis`, finding both extremes in a single pass over each lane.
+    ///
+    /// Elements that don't compare with themselves (like `NaN`) are
+    /// propagated.
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 2],
+    ///                [-3, 4, 4]]);
+    /// assert_eq!(a.ptp(Axis(0)), arr1(&[4, 1, 2]));
+    /// assert_eq!(a.ptp(Axis(1)), arr1(&[4, 7]));
+    /// ```
+    pub fn ptp(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Clone + PartialOrd + Sub<Output=A>,
+              D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "ndarray: ptp: axis of length zero");
+        self.map_axis(axis, |lane| {
+            let (mut min, mut max) = (&lane[0], &lane[0]);
+            for x in &lane {
+                if x.partial_cmp(x).is_none() {
+                    // x - x keeps the incomparable value, e.g. NaN
+                    return x.clone() - x.clone();
+                }
+                if *x < *min {
+                    min = x;
+                } else if *x > *max {
+                    max = x;
+                }
+            }
+            max.clone() - min.clone()
+        })
+    }
+
+    /// Return the index of the smallest element of the array, or `None` if
+    /// the array is empty.
+    ///
+    /// The first index is returned if there are several smallest elements.
+    /// If an element doesn't compare with itself (like `NaN`), the index of
+    /// the first such element is returned.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 5.],
+    ///                [-3., 5.]]);
+    /// assert_eq!(a.argmin(), Some((1, 0)));
+    /// assert_eq!(a.argmax(), Some((0, 1)));
+    /// ```
+    pub fn argmin(&self) -> Option<D::Pattern>
+        where A: PartialOrd,
+    {
+        extremum(self.indexed_iter(), |x, y| x < y).map(|(i, _)| i)
+    }
+
+    /// Return the index of the largest element of the array, or `None` if
+    /// the array is empty.
+    ///
+    /// The first index is returned if there are several largest elements.
+    /// If an element doesn't compare with itself (like `NaN`), the index of
+    /// the first such element is returned.
+    pub fn argmax(&self) -> Option<D::Pattern>
+        where A: PartialOrd,
+    {
+        extremum(self.indexed_iter(), |x, y| x > y).map(|(i, _)| i)
+    }
+
+    /// Return the indices along `axis` of the smallest elements along
+    /// `axis`.
+    ///
+    /// Ties and elements that don't compare with themselves are treated like
+    /// for [`.argmin()`](#method.argmin).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 2],
+    ///                [-3, 4, 7]]);
+    /// assert_eq!(a.argmin_axis(Axis(0)), arr1(&[1, 1, 0]));
+    /// assert_eq!(a.argmax_axis(Axis(1)), arr1(&[1, 2]));
+    /// ```
+    pub fn argmin_axis(&self, axis: Axis) -> Array<Ix, <D as RemoveAxis>::Smaller>
+        where A: PartialOrd,
+              D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "ndarray: argmin_axis: axis of length zero");
+        self.map_axis(axis, |lane| {
+            extremum(lane.into_iter().enumerate(), |x, y| x < y).unwrap().0
+        })
+    }
+
+    /// Return the indices along `axis` of the largest elements along
+    /// `axis`.
+    ///
+    /// Ties and elements that don't compare with themselves are treated like
+    /// for [`.argmax()`](#method.argmax).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn argmax_axis(&self, axis: Axis) -> Array<Ix, <D as RemoveAxis>::Smaller>
+        where A: PartialOrd,
+              D: RemoveAxis,
+    {
+        assert!(self.len_of(axis) > 0, "ndarray: argmax_axis: axis of length zero");
+        self.map_axis(axis, |lane| {
+            extremum(lane.into_iter().enumerate(), |x, y| x > y).unwrap().0
+        })
+    }
+
+    /// Return the number of elements that are not zero.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[0., 1.5],
+    ///                [-2., 0.]]);
+    /// assert_eq!(a.count_nonzero(), 2);
+    /// ```
+    pub fn count_nonzero(&self) -> usize
+        where A: Zero,
+    {
+        self.fold(0, |n, x| if x.is_zero() { n } else { n + 1 })
+    }
+
+    /// Return the indices of the elements that are not zero, as one index
+    /// array per axis.
+    ///
+    /// See [`.indices_where()`](#method.indices_where) for details.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[0., 1.5],
+    ///                [-2., 0.]]);
+    /// let index = a.nonzero();
+    /// assert_eq!(index, vec![arr1(&[0, 1]), arr1(&[1, 0])]);
+    /// for (&i, &j) in index[0].iter().zip(&index[1]) {
+    ///     assert!(a[[i, j]] != 0.);
+    /// }
+    /// ```
+    pub fn nonzero(&self) -> Vec<Array1<Ix>>
+        where A: Zero,
+    {
+        self.indices_where(|x| !x.is_zero())
+    }
+
     /// Return sum along `axis`.
     ///
     /// ```
@@ -86,6 +403,176 @@ impl<A, S, D> ArrayBase<S, D>
         res
     }
 
+    /// Return sum along `axis`, accumulated in the type `B`.
+    ///
+    /// See [`.scalar_sum_as()`](#method.scalar_sum_as).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if an element can not be
+    /// represented in `B`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[200u8, 100],
+    ///                [100, 100]]);
+    /// assert_eq!(a.sum_as::<u32>(Axis(0)), aview1(&[300, 200]));
+    /// assert_eq!(a.mean_as::<f64>(Axis(1)), aview1(&[150., 100.]));
+    /// ```
+    pub fn sum_as<B>(&self, axis: Axis) -> Array<B, <D as RemoveAxis>::Smaller>
+        where A: Clone + ToPrimitive,
+              B: Clone + Num + NumCast,
+              D: RemoveAxis,
+    {
+        self.fold_axis(axis, B::zero(), |acc, x| acc.clone() + cast_elem(x))
+    }
+
+    /// Return product along `axis`.
+    ///
+    /// The product along an axis of length zero is one.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.product_axis(Axis(0)), aview1(&[3., 8.]));
+    /// assert_eq!(a.product_axis(Axis(1)), aview1(&[2., 12.]));
+    /// ```
+    pub fn product_axis(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Clone + libnum::One + Mul<Output=A>,
+              D: RemoveAxis,
+    {
+        let mut res = Array::from_elem(self.raw_dim().remove_axis(axis), A::one());
+        for subview in self.axis_iter(axis) {
+            res = res * &subview;
+        }
+        res
+    }
+
+    /// Return the cumulative sum of the elements along `axis`.
+    ///
+    /// The result has the same shape as `self`; its element at index `i`
+    /// along `axis` is the sum of the elements at indices `0..=i`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.cumsum(Axis(0)), arr2(&[[1, 2, 3],
+    ///                                      [5, 7, 9]]));
+    /// assert_eq!(a.cumsum(Axis(1)), arr2(&[[1, 3, 6],
+    ///                                      [4, 9, 15]]));
+    /// ```
+    pub fn cumsum(&self, axis: Axis) -> Array<A, D>
+        where A: Clone + Add<Output=A>,
+    {
+        let mut res = self.to_owned();
+        res.cumsum_inplace(axis);
+        res
+    }
+
+    /// Replace the elements with their cumulative sum along `axis`, in
+    /// place.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn cumsum_inplace(&mut self, axis: Axis)
+        where A: Clone + Add<Output=A>,
+              S: DataMut,
+    {
+        self.accumulate_axis_inplace(axis, |prev, x| *x = prev.clone() + x.clone());
+    }
+
+    /// Return the cumulative product of the elements along `axis`.
+    ///
+    /// The result has the same shape as `self`; its element at index `i`
+    /// along `axis` is the product of the elements at indices `0..=i`.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Axis};
+    ///
+    /// let a = arr1(&[1, 2, 3, 4]);
+    /// assert_eq!(a.cumprod(Axis(0)), arr1(&[1, 2, 6, 24]));
+    /// ```
+    pub fn cumprod(&self, axis: Axis) -> Array<A, D>
+        where A: Clone + Mul<Output=A>,
+    {
+        let mut res = self.to_owned();
+        res.cumprod_inplace(axis);
+        res
+    }
+
+    /// Replace the elements with their cumulative product along `axis`, in
+    /// place.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn cumprod_inplace(&mut self, axis: Axis)
+        where A: Clone + Mul<Output=A>,
+              S: DataMut,
+    {
+        self.accumulate_axis_inplace(axis, |prev, x| *x = prev.clone() * x.clone());
+    }
+
+    /// Return the `n`th order discrete differences along `axis`.
+    ///
+    /// The first order differences are `a[i + 1] - a[i]` along `axis`, and
+    /// higher orders are computed by repeating this. The result has the
+    /// shape of `self`, except that `axis` is shorter by `n` (down to zero).
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 4, 7],
+    ///                [0, 5, 0, 5]]);
+    /// assert_eq!(a.diff(1, Axis(1)), arr2(&[[1, 2, 3],
+    ///                                       [5, -5, 5]]));
+    /// assert_eq!(a.diff(2, Axis(1)), arr2(&[[1, 1],
+    ///                                       [-10, 10]]));
+    /// assert_eq!(a.diff(1, Axis(0)), arr2(&[[-1, 3, -4, -2]]));
+    /// assert_eq!(arr1(&[1., 2.]).diff(3, Axis(0)).len(), 0);
+    /// ```
+    pub fn diff(&self, n: usize, axis: Axis) -> Array<A, D>
+        where A: Clone + Sub<Output=A>,
+    {
+        let mut res = self.to_owned();
+        for _ in 0..n {
+            let len = res.len_of(axis);
+            if len == 0 {
+                break;
+            }
+            res = {
+                let hi = res.view().split_at(axis, 1).1;
+                let lo = res.view().split_at(axis, len - 1).0;
+                &hi - &lo
+            };
+        }
+        res
+    }
+
+    /// Update each subview along `axis`, except the first, by calling `f`
+    /// on its elements and the corresponding (already updated) elements of
+    /// the previous subview.
+    fn accumulate_axis_inplace<F>(&mut self, axis: Axis, mut f: F)
+        where F: FnMut(&A, &mut A),
+              S: DataMut,
+    {
+        for i in 1..self.len_of(axis) {
+            // views of length one along `axis`, to keep the dimension type
+            let (head, tail) = self.view_mut().split_at(axis, i);
+            let prev = head.split_at(axis, i - 1).1;
+            let cur = tail.split_at(axis, 1).0;
+            Zip::from(cur).and(&prev).apply(|x, p| f(p, x));
+        }
+    }
+
     /// Return mean along `axis`.
     ///
     /// **Panics** if `axis` is out of bounds.
@@ -113,6 +600,447 @@ impl<A, S, D> ArrayBase<S, D>
         sum / &aview0(&cnt)
     }
 
+    /// Return mean along `axis`, accumulated and computed in the type `B`.
+    ///
+    /// See [`.sum_as()`](#method.sum_as).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if an element or the
+    /// length of `axis` can not be represented in `B`.
+    pub fn mean_as<B>(&self, axis: Axis) -> Array<B, <D as RemoveAxis>::Smaller>
+        where A: Clone + ToPrimitive,
+              B: Clone + Num + NumCast,
+              D: RemoveAxis,
+    {
+        let n = cast_elem::<_, B>(&self.len_of(axis));
+        self.sum_as::<B>(axis).mapv_into(|x| x / n.clone())
+    }
+
+    /// Return the weighted mean along `axis`.
+    ///
+    /// Each subview along `axis` is weighted by the corresponding element of
+    /// `weights`, and the weighted sum is divided by the sum of the weights.
+    ///
+    /// **Panics** if `axis` is out of bounds, if the length of `weights` is
+    /// not the length of `axis`, or if the weights sum to zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// let w = arr1(&[3., 1.]);
+    /// assert_eq!(a.weighted_mean(&w, Axis(0)), aview1(&[1.5, 2.5]));
+    /// assert_eq!(a.weighted_mean(&w, Axis(1)), aview1(&[1.25, 3.25]));
+    /// ```
+    pub fn weighted_mean<S2>(&self, weights: &ArrayBase<S2, Ix1>, axis: Axis)
+        -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              S2: Data<Elem=A>,
+              D: RemoveAxis,
+    {
+        assert_eq!(weights.len(), self.len_of(axis),
+                   "ndarray: weighted_mean: the length of the weights must be the length of the axis");
+        let total = weights.fold(A::zero(), |acc, &w| acc + w);
+        assert!(total != A::zero(), "ndarray: weighted_mean: the weights sum to zero");
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        for (subview, &w) in self.axis_iter(axis).zip(weights) {
+            let w = w / total;
+            res.zip_mut_with(&subview, |acc, &x| *acc = *acc + w * x);
+        }
+        res
+    }
+
+    /// Integrate along `axis` with the trapezoidal rule, for samples spaced
+    /// `dx` apart.
+    ///
+    /// An axis with fewer than two samples integrates to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let y = arr2(&[[0., 1., 2.],
+    ///                [1., 1., 1.]]);
+    /// assert_eq!(y.trapz(0.5, Axis(1)), aview1(&[1., 1.]));
+    /// assert_eq!(y.trapz(1., Axis(0)), aview1(&[0.5, 1., 1.5]));
+    /// ```
+    pub fn trapz(&self, dx: A, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        let half = dx / (A::one() + A::one());
+        self.weighted_sum_axis(axis, |i| if i == 0 || i == n - 1 { half } else { dx })
+    }
+
+    /// Integrate along `axis` with the trapezoidal rule, for samples at the
+    /// coordinates `x`.
+    ///
+    /// The coordinates don't need to be evenly spaced or increasing. An axis
+    /// with fewer than two samples integrates to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if the length of `x` is not
+    /// the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{aview0, arr1, Axis};
+    ///
+    /// let x = arr1(&[0., 1., 3.]);
+    /// let y = x.mapv(|x| 2. * x);
+    /// assert_eq!(y.trapz_xy(&x, Axis(0)), aview0(&9.));
+    /// ```
+    pub fn trapz_xy<S2>(&self, x: &ArrayBase<S2, Ix1>, axis: Axis)
+        -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              S2: Data<Elem=A>,
+              D: RemoveAxis,
+    {
+        let n = self.len_of(axis);
+        assert_eq!(x.len(), n,
+                   "ndarray: trapz_xy: the length of x must be the length of the axis");
+        let two = A::one() + A::one();
+        // each sample is weighted by half the width of its neighbouring intervals
+        self.weighted_sum_axis(axis, |i| {
+            let lo = if i == 0 { x[0] } else { x[i - 1] };
+            let hi = if i == n - 1 { x[n - 1] } else { x[i + 1] };
+            (hi - lo) / two
+        })
+    }
+
+    /// Return the sum of the subviews along `axis`, each multiplied by
+    /// `weight(i)` for its index `i`; zero if `axis` has fewer than two
+    /// elements.
+    fn weighted_sum_axis<F>(&self, axis: Axis, mut weight: F)
+        -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+              F: FnMut(usize) -> A,
+    {
+        let mut res = Array::zeros(self.raw_dim().remove_axis(axis));
+        if self.len_of(axis) < 2 {
+            return res;
+        }
+        for (i, subview) in self.axis_iter(axis).enumerate() {
+            let w = weight(i);
+            res.zip_mut_with(&subview, |acc, &y| *acc = *acc + w * y);
+        }
+        res
+    }
+
+    /// Return variance along `axis`.
+    ///
+    /// The variance is computed as the sum of squared deviations from the
+    /// mean, divided by `n - ddof`, where `n` is the length of `axis`. Use
+    /// `ddof = 0.` for the population variance and `ddof = 1.` for the
+    /// unbiased sample variance.
+    ///
+    /// The mean and the sum of squared deviations are updated in a single,
+    /// numerically stable, pass over the subviews along `axis` (Welford's
+    /// algorithm).
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ddof` is negative or
+    /// not less than the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.]]);
+    /// assert_eq!(a.var(Axis(0), 1.), aview1(&[4., 4.]));
+    /// assert_eq!(a.var(Axis(1), 0.), aview1(&[0.25, 0.25, 0.25]));
+    /// ```
+    pub fn var(&self, axis: Axis, ddof: A) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        let n = A::from(self.len_of(axis)).unwrap();
+        assert!(ddof >= A::zero() && ddof < n,
+                "ndarray: var: ddof must be non-negative and less than the length of the axis");
+        let dim = self.raw_dim().remove_axis(axis);
+        let mut mean = Array::<A, _>::zeros(dim.clone());
+        let mut sum_sq = Array::<A, _>::zeros(dim);
+        let mut count = A::zero();
+        for subview in self.axis_iter(axis) {
+            count = count + A::one();
+            Zip::from(&mut mean).and(&mut sum_sq).and(&subview).apply(|mean, sum_sq, &x| {
+                let delta = x - *mean;
+                *mean = *mean + delta / count;
+                *sum_sq = *sum_sq + delta * (x - *mean);
+            });
+        }
+        let dof = n - ddof;
+        sum_sq.mapv_into(|x| x / dof)
+    }
+
+    /// Return standard deviation along `axis`.
+    ///
+    /// This is the square root of [`.var(axis, ddof)`](#method.var); see
+    /// there for the meaning of `ddof`.
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `ddof` is negative or
+    /// not less than the length of `axis`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.],
+    ///                [5., 6.]]);
+    /// assert_eq!(a.std(Axis(0), 1.), aview1(&[2., 2.]));
+    /// ```
+    pub fn std(&self, axis: Axis, ddof: A) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.var(axis, ddof).mapv_into(Float::sqrt)
+    }
+
+    /// Return the logarithm of the sum of the exponentials of the elements
+    /// along `axis`, `ln(Σ exp(x))`.
+    ///
+    /// The exponentials are computed relative to the running maximum of each
+    /// lane, in a single pass over the subviews along `axis`, so that they
+    /// do not overflow. An empty lane gives negative infinity.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1000., 1000.],
+    ///                [-1000., 0.]]);
+    /// let lse = a.logsumexp(Axis(0));
+    /// assert_eq!(lse[0], 1000.);
+    /// assert!((lse[1] - (1000. + (1. + (-1000f64).exp()).ln())).abs() < 1e-12);
+    ///
+    /// let lse = a.logsumexp(Axis(1));
+    /// assert!((lse[0] - (1000. + 2f64.ln())).abs() < 1e-12);
+    /// ```
+    pub fn logsumexp(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        let dim = self.raw_dim().remove_axis(axis);
+        let mut max = Array::from_elem(dim.clone(), A::neg_infinity());
+        let mut sum = Array::<A, _>::zeros(dim);
+        for subview in self.axis_iter(axis) {
+            Zip::from(&mut max).and(&mut sum).and(&subview).apply(|max, sum, &x| {
+                if x == *max {
+                    // also covers infinities, where x - max is NaN
+                    *sum = *sum + A::one();
+                } else if x > *max {
+                    *sum = *sum * (*max - x).exp() + A::one();
+                    *max = x;
+                } else {
+                    *sum = *sum + (x - *max).exp();
+                }
+            });
+        }
+        Zip::from(&mut max).and(&sum).apply(|max, &sum| *max = *max + sum.ln());
+        max
+    }
+
+    /// Return the `q`th percentile along `axis`, for `q` in `0..=100`.
+    ///
+    /// When the percentile falls between two elements, it is linearly
+    /// interpolated between them. Each lane along `axis` is copied into
+    /// a scratch buffer once, where the needed elements are found by
+    /// selection (quickselect) instead of sorting. Lanes with `NaN` elements
+    /// have a `NaN` percentile.
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero, or if `q`
+    /// is not in `0..=100`.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 8., 3., 6.],
+    ///                [4., 4., 0., 2.]]);
+    /// assert_eq!(a.percentile(50., Axis(1)), aview1(&[4.5, 3.]));
+    /// assert_eq!(a.percentile(100., Axis(1)), aview1(&[8., 4.]));
+    /// assert_eq!(a.percentile(25., Axis(0)), aview1(&[1.75, 5., 0.75, 3.]));
+    /// ```
+    pub fn percentile(&self, q: A, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        let hundred = A::from(100).unwrap();
+        assert!(q >= A::zero() && q <= hundred,
+                "ndarray: percentile: q must be in 0..=100");
+        let n = self.len_of(axis);
+        assert!(n > 0, "ndarray: percentile: axis of length zero");
+        // fractional rank of the percentile
+        let rank = q / hundred * A::from(n - 1).unwrap();
+        let lo = rank.floor().to_usize().unwrap();
+        let frac = rank - rank.floor();
+        let mut buf = Vec::with_capacity(n);
+        self.map_axis(axis, |lane| {
+            buf.clear();
+            for &x in lane {
+                if x.is_nan() {
+                    return x;
+                }
+                buf.push(x);
+            }
+            let a = select_nth(&mut buf, lo);
+            if lo + 1 < n && frac > A::zero() {
+                // after selection, the elements after `lo` are not smaller
+                let b = buf[lo + 1..].iter().cloned().fold(A::infinity(), A::min);
+                a + (b - a) * frac
+            } else {
+                a
+            }
+        })
+    }
+
+    /// Return the median along `axis`.
+    ///
+    /// The median of an even number of elements is the mean of the two
+    /// middle elements. This is the same as
+    /// [`.percentile(50., axis)`](#method.percentile).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 8., 3.],
+    ///                [4., 4., 0.]]);
+    /// assert_eq!(a.median(Axis(1)), aview1(&[3., 4.]));
+    /// assert_eq!(a.median(Axis(0)), aview1(&[2.5, 6., 1.5]));
+    /// ```
+    pub fn median(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.percentile(A::from(50).unwrap(), axis)
+    }
+
+    /// Return the number of elements along `axis` that are not NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn nancount(&self, axis: Axis) -> Array<usize, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.fold_axis(axis, 0, |&n, x| if x.is_nan() { n } else { n + 1 })
+    }
+
+    /// Return sum along `axis`, skipping NaN elements.
+    ///
+    /// A lane with only NaN elements sums to zero.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    /// use std::f64::NAN;
+    ///
+    /// let a = arr2(&[[1., NAN],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.nansum(Axis(0)), aview1(&[4., 4.]));
+    /// assert_eq!(a.nancount(Axis(0)), aview1(&[2, 1]));
+    /// assert_eq!(a.nanmean(Axis(0)), aview1(&[2., 4.]));
+    /// assert_eq!(a.nanmax(Axis(1)), aview1(&[1., 4.]));
+    /// ```
+    pub fn nansum(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::zero(), |&s, &x| if x.is_nan() { s } else { s + x })
+    }
+
+    /// Return mean along `axis`, skipping NaN elements.
+    ///
+    /// The mean of a lane with only NaN elements is NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn nanmean(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        let mut sum = self.nansum(axis);
+        let count = self.nancount(axis);
+        Zip::from(&mut sum).and(&count).apply(|s, &n| *s = *s / A::from(n).unwrap());
+        sum
+    }
+
+    /// Return the greatest element along `axis`, skipping NaN elements.
+    ///
+    /// The maximum of a lane with only NaN elements is NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn nanmax(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::nan(), |&m, &x| if x > m || m.is_nan() { x } else { m })
+    }
+
+    /// Return the least element along `axis`, skipping NaN elements.
+    ///
+    /// The minimum of a lane with only NaN elements is NaN.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn nanmin(&self, axis: Axis) -> Array<A, <D as RemoveAxis>::Smaller>
+        where A: Float,
+              D: RemoveAxis,
+    {
+        self.fold_axis(axis, A::nan(), |&m, &x| if x < m || m.is_nan() { x } else { m })
+    }
+
+    /// Return the L1 norm of the array, the sum of the absolute values of
+    /// its elements, treating it as a flat vector.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[ 3., -4.],
+    ///                [-1.,  0.]]);
+    /// assert_eq!(a.norm_l1(), 8.);
+    /// assert_eq!(a.norm_l2(), 26f64.sqrt());
+    /// assert_eq!(a.norm_max(), 4.);
+    /// ```
+    pub fn norm_l1(&self) -> A
+        where A: Float,
+    {
+        self.fold(A::zero(), |acc, &x| acc + x.abs())
+    }
+
+    /// Return the L2 (Euclidean) norm of the array, treating it as a flat
+    /// vector.
+    ///
+    /// The squares are computed after scaling by the max-norm, so that the
+    /// result does not overflow or underflow unless the norm itself does.
+    pub fn norm_l2(&self) -> A
+        where A: Float,
+    {
+        let scale = self.norm_max();
+        if scale == A::zero() || !scale.is_finite() {
+            return scale;
+        }
+        let ssq = self.fold(A::zero(), |acc, &x| {
+            let y = x / scale;
+            acc + y * y
+        });
+        scale * ssq.sqrt()
+    }
+
+    /// Return the max-norm of the array, the greatest absolute value of its
+    /// elements, treating it as a flat vector.
+    ///
+    /// The norm of an empty array is zero, and NaN if any element is NaN.
+    pub fn norm_max(&self) -> A
+        where A: Float,
+    {
+        self.fold(A::zero(), |acc, &x| {
+            let y = x.abs();
+            if y > acc || y.is_nan() { y } else { acc }
+        })
+    }
+
     /// Return `true` if the arrays' elementwise differences are all within
     /// the given absolute tolerance, `false` otherwise.
     ///
@@ -127,5 +1055,329 @@ impl<A, S, D> ArrayBase<S, D>
         let rhs_broadcast = rhs.broadcast_unwrap(self.raw_dim());
         self.iter().zip(rhs_broadcast.iter()).all(|(x, y)| (*x - *y).abs() <= tol)
     }
+
+    /// Raise each element to the integer power `n`, by repeated
+    /// multiplication.
+    ///
+    /// For float elements, see also [`.powi()`](#method.powi) and
+    /// [`.powf()`](#method.powf).
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1, 2, 3]);
+    /// assert_eq!(a.pow(3), arr1(&[1, 8, 27]));
+    /// ```
+    pub fn pow(&self, n: usize) -> Array<A, D>
+        where A: Clone + libnum::One + Mul<Output=A>,
+    {
+        self.mapv(move |x| libnum::pow(x, n))
+    }
+
+    /// Return a new array with each element limited to the range
+    /// `min..=max`.
+    ///
+    /// Elements that don't compare with the bounds (like `NaN`) are
+    /// left unchanged.
+    ///
+    /// **Panics** if `min > max`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[-2., 0.5, 3.]);
+    /// assert_eq!(a.clip(0., 1.), arr1(&[0., 0.5, 1.]));
+    /// ```
+    pub fn clip(&self, min: A, max: A) -> Array<A, D>
+        where A: Clone + PartialOrd,
+    {
+        assert!(min.partial_cmp(&max) != Some(cmp::Ordering::Greater),
+                "ndarray: clip: min must not be greater than max");
+        self.map(move |x| clip_elem(x, &min, &max).clone())
+    }
+
+    /// Limit each element to the range `min..=max`, in place.
+    ///
+    /// Elements that don't compare with the bounds (like `NaN`) are
+    /// left unchanged.
+    ///
+    /// **Panics** if `min > max`.
+    pub fn clip_inplace(&mut self, min: A, max: A)
+        where A: Clone + PartialOrd,
+              S: DataMut,
+    {
+        assert!(min.partial_cmp(&max) != Some(cmp::Ordering::Greater),
+                "ndarray: clip: min must not be greater than max");
+        self.map_inplace(move |x| {
+            if *x < min {
+                *x = min.clone();
+            } else if *x > max {
+                *x = max.clone();
+            }
+        });
+    }
+
+    /// Return a new array with the absolute value of each element.
+    ///
+    /// For signed integers, the absolute value of the minimum value
+    /// overflows (and panics in debug mode).
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[-2, 0, 3]).abs(), arr1(&[2, 0, 3]));
+    /// assert_eq!(arr1(&[-1.5, 2.]).abs(), arr1(&[1.5, 2.]));
+    /// ```
+    pub fn abs(&self) -> Array<A, D>
+        where A: Signed,
+    {
+        self.map(Signed::abs)
+    }
+
+    /// Replace each element with its absolute value, in place.
+    ///
+    /// For signed integers, the absolute value of the minimum value
+    /// overflows (and panics in debug mode).
+    pub fn iabs(&mut self)
+        where A: Signed,
+              S: DataMut,
+    {
+        self.unordered_foreach_mut(|x| *x = x.abs());
+    }
+
+    /// Return a new array with the sign of each element.
+    ///
+    /// For signed integers the sign is `1` for positive numbers, `0` for zero
+    /// and `-1` for negative numbers. For floats it is `1.` for positive
+    /// numbers (including `+0.` and `+∞`), `-1.` for negative numbers
+    /// (including `-0.` and `-∞`) and `NaN` for `NaN`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[-7, 0, 3]).signum(), arr1(&[-1, 0, 1]));
+    /// assert_eq!(arr1(&[-7., 0., 3.]).signum(), arr1(&[-1., 1., 1.]));
+    /// ```
+    pub fn signum(&self) -> Array<A, D>
+        where A: Signed,
+    {
+        self.map(Signed::signum)
+    }
+
+    /// Return a new array with the elementwise maximum of `self` and `rhs`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// Where the elements don't compare (like `NaN`), the element of `self`
+    /// is kept.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1., 5.],
+    ///                [7., 3.]]);
+    /// assert_eq!(a.maximum(&arr1(&[4., 4.])), arr2(&[[4., 5.],
+    ///                                                [7., 4.]]));
+    /// ```
+    pub fn maximum<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+        where A: Clone + PartialOrd,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let mut res = self.to_owned();
+        res.maximum_inplace(rhs);
+        res
+    }
+
+    /// Return a new array with the elementwise minimum of `self` and `rhs`.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// Where the elements don't compare (like `NaN`), the element of `self`
+    /// is kept.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn minimum<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+        where A: Clone + PartialOrd,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let mut res = self.to_owned();
+        res.minimum_inplace(rhs);
+        res
+    }
+
+    /// Replace each element with the maximum of it and the corresponding
+    /// element of `rhs`, in place.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn maximum_inplace<S2, E>(&mut self, rhs: &ArrayBase<S2, E>)
+        where A: Clone + PartialOrd,
+              S: DataMut,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).apply(|x, y| if *y > *x { *x = y.clone(); });
+    }
+
+    /// Replace each element with the minimum of it and the corresponding
+    /// element of `rhs`, in place.
+    ///
+    /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn minimum_inplace<S2, E>(&mut self, rhs: &ArrayBase<S2, E>)
+        where A: Clone + PartialOrd,
+              S: DataMut,
+              S2: Data<Elem=A>,
+              E: Dimension,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).apply(|x, y| if *y < *x { *x = y.clone(); });
+    }
 }
 
+impl<A, S> ArrayBase<S, Ix2>
+    where S: Data<Elem=A>,
+{
+    /// Return the sum of the diagonal of the matrix.
+    ///
+    /// With `offset` zero this is the main diagonal, `a[[i, i]]`; otherwise
+    /// it is `a[[i, i + offset]]`, a diagonal above the main diagonal for a
+    /// positive `offset` and below it for a negative one. The sum of an
+    /// empty diagonal is zero. The diagonal is summed in place, as a
+    /// strided view.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.trace(0), 1 + 5);
+    /// assert_eq!(a.trace(1), 2 + 6);
+    /// assert_eq!(a.trace(-1), 4);
+    /// assert_eq!(a.trace(3), 0);
+    /// ```
+    pub fn trace(&self, offset: isize) -> A
+        where A: Clone + Add<Output=A> + Zero,
+    {
+        offset_diagonal(self.view(), offset, Axis(0), Axis(1))
+            .into_diag()
+            .fold(A::zero(), |acc, x| acc + x.clone())
+    }
+}
+
+/// Return the view of `a` that starts at the diagonal with the given
+/// `offset` between `axis1` and `axis2`, so that the diagonal begins at
+/// index zero along both axes.
+fn offset_diagonal<A, D>(a: ArrayView<A, D>, offset: isize, axis1: Axis, axis2: Axis)
+    -> ArrayView<A, D>
+    where D: Dimension,
+{
+    let (axis, skip) = if offset >= 0 {
+        (axis2, offset as usize)
+    } else {
+        (axis1, offset.wrapping_neg() as usize)
+    };
+    let skip = cmp::min(skip, a.len_of(axis));
+    a.split_at(axis, skip).1
+}
+
+/// Reorder `v` so that the element at `k` is the one that would be there
+/// if `v` were sorted, and no element after it is smaller; return it.
+///
+/// Quickselect with median of three pivots and three way partitioning, so
+/// that repeated elements are handled in linear time. The elements must be
+/// totally ordered (no `NaN`).
+fn select_nth<A>(v: &mut [A], k: usize) -> A
+    where A: PartialOrd + Copy,
+{
+    let (mut lo, mut hi) = (0, v.len());
+    while hi - lo > 1 {
+        let mid = lo + (hi - lo) / 2;
+        let (a, b, c) = (v[lo], v[mid], v[hi - 1]);
+        let pivot = if (a < b) == (b < c) {
+            b
+        } else if (b < a) == (a < c) {
+            a
+        } else {
+            c
+        };
+        // partition lo..hi into [< pivot | == pivot | > pivot]
+        let (mut lt, mut i, mut gt) = (lo, lo, hi);
+        while i < gt {
+            if v[i] < pivot {
+                v.swap(i, lt);
+                lt += 1;
+                i += 1;
+            } else if pivot < v[i] {
+                gt -= 1;
+                v.swap(i, gt);
+            } else {
+                i += 1;
+            }
+        }
+        if k < lt {
+            hi = lt;
+        } else if k >= gt {
+            lo = gt;
+        } else {
+            break;
+        }
+    }
+    v[k]
+}
+
+/// Return the element (and its key) that `better` prefers over all others
+/// (the first one, among equals), or the first element that doesn't compare
+/// with itself.
+fn extremum<'a, A, K, I, F>(iter: I, better: F) -> Option<(K, &'a A)>
+    where A: PartialOrd + 'a,
+          I: IntoIterator<Item=(K, &'a A)>,
+          F: Fn(&A, &A) -> bool,
+{
+    let mut iter = iter.into_iter();
+    let mut best = match iter.next() {
+        Some(x) => x,
+        None => return None,
+    };
+    if best.1.partial_cmp(best.1).is_none() {
+        return Some(best);
+    }
+    for x in iter {
+        if x.1.partial_cmp(x.1).is_none() {
+            return Some(x);
+        }
+        if better(x.1, best.1) {
+            best = x;
+        }
+    }
+    Some(best)
+}
+
+/// Convert `x` to `B`, or panic if it is not representable.
+fn cast_elem<A, B>(x: &A) -> B
+    where A: Clone + ToPrimitive,
+          B: NumCast,
+{
+    match B::from(x.clone()) {
+        Some(y) => y,
+        None => panic!("ndarray: element is not representable in the accumulator type"),
+    }
+}
+
+#[inline]
+fn clip_elem<'a, A: PartialOrd>(x: &'a A, min: &'a A, max: &'a A) -> &'a A {
+    if *x < *min {
+        min
+    } else if *x > *max {
+        max
+    } else {
+        x
+    }
+}
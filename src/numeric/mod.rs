@@ -1,3 +1,4 @@
-
 mod impl_numeric;
-
+mod impl_float_maths;
+mod impl_integer_ops;
+mod impl_complex;
@@ -10,6 +10,7 @@ use libnum;
 use std::cmp;
 use std::ops::{
     Add,
+    Mul,
 };
 
 use LinalgScalar;
@@ -51,6 +52,46 @@ pub fn unrolled_sum<A>(mut xs: &[A]) -> A
     sum
 }
 
+/// Length of the blocks summed directly by `pairwise_sum`
+const PAIRWISE_BLOCK: usize = 512;
+
+/// Compute the sum of the values in `xs` by pairwise summation: the
+/// halves of long slices are summed separately and then added, so that
+/// rounding errors grow with the logarithm of the length (instead of
+/// linearly).
+pub fn pairwise_sum<A>(xs: &[A]) -> A
+    where A: Clone + Add<Output=A> + libnum::Zero,
+{
+    if xs.len() <= PAIRWISE_BLOCK {
+        unrolled_sum(xs)
+    } else {
+        let (a, b) = xs.split_at(xs.len() / 2);
+        pairwise_sum(a) + pairwise_sum(b)
+    }
+}
+
+/// Compute the product of the values in `xs`
+pub fn unrolled_prod<A>(mut xs: &[A]) -> A
+    where A: Clone + Mul<Output=A> + libnum::One,
+{
+    // fourfold unrolled so that the multiplications are independent
+    let (mut p0, mut p1, mut p2, mut p3) =
+        (A::one(), A::one(), A::one(), A::one());
+    while xs.len() >= 4 {
+        p0 = p0 * xs[0].clone();
+        p1 = p1 * xs[1].clone();
+        p2 = p2 * xs[2].clone();
+        p3 = p3 * xs[3].clone();
+
+        xs = &xs[4..];
+    }
+    let mut prod = (p0 * p2) * (p1 * p3);
+    for x in xs {
+        prod = prod * x.clone();
+    }
+    prod
+}
+
 /// Compute the dot product.
 ///
 /// `xs` and `ys` must be the same length
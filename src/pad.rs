@@ -0,0 +1,135 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use imp_prelude::*;
+
+/// How [`.pad()`](struct.ArrayBase.html#method.pad) fills the border it
+/// adds around an array.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PadMode<A> {
+    /// Fill the border with a constant value.
+    Constant(A),
+    /// Repeat the nearest element of the array at the edge.
+    Edge,
+    /// Wrap around, continuing with the elements from the opposite side, as
+    /// if the array were periodic.
+    Wrap,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    /// Return a new array with a border added around `self`, filled
+    /// according to `mode`.
+    ///
+    /// `widths` has a pair `(before, after)` for each axis, which is the
+    /// number of elements to add before the start and after the end of that
+    /// axis. The corners of the border are filled as if the axes were padded
+    /// one after the other.
+    ///
+    /// **Panics** if `widths` does not have one pair for each axis, or if an
+    /// axis of length zero is padded with `PadMode::Edge` or `PadMode::Wrap`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, PadMode};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.pad(&[(1, 0), (0, 1)], PadMode::Constant(0)),
+    ///            arr2(&[[0, 0, 0],
+    ///                   [1, 2, 0],
+    ///                   [3, 4, 0]]));
+    /// assert_eq!(a.pad(&[(1, 0), (0, 1)], PadMode::Edge),
+    ///            arr2(&[[1, 2, 2],
+    ///                   [1, 2, 2],
+    ///                   [3, 4, 4]]));
+    /// assert_eq!(a.pad(&[(1, 0), (0, 1)], PadMode::Wrap),
+    ///            arr2(&[[3, 4, 3],
+    ///                   [1, 2, 1],
+    ///                   [3, 4, 3]]));
+    /// ```
+    pub fn pad(&self, widths: &[(Ix, Ix)], mode: PadMode<A>) -> Array<A, D>
+        where A: Clone,
+    {
+        assert!(widths.len() == self.ndim(),
+                "ndarray: pad: {} pairs of widths for an array of shape {:?}",
+                widths.len(), self.shape());
+        let constant = match mode { PadMode::Constant(_) => true, _ => false };
+        let mut dim = self.raw_dim();
+        for (ax, (len, &(before, after))) in dim.slice_mut().iter_mut().zip(widths).enumerate() {
+            assert!(*len > 0 || before + after == 0 || constant,
+                    "ndarray: pad: axis {} of length zero can only be padded with a constant",
+                    ax);
+            *len += before + after;
+        }
+        let fill = match mode {
+            PadMode::Constant(ref value) => value.clone(),
+            _ => match self.iter().next() {
+                Some(elt) => elt.clone(),
+                // another axis has length zero, so the result is empty
+                None => return Array::from_shape_vec(dim, Vec::new()).unwrap(),
+            },
+        };
+        let mut res = Array::from_elem(dim, fill);
+        interior(res.view_mut(), widths, self.shape(), 0).assign(self);
+        if constant {
+            return res;
+        }
+        // pad one axis at a time, copying blocks of the part filled so far;
+        // this also fills the corners
+        for (ax, (&(before, after), &len)) in widths.iter().zip(self.shape()).enumerate() {
+            if before + after == 0 {
+                continue;
+            }
+            let region = interior(res.view_mut(), widths, self.shape(), ax + 1);
+            let (mut head, rest) = region.split_at(Axis(ax), before);
+            let (mid, mut tail) = rest.split_at(Axis(ax), len);
+            match mode {
+                PadMode::Edge => {
+                    // the edges have length one along `ax`, and broadcast
+                    head.assign(&mid.view().split_at(Axis(ax), 1).0);
+                    tail.assign(&mid.view().split_at(Axis(ax), len - 1).1);
+                }
+                _ => {
+                    // copy whole periods, ending at the array in `head` and
+                    // starting from it in `tail`
+                    while head.len_of(Axis(ax)) > 0 {
+                        let n = cmp::min(len, head.len_of(Axis(ax)));
+                        let split = head.len_of(Axis(ax)) - n;
+                        let (rest, mut period) = head.split_at(Axis(ax), split);
+                        period.assign(&mid.view().split_at(Axis(ax), len - n).1);
+                        head = rest;
+                    }
+                    while tail.len_of(Axis(ax)) > 0 {
+                        let n = cmp::min(len, tail.len_of(Axis(ax)));
+                        let (mut period, rest) = tail.split_at(Axis(ax), n);
+                        period.assign(&mid.view().split_at(Axis(ax), n).0);
+                        tail = rest;
+                    }
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Narrow `view` to the elements copied from the array of shape `shape`,
+/// along `axis` and the axes after it.
+fn interior<'a, A, D>(mut view: ArrayViewMut<'a, A, D>, widths: &[(Ix, Ix)], shape: &[Ix],
+                      axis: usize) -> ArrayViewMut<'a, A, D>
+    where D: Dimension,
+{
+    for (ax, (&(before, _), &len)) in widths.iter().zip(shape).enumerate().skip(axis) {
+        let (_, rest) = view.split_at(Axis(ax), before);
+        view = rest.split_at(Axis(ax), len).0;
+    }
+    view
+}
@@ -0,0 +1,243 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parallel elementwise operations using native threads.
+
+use std::cmp;
+use std::panic;
+use std::thread;
+
+use imp_prelude::*;
+
+/// Parallel traversal of the elements of an array view.
+///
+/// Created with [`.par_iter()`](struct.ArrayBase.html#method.par_iter).
+pub struct ParIter<'a, A: 'a, D> {
+    view: ArrayView<'a, A, D>,
+    threads: usize,
+}
+
+/// Parallel traversal of the elements of a mutable array view.
+///
+/// Created with
+/// [`.par_iter_mut()`](struct.ArrayBase.html#method.par_iter_mut).
+pub struct ParIterMut<'a, A: 'a, D> {
+    view: ArrayViewMut<'a, A, D>,
+    threads: usize,
+}
+
+macro_rules! par_iter_impl {
+    ($name:ident, $item:ty, $sync:ident) => {
+impl<'a, A, D> $name<'a, A, D>
+    where A: $sync,
+          D: Dimension,
+{
+    /// Use at most `threads` threads (including the calling thread).
+    ///
+    /// The default is the number of available CPUs.
+    ///
+    /// **Panics** if `threads` is zero.
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "ndarray: thread count must be non-zero");
+        self.threads = threads;
+        self
+    }
+
+    /// Call `f` on each element, in parallel.
+    ///
+    /// Elements are visited in arbitrary order.
+    pub fn for_each<F>(self, f: F)
+        where F: Fn($item) + Sync,
+    {
+        par_split(self.view, self.threads, &|view| {
+            ::Zip::from(view).apply(|x| f(x))
+        });
+    }
+}
+    }
+}
+
+par_iter_impl!(ParIter, &'a A, Sync);
+par_iter_impl!(ParIterMut, &'a mut A, Send);
+
+/// Arrays with fewer elements than this are mapped by `par_map` on the
+/// calling thread, since starting threads would cost more than it saves.
+const PAR_MAP_MIN_LEN: usize = 1 << 12;
+
+/// Number of threads used by default.
+fn default_threads() -> usize {
+    thread::available_parallelism().map(|n| n.get()).unwrap_or(1)
+}
+
+/// Views that can be split in two along an axis.
+trait Split: Sized {
+    fn shape(&self) -> &[Ix];
+    fn split_at(self, axis: Axis, index: Ix) -> (Self, Self);
+}
+
+impl<'a, A, D: Dimension> Split for ArrayView<'a, A, D> {
+    fn shape(&self) -> &[Ix] {
+        self.shape()
+    }
+    fn split_at(self, axis: Axis, index: Ix) -> (Self, Self) {
+        self.split_at(axis, index)
+    }
+}
+
+impl<'a, A, D: Dimension> Split for ArrayViewMut<'a, A, D> {
+    fn shape(&self) -> &[Ix] {
+        self.shape()
+    }
+    fn split_at(self, axis: Axis, index: Ix) -> (Self, Self) {
+        self.split_at(axis, index)
+    }
+}
+
+// Two views of the same shape are split in lock step.
+impl<P, Q> Split for (P, Q)
+    where P: Split,
+          Q: Split,
+{
+    fn shape(&self) -> &[Ix] {
+        self.0.shape()
+    }
+    fn split_at(self, axis: Axis, index: Ix) -> (Self, Self) {
+        let (p1, p2) = self.0.split_at(axis, index);
+        let (q1, q2) = self.1.split_at(axis, index);
+        ((p1, q1), (p2, q2))
+    }
+}
+
+/// Split `part` into at most `threads` pieces along its longest axis, and
+/// call `f` on each piece in its own thread.
+fn par_split<P, F>(part: P, threads: usize, f: &F)
+    where P: Split + Send,
+          F: Fn(P) + Sync,
+{
+    thread::scope(|scope| {
+        let mut part = part;
+        let mut threads = threads;
+        loop {
+            let (axis, len) = part.shape().iter().cloned().enumerate()
+                                  .max_by_key(|&(_, len)| len)
+                                  .unwrap_or((0, 0));
+            if threads <= 1 || len <= 1 {
+                f(part);
+                return;
+            }
+            // split off a share proportional to one thread and hand it off
+            let index = len / threads;
+            let (left, right) = part.split_at(Axis(axis), index.max(1));
+            scope.spawn(move || f(left));
+            part = right;
+            threads -= 1;
+        }
+    })
+}
+
+impl<A, S, D> ArrayBase<S, D>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    /// Return a parallel traversal of the elements of the array.
+    ///
+    /// The array is split along its longest axis into one piece per
+    /// thread.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    ///
+    /// let a = Array2::from_elem((64, 64), 1);
+    /// let count = AtomicUsize::new(0);
+    /// a.par_iter().for_each(|&x| { count.fetch_add(x, Ordering::Relaxed); });
+    /// assert_eq!(count.load(Ordering::Relaxed), 64 * 64);
+    /// ```
+    pub fn par_iter(&self) -> ParIter<A, D>
+        where A: Sync,
+    {
+        ParIter {
+            view: self.view(),
+            threads: default_threads(),
+        }
+    }
+
+    /// Return a parallel traversal of the elements of the array, yielding
+    /// mutable references.
+    ///
+    /// The array is split along its longest axis into one piece per
+    /// thread.
+    ///
+    /// ```
+    /// use ndarray::Array2;
+    ///
+    /// let mut a = Array2::<f64>::zeros((64, 64));
+    /// a.par_iter_mut().for_each(|x| *x += 1.);
+    /// assert_eq!(a, Array2::from_elem((64, 64), 1.));
+    /// ```
+    pub fn par_iter_mut(&mut self) -> ParIterMut<A, D>
+        where S: DataMut,
+              A: Send,
+    {
+        ParIterMut {
+            view: self.view_mut(),
+            threads: default_threads(),
+        }
+    }
+
+    /// Call `f` by reference on each element, in parallel, and create a new
+    /// array with the new values.
+    ///
+    /// Elements are visited in arbitrary order. The array is split along
+    /// its first axis into one piece per thread; small arrays are mapped on
+    /// the calling thread.
+    ///
+    /// Return an array with the same shape as `self`, in standard layout.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[ 0., 1.],
+    ///                [-1., 2.]]);
+    /// assert_eq!(a.par_map(|x| x * 2.), arr2(&[[ 0., 2.],
+    ///                                          [-2., 4.]]));
+    /// ```
+    pub fn par_map<B, F>(&self, f: F) -> Array<B, D>
+        where F: Fn(&A) -> B + Sync,
+              A: Sync,
+              B: Send,
+    {
+        let rows = if self.ndim() == 0 { 1 } else { self.len_of(Axis(0)) };
+        let threads = cmp::min(cmp::min(default_threads(), self.len() / PAR_MAP_MIN_LEN), rows);
+        let elems = if threads <= 1 {
+            self.iter().map(&f).collect()
+        } else {
+            // map consecutive blocks of rows, then join the results in order
+            let mut elems = Vec::with_capacity(self.len());
+            thread::scope(|scope| {
+                let f = &f;
+                let mut rest = self.view();
+                let mut handles = Vec::with_capacity(threads);
+                for t in (1..threads + 1).rev() {
+                    let len = rest.len_of(Axis(0));
+                    let (piece, right) = rest.split_at(Axis(0), len / t);
+                    rest = right;
+                    handles.push(scope.spawn(move || piece.iter().map(f).collect::<Vec<B>>()));
+                }
+                for handle in handles {
+                    match handle.join() {
+                        Ok(part) => elems.extend(part),
+                        Err(e) => panic::resume_unwind(e),
+                    }
+                }
+            });
+            elems
+        };
+        Array::from_shape_vec(self.dim.clone(), elems).unwrap()
+    }
+}
@@ -3,6 +3,16 @@ use Dimension;
 use {Shape, StrideShape};
 use dimension::IntoDimension;
 
+/// The order in which the elements of an array are laid out in a line, as
+/// by [`.ravel()`](struct.ArrayBase.html#method.ravel).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Order {
+    /// Row major (C) order: the last index varies the fastest.
+    RowMajor,
+    /// Column major (Fortran) order: the first index varies the fastest.
+    ColumnMajor,
+}
+
 /// A trait for `Shape` and `D where D: Dimension` that allows
 /// customizing the memory layout (strides) of an array shape.
 ///
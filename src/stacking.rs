@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use imp_prelude::*;
-use error::{ShapeError, ErrorKind, from_kind};
+use error::{ShapeError, ErrorKind, from_kind, incompatible_shapes};
 
 /// Stack arrays along the given axis.
 ///
@@ -71,6 +71,116 @@ pub fn stack<'a, A, D>(axis: Axis, arrays: &[ArrayView<'a, A, D>])
     Ok(res)
 }
 
+/// Stack arrays along a new axis.
+///
+/// Unlike [`stack`](fn.stack.html), which joins arrays along an existing
+/// axis, this inserts a new axis at position `axis` in the result; for
+/// example `k` arrays of shape `(m, n)` stacked along `Axis(0)` give an
+/// array of shape `(k, m, n)`.
+///
+/// ***Errors*** if the arrays have mismatching shapes; the error records the
+/// shape of the first array that differs and the shape of `arrays[0]`.<br>
+/// ***Errors*** if `arrays` is empty, if `axis` is greater than the number
+/// of axes of the arrays, if the result is larger than is possible to
+/// represent.
+///
+/// ```
+/// use ndarray::{arr2, arr3, Axis, stack_new_axis};
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// let b = arr2(&[[5, 6],
+///                [7, 8]]);
+/// assert_eq!(stack_new_axis(Axis(0), &[a.view(), b.view()]).unwrap(),
+///            arr3(&[[[1, 2],
+///                    [3, 4]],
+///                   [[5, 6],
+///                    [7, 8]]]));
+/// assert_eq!(stack_new_axis(Axis(2), &[a.view(), b.view()]).unwrap(),
+///            arr3(&[[[1, 5], [2, 6]],
+///                   [[3, 7], [4, 8]]]));
+/// ```
+pub fn stack_new_axis<'a, A, D>(axis: Axis, arrays: &[ArrayView<'a, A, D>])
+    -> Result<Array<A, D::Larger>, ShapeError>
+    where A: Copy,
+          D: Dimension
+{
+    if arrays.len() == 0 {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+    if axis.axis() > arrays[0].ndim() {
+        return Err(from_kind(ErrorKind::OutOfBounds));
+    }
+    if let Some(a) = arrays.iter().find(|a| a.shape() != arrays[0].shape()) {
+        return Err(incompatible_shapes(&a.raw_dim(), &arrays[0].raw_dim()));
+    }
+    let arrays = arrays.iter().map(|a| a.clone().insert_axis(axis)).collect::<Vec<_>>();
+    stack(axis, &arrays)
+}
+
+/// Repeat the whole array `a` along each axis, `reps[i]` times along axis
+/// `i`, like a tiling.
+///
+/// The result has the same number of axes as `a`, and is `reps[i]` times
+/// as long along axis `i`. Each tile is copied from `a` as a block, using
+/// contiguous copies where the memory layout of `a` allows.
+///
+/// **Panics** if `reps` does not have one count for each axis of `a`, or if
+/// the size of the result would overflow `usize`.
+///
+/// ```
+/// use ndarray::{arr2, tile};
+///
+/// let a = arr2(&[[1, 2],
+///                [3, 4]]);
+/// assert_eq!(tile(&a, &[2, 3]),
+///            arr2(&[[1, 2, 1, 2, 1, 2],
+///                   [3, 4, 3, 4, 3, 4],
+///                   [1, 2, 1, 2, 1, 2],
+///                   [3, 4, 3, 4, 3, 4]]));
+/// ```
+pub fn tile<A, S, D>(a: &ArrayBase<S, D>, reps: &[Ix]) -> Array<A, D>
+    where A: Copy,
+          S: Data<Elem=A>,
+          D: Dimension,
+{
+    assert!(reps.len() == a.ndim(),
+            "ndarray: tile: {} repetition counts for an array of shape {:?}",
+            reps.len(), a.shape());
+    let mut res_dim = a.raw_dim();
+    for (len, &r) in res_dim.slice_mut().iter_mut().zip(reps) {
+        *len = len.checked_mul(r)
+                  .expect("ndarray: tile: the size of the result overflows usize");
+    }
+    let size = res_dim.size_checked()
+                      .expect("ndarray: tile: the size of the result overflows usize");
+    if size == 0 {
+        return Array::from_shape_vec(res_dim, Vec::new()).unwrap();
+    }
+    // fill with any element of `a`; the tiles overwrite all of them
+    let mut res = Array::from_elem(res_dim, *a.iter().next().unwrap());
+    assign_tiles(res.view_mut(), &a.view(), reps, 0);
+    res
+}
+
+/// Assign `a` to each tile of `view`, splitting it along `axis` and the
+/// axes after it.
+fn assign_tiles<A, D>(mut view: ArrayViewMut<A, D>, a: &ArrayView<A, D>, reps: &[Ix], axis: usize)
+    where A: Copy,
+          D: Dimension,
+{
+    if axis == a.ndim() {
+        view.assign(a);
+        return;
+    }
+    let len = a.shape()[axis];
+    for _ in 0..reps[axis] {
+        let (tile, rest) = view.split_at(Axis(axis), len);
+        assign_tiles(tile, a, reps, axis + 1);
+        view = rest;
+    }
+}
+
 /// Stack arrays along the given axis.
 ///
 /// Uses the [`stack`][1] function, calling `ArrayView::from(&a)` on each
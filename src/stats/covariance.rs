@@ -0,0 +1,86 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use libnum::Float;
+
+use imp_prelude::*;
+use linalg::general_mat_mul;
+use LinalgScalar;
+
+/// Return the covariance matrix of the variables in the rows of `a`.
+///
+/// Each row of `a` holds the observations of one variable, so a matrix with
+/// `m` rows gives an `m × m` covariance matrix. For variables in the
+/// columns, pass `a.t()`.
+///
+/// The sums of products of deviations from the mean are divided by
+/// `n - ddof`, where `n` is the number of observations. Use `ddof = 0.` for
+/// the population covariance and `ddof = 1.` for the unbiased sample
+/// covariance.
+///
+/// **Panics** if `ddof` is negative or not less than the number of
+/// observations.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::stats::cov;
+///
+/// let a = arr2(&[[1., 2., 3.],
+///                [6., 4., 2.]]);
+/// assert_eq!(cov(&a, 1.), arr2(&[[ 1., -2.],
+///                                [-2.,  4.]]));
+/// ```
+pub fn cov<A, S>(a: &ArrayBase<S, Ix2>, ddof: A) -> Array2<A>
+    where A: LinalgScalar + Float,
+          S: Data<Elem=A>,
+{
+    let (m, n) = a.dim();
+    let n = A::from(n).unwrap();
+    assert!(ddof >= A::zero() && ddof < n,
+            "ndarray: cov: ddof must be non-negative and less than the number of observations");
+    let mean = a.mean(Axis(1)).into_shape((m, 1)).unwrap();
+    let centered = a - &mean;
+    let mut c = Array2::zeros((m, m));
+    general_mat_mul(A::one() / (n - ddof), &centered, &centered.t(), A::zero(), &mut c);
+    c
+}
+
+/// Return the matrix of Pearson correlation coefficients of the variables
+/// in the rows of `a`.
+///
+/// This is the covariance matrix (see [`cov`](fn.cov.html)) normalized by
+/// the standard deviations of the variables, so that its elements are in
+/// the range `-1..=1`. Variables with zero variance give NaN coefficients.
+///
+/// **Panics** if `a` has no columns (observations).
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::stats::corrcoef;
+///
+/// let a = arr2(&[[1., 2., 3.],
+///                [6., 4., 2.],
+///                [1., 3., 2.]]);
+/// let r = corrcoef(&a);
+/// assert!(r.all_close(&arr2(&[[ 1.0, -1.0,  0.5],
+///                             [-1.0,  1.0, -0.5],
+///                             [ 0.5, -0.5,  1.0]]), 1e-12));
+/// ```
+pub fn corrcoef<A, S>(a: &ArrayBase<S, Ix2>) -> Array2<A>
+    where A: LinalgScalar + Float,
+          S: Data<Elem=A>,
+{
+    let mut c = cov(a, A::zero());
+    let std = c.diag().mapv(A::sqrt);
+    for ((i, j), r) in c.indexed_iter_mut() {
+        let x = *r / (std[i] * std[j]);
+        // keep rounding errors from leaving the range
+        *r = if x.is_nan() { x } else { x.max(-A::one()).min(A::one()) };
+    }
+    c
+}
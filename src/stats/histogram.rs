@@ -0,0 +1,204 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use libnum::Float;
+
+use imp_prelude::*;
+use Zip;
+
+/// How to divide the range of values into the bins of a histogram.
+#[derive(Clone, Debug)]
+pub enum Bins<A> {
+    /// The given number of bins of equal width, covering the range from the
+    /// least to the greatest value of the data.
+    Count(usize),
+    /// The given number of bins of equal width, covering the range from the
+    /// first to the second value (inclusive).
+    Uniform(usize, A, A),
+    /// Bins between consecutive values of the given increasing edges.
+    Edges(Array1<A>),
+}
+
+/// Count the elements of `a` that fall into each of the `bins`.
+///
+/// Each bin includes its lower edge, and the last bin also includes its
+/// upper edge. NaN elements and elements outside of all bins are not
+/// counted.
+///
+/// Return the counts and the bin edges, which have one element more than
+/// the counts.
+///
+/// **Panics** if there are no bins, the edges are not increasing, or a
+/// range is empty.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::stats::{histogram, Bins};
+///
+/// let a = arr2(&[[0., 1., 1.5],
+///                [2., 3.5, 4.]]);
+/// let (counts, edges) = histogram(&a, Bins::Count(4));
+/// assert_eq!(counts, arr1(&[1, 2, 1, 2]));
+/// assert_eq!(edges, arr1(&[0., 1., 2., 3., 4.]));
+///
+/// let (counts, _) = histogram(&a, Bins::Edges(arr1(&[1., 2., 10.])));
+/// assert_eq!(counts, arr1(&[2, 3]));
+/// ```
+pub fn histogram<A, S, D>(a: &ArrayBase<S, D>, bins: Bins<A>) -> (Array1<usize>, Array1<A>)
+    where A: Float,
+          S: Data<Elem=A>,
+          D: Dimension,
+{
+    let bins = Binning::new(bins, a);
+    let mut counts = Array1::zeros(bins.len());
+    for &x in a.iter() {
+        if let Some(i) = bins.index(x) {
+            counts[i] += 1;
+        }
+    }
+    (counts, bins.edges)
+}
+
+/// Count the pairs of corresponding elements of `x` and `y` that fall into
+/// each combination of the `x_bins` and the `y_bins`.
+///
+/// The bins are defined like for [`histogram`](fn.histogram.html); a pair
+/// is only counted if both of its values are in a bin.
+///
+/// Return the counts, with one row per bin of `x` and one column per bin of
+/// `y`, and the bin edges of `x` and `y`.
+///
+/// **Panics** if the shapes of `x` and `y` differ, or if the bins are
+/// invalid.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::stats::{histogram2d, Bins};
+///
+/// let x = arr1(&[0., 0.5, 1., 2.]);
+/// let y = arr1(&[1., 3., 3., 2.]);
+/// let (counts, _, y_edges) = histogram2d(&x, &y, Bins::Uniform(2, 0., 2.),
+///                                        Bins::Count(2));
+/// assert_eq!(y_edges, arr1(&[1., 2., 3.]));
+/// assert_eq!(counts, arr2(&[[1, 1],
+///                           [0, 2]]));
+/// ```
+pub fn histogram2d<A, S1, S2, D>(x: &ArrayBase<S1, D>, y: &ArrayBase<S2, D>,
+                                 x_bins: Bins<A>, y_bins: Bins<A>)
+    -> (Array2<usize>, Array1<A>, Array1<A>)
+    where A: Float,
+          S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert!(x.shape() == y.shape(),
+            "ndarray: histogram2d: shapes {:?} and {:?} differ", x.shape(), y.shape());
+    let x_bins = Binning::new(x_bins, x);
+    let y_bins = Binning::new(y_bins, y);
+    let mut counts = Array2::zeros((x_bins.len(), y_bins.len()));
+    Zip::from(x).and(y).apply(|&x, &y| {
+        if let (Some(i), Some(j)) = (x_bins.index(x), y_bins.index(y)) {
+            counts[[i, j]] += 1;
+        }
+    });
+    (counts, x_bins.edges, y_bins.edges)
+}
+
+/// Resolved bin edges.
+struct Binning<A> {
+    edges: Array1<A>,
+    /// Whether the bins have equal width, so that a value's bin can be
+    /// computed directly.
+    uniform: bool,
+}
+
+impl<A: Float> Binning<A> {
+    fn new<S, D>(bins: Bins<A>, data: &ArrayBase<S, D>) -> Self
+        where S: Data<Elem=A>,
+              D: Dimension,
+    {
+        match bins {
+            Bins::Count(n) => {
+                let (lo, hi) = data.fold(None, |range, &x| {
+                    if x.is_nan() {
+                        return range;
+                    }
+                    match range {
+                        None => Some((x, x)),
+                        Some((lo, hi)) => Some((x.min(lo), x.max(hi))),
+                    }
+                }).unwrap_or((A::zero(), A::one()));
+                // widen a degenerate range, so that the data falls in a bin;
+                // for large values, by enough to separate all the edges
+                let half = A::from(0.5).unwrap()
+                    .max(lo.abs() * A::epsilon() * A::from(n).unwrap());
+                if lo == hi {
+                    Self::uniform(n, lo - half, hi + half)
+                } else {
+                    Self::uniform(n, lo, hi)
+                }
+            }
+            Bins::Uniform(n, lo, hi) => Self::uniform(n, lo, hi),
+            Bins::Edges(edges) => {
+                assert!(edges.len() >= 2, "ndarray: histogram: at least two bin edges are needed");
+                for (a, b) in edges.iter().zip(edges.iter().skip(1)) {
+                    assert!(a < b, "ndarray: histogram: bin edges must be increasing");
+                }
+                Binning { edges: edges, uniform: false }
+            }
+        }
+    }
+
+    fn uniform(n: usize, lo: A, hi: A) -> Self {
+        assert!(n > 0, "ndarray: histogram: the number of bins must be non-zero");
+        assert!(lo < hi && hi.is_finite() && lo.is_finite(),
+                "ndarray: histogram: the bin range must be finite and non-empty");
+        let mut edges = Array1::linspace(lo, hi, n + 1);
+        // make the last edge exact
+        edges[n] = hi;
+        Binning { edges: edges, uniform: true }
+    }
+
+    fn len(&self) -> usize {
+        self.edges.len() - 1
+    }
+
+    /// Return the bin of `x`, if any.
+    fn index(&self, x: A) -> Option<usize> {
+        let n = self.len();
+        let (lo, hi) = (self.edges[0], self.edges[n]);
+        if !(x >= lo && x <= hi) {
+            return None;
+        }
+        if x == hi {
+            return Some(n - 1);
+        }
+        if self.uniform {
+            let i = ((x - lo) / (hi - lo) * A::from(n).unwrap()).to_usize().unwrap();
+            let i = cmp::min(i, n - 1);
+            // correct for rounding, so that the result agrees with the edges
+            if x < self.edges[i] {
+                Some(i - 1)
+            } else if x >= self.edges[i + 1] {
+                Some(i + 1)
+            } else {
+                Some(i)
+            }
+        } else {
+            // binary search for the last edge not greater than x
+            let (mut a, mut b) = (0, n);
+            while b - a > 1 {
+                let mid = (a + b) / 2;
+                if self.edges[mid] <= x { a = mid } else { b = mid }
+            }
+            Some(a)
+        }
+    }
+}
@@ -0,0 +1,320 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use imp_prelude::*;
+
+/// Array-like values that can be traversed in lock step with [`Zip`].
+///
+/// Implemented for read-only and read-write array views.
+///
+/// [`Zip`]: struct.Zip.html
+pub trait NdProducer {
+    /// The element type of the underlying data.
+    type Elem;
+    /// The item produced for each element (a reference to it).
+    type Item;
+    /// The dimensionality of the producer.
+    type Dim: Dimension;
+    #[doc(hidden)]
+    fn raw_dim(&self) -> Self::Dim;
+    #[doc(hidden)]
+    fn is_standard_layout(&self) -> bool;
+    #[doc(hidden)]
+    fn as_ptr(&self) -> *mut Self::Elem;
+    #[doc(hidden)]
+    fn stride_of(&self, axis: Axis) -> isize;
+    #[doc(hidden)]
+    unsafe fn uget_ptr(&self, index: &Self::Dim) -> *mut Self::Elem;
+    #[doc(hidden)]
+    unsafe fn as_ref(ptr: *mut Self::Elem) -> Self::Item;
+}
+
+/// Conversion into an [`NdProducer`].
+///
+/// Implemented for array views and for references to arrays (`&A` produces
+/// `&A::Elem` items and `&mut A` produces `&mut A::Elem` items).
+///
+/// [`NdProducer`]: trait.NdProducer.html
+pub trait IntoNdProducer {
+    /// The producer that `self` is converted into.
+    type Output: NdProducer;
+    /// Convert `self` into a producer.
+    fn into_producer(self) -> Self::Output;
+}
+
+impl<P> IntoNdProducer for P
+    where P: NdProducer,
+{
+    type Output = Self;
+    fn into_producer(self) -> Self { self }
+}
+
+impl<'a, A: 'a, S, D> IntoNdProducer for &'a ArrayBase<S, D>
+    where S: Data<Elem=A>,
+          D: Dimension,
+{
+    type Output = ArrayView<'a, A, D>;
+    fn into_producer(self) -> Self::Output {
+        self.view()
+    }
+}
+
+impl<'a, A: 'a, S, D> IntoNdProducer for &'a mut ArrayBase<S, D>
+    where S: DataMut<Elem=A>,
+          D: Dimension,
+{
+    type Output = ArrayViewMut<'a, A, D>;
+    fn into_producer(self) -> Self::Output {
+        self.view_mut()
+    }
+}
+
+macro_rules! impl_producer {
+    ($view:ident, $item:ty, $as_ref:ident) => {
+impl<'a, A, D> NdProducer for $view<'a, A, D>
+    where D: Dimension,
+{
+    type Elem = A;
+    type Item = $item;
+    type Dim = D;
+
+    fn raw_dim(&self) -> D {
+        self.raw_dim()
+    }
+
+    fn is_standard_layout(&self) -> bool {
+        self.is_standard_layout()
+    }
+
+    fn as_ptr(&self) -> *mut A {
+        self.ptr
+    }
+
+    fn stride_of(&self, axis: Axis) -> isize {
+        self.strides()[axis.axis()]
+    }
+
+    unsafe fn uget_ptr(&self, index: &D) -> *mut A {
+        self.ptr.offset(D::stride_offset(index, &self.strides))
+    }
+
+    unsafe fn as_ref(ptr: *mut A) -> $item {
+        $as_ref(ptr)
+    }
+}
+    }
+}
+
+unsafe fn ptr_as_ref<'a, A>(ptr: *mut A) -> &'a A { &*ptr }
+unsafe fn ptr_as_mut<'a, A>(ptr: *mut A) -> &'a mut A { &mut *ptr }
+
+impl_producer!(ArrayView, &'a A, ptr_as_ref);
+impl_producer!(ArrayViewMut, &'a mut A, ptr_as_mut);
+
+/// Lock step traversal of up to six arrays of the same shape.
+///
+/// The `Zip` is created with [`Zip::from`](#method.from) from the first
+/// array or view, and more are added using [`.and()`](#method.and).
+/// [`.apply()`](#method.apply) then calls a closure with one item from each
+/// part, for every element.
+///
+/// When all the parts are contiguous and in standard layout, the traversal is
+/// a plain loop over the elements; otherwise it proceeds row by row.
+///
+/// See also the [`azip!()`](macro.azip!.html) macro, which is a more
+/// convenient way to use `Zip`.
+///
+/// ```
+/// use ndarray::{Array2, Zip};
+///
+/// let a = Array2::from_elem((3, 4), 1.);
+/// let b = Array2::from_elem((4, 3), 2.);
+/// let mut c = Array2::zeros((3, 4));
+///
+/// // b.t() is traversed in its (transposed) logical order
+/// Zip::from(&mut c).and(&a).and(&b.t()).apply(|c, &a, &b| {
+///     *c = a + b;
+/// });
+/// assert_eq!(c, Array2::from_elem((3, 4), 3.));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Zip<Parts, D> {
+    parts: Parts,
+    dimension: D,
+}
+
+impl<P, D> Zip<(P,), D>
+    where P: NdProducer<Dim=D>,
+          D: Dimension,
+{
+    /// Create a new `Zip` from the input array or view `p`.
+    pub fn from<IP>(p: IP) -> Self
+        where IP: IntoNdProducer<Output=P>,
+    {
+        let p = p.into_producer();
+        Zip {
+            dimension: p.raw_dim(),
+            parts: (p,),
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn zip_shape_error(from: &[Ix], to: &[Ix]) -> ! {
+    panic!("ndarray: Zip: shape {:?} does not match shape {:?}", from, to)
+}
+
+macro_rules! map_impl {
+    ($([$and:ident $($p:ident)*],)+) => {
+        $(
+        #[allow(non_snake_case)]
+        impl<D, $($p),*> Zip<($($p,)*), D>
+            where D: Dimension,
+                  $($p: NdProducer<Dim=D>,)*
+        {
+            /// Call `function` with one item from each part, for all
+            /// elements.
+            ///
+            /// Elements are visited in arbitrary order.
+            pub fn apply<F>(self, mut function: F)
+                where F: FnMut($($p::Item),*)
+            {
+                let ($($p,)*) = self.parts;
+                let ndim = self.dimension.ndim();
+                if $($p.is_standard_layout() &&)* true {
+                    $(
+                        let $p = $p.as_ptr();
+                    )*
+                    for i in 0..self.dimension.size() {
+                        unsafe {
+                            function($($p::as_ref($p.offset(i as isize))),*);
+                        }
+                    }
+                    return;
+                }
+                // ndim >= 1, since zero-dimensional arrays are in standard layout
+                let last = Axis(ndim - 1);
+                let inner_len = self.dimension[ndim - 1];
+                let mut outer = self.dimension.clone();
+                outer[ndim - 1] = 1;
+                let mut index = outer.first_index();
+                while let Some(ix) = index {
+                    $(
+                        let $p = unsafe { ($p.uget_ptr(&ix), $p.stride_of(last)) };
+                    )*
+                    for i in 0..inner_len as isize {
+                        unsafe {
+                            function($($p::as_ref($p.0.offset(i * $p.1))),*);
+                        }
+                    }
+                    index = outer.next_for(ix);
+                }
+            }
+
+            map_impl!(@and $and [$($p)*]);
+        }
+        )+
+    };
+    (@and no [$($p:ident)*]) => { };
+    (@and yes [$($p:ident)*]) => {
+            /// Include the producer `p` in the `Zip`.
+            ///
+            /// **Panics** if `p`’s shape doesn’t match the `Zip`’s exactly.
+            pub fn and<IP>(self, p: IP) -> Zip<($($p,)* IP::Output,), D>
+                where IP: IntoNdProducer,
+                      IP::Output: NdProducer<Dim=D>,
+            {
+                let p = p.into_producer();
+                let dim = p.raw_dim();
+                if dim != self.dimension {
+                    zip_shape_error(dim.slice(), self.dimension.slice());
+                }
+                let ($($p,)*) = self.parts;
+                Zip {
+                    parts: ($($p,)* p,),
+                    dimension: self.dimension,
+                }
+            }
+    };
+}
+
+map_impl!{
+    [yes P1],
+    [yes P1 P2],
+    [yes P1 P2 P3],
+    [yes P1 P2 P3 P4],
+    [yes P1 P2 P3 P4 P5],
+    [no P1 P2 P3 P4 P5 P6],
+}
+
+/// Array zip macro: lock step function application across several arrays.
+///
+/// This is a shorthand for [`Zip`](struct.Zip.html).
+///
+/// `azip!(mut a, b, c in { *a = b + c })` traverses the arrays `a`, `b`
+/// and `c` in lock step, and for each element evaluates the body with
+/// the element of `a` bound as `a: &mut A` and the elements of `b` and `c`
+/// bound by value (they must be `Copy`). Up to six arrays are supported,
+/// and any of them can be marked `mut`.
+///
+/// **Panics** if the arrays don’t have the same shape.
+///
+/// ```
+/// #[macro_use(azip)]
+/// extern crate ndarray;
+///
+/// use ndarray::Array2;
+///
+/// type M = Array2<f32>;
+///
+/// fn main() {
+///     let mut a = M::zeros((16, 16));
+///     let b = M::from_elem(a.dim(), 1.);
+///     let c = M::from_elem(a.dim(), 2.);
+///
+///     // Compute a simple ternary operation:
+///     // elementwise addition of b and c, stored in a
+///     azip!(mut a, b, c in { *a = b + c });
+///
+///     assert_eq!(a, &b + &c);
+/// }
+/// ```
+#[macro_export]
+macro_rules! azip {
+    // first argument
+    (@start mut $x:ident in $body:expr) => {
+        $crate::Zip::from(&mut $x).apply(|$x| $body)
+    };
+    (@start $x:ident in $body:expr) => {
+        $crate::Zip::from(&$x).apply(|&$x| $body)
+    };
+    (@start mut $x:ident, $($t:tt)*) => {
+        azip!(@munch ($crate::Zip::from(&mut $x)) [$x] $($t)*)
+    };
+    (@start $x:ident, $($t:tt)*) => {
+        azip!(@munch ($crate::Zip::from(&$x)) [&$x] $($t)*)
+    };
+    // last argument
+    (@munch ($e:expr) [$($pat:tt)*] mut $x:ident in $body:expr) => {
+        $e.and(&mut $x).apply(|$($pat)*, $x| $body)
+    };
+    (@munch ($e:expr) [$($pat:tt)*] $x:ident in $body:expr) => {
+        $e.and(&$x).apply(|$($pat)*, &$x| $body)
+    };
+    // arguments in the middle
+    (@munch ($e:expr) [$($pat:tt)*] mut $x:ident, $($t:tt)*) => {
+        azip!(@munch ($e.and(&mut $x)) [$($pat)*, $x] $($t)*)
+    };
+    (@munch ($e:expr) [$($pat:tt)*] $x:ident, $($t:tt)*) => {
+        azip!(@munch ($e.and(&$x)) [$($pat)*, &$x] $($t)*)
+    };
+    ($($t:tt)*) => {
+        azip!(@start $($t)*)
+    };
+}
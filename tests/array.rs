@@ -225,6 +225,29 @@ fn test_sub()
     assert_eq!(m, mat.subview(Axis(1), 1));
 }
 
+#[test]
+fn test_subview_mut()
+{
+    let mut a = Array::<f32, _>::zeros((3, 4));
+    let v = arr1(&[1., 2., 3., 4.]);
+    a.subview_mut(Axis(0), 1).assign(&v);
+    assert_eq!(a.row(1), v);
+    assert_eq!(a.row(0), Array::zeros(4));
+    assert_eq!(a.row(2), Array::zeros(4));
+
+    let w = arr1(&[7., 8., 9.]);
+    a.subview_mut(Axis(1), 3).assign(&w);
+    assert_eq!(a.column(3), w);
+    assert_eq!(a[[1, 2]], 3.);
+
+    // subview_mut on a shared array breaks sharing first
+    let mut b = RcArray::<i32, _>::zeros((2, 2, 2));
+    let c = b.clone();
+    b.subview_mut(Axis(2), 0).fill(1);
+    assert_eq!(b.scalar_sum(), 4);
+    assert_eq!(c.scalar_sum(), 0);
+}
+
 
 #[test]
 fn test_select(){
@@ -321,6 +344,29 @@ fn assign()
     assert_eq!(a, arr2(&[[0, 0], [3, 4]]));
 }
 
+#[test]
+fn zip_mut_with()
+{
+    let mut a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    let b = Array::from_iter(0..12).into_shape((4, 3)).unwrap();
+    let b = b.t();
+    a.zip_mut_with(&b, |x, &y| *x -= y);
+    assert_eq!(a, arr2(&[[ 0, -2, -4, -6],
+                         [ 3,  1, -1, -3],
+                         [ 6,  4,  2,  0]]));
+
+    /* Test different element types */
+    let mut c = Array::<f32, _>::zeros((2, 3));
+    let mask = arr2(&[[true, false, true], [false, true, false]]);
+    c.zip_mut_with(&mask, |x, &m| if m { *x = 1. });
+    assert_eq!(c, arr2(&[[1., 0., 1.], [0., 1., 0.]]));
+
+    /* Test broadcasting */
+    let mut d = Array::zeros((2, 3));
+    d.zip_mut_with(&arr2(&[[1], [2]]), |x, &y| *x = y);
+    assert_eq!(d, arr2(&[[1, 1, 1], [2, 2, 2]]));
+}
+
 #[test]
 fn sum_mean()
 {
@@ -414,6 +460,50 @@ fn map1()
     assert_eq!(a[(0, 0)], *c[(0, 0)]);
 }
 
+#[test]
+fn all_any()
+{
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert!(a.all(|&x| x > 0));
+    assert!(!a.all(|&x| x < 6));
+    assert!(a.any(|&x| x == 6));
+    assert!(!a.any(|&x| x > 6));
+
+    // non-contiguous, and stops early
+    let v = a.slice(s![.., ..;-2]);
+    let mut visited = 0;
+    assert!(!v.all(|&x| { visited += 1; x % 2 == 0 }));
+    assert_eq!(visited, 1);
+    assert!(v.t().all(|&x| x != 2 && x != 5));
+    assert!(v.any(|&x| x == 4));
+
+    let e = arr1::<i32>(&[]);
+    assert!(e.all(|_| false));
+    assert!(!e.any(|_| true));
+}
+
+#[test]
+fn map_inplace()
+{
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    a.map_inplace(|x| *x *= 2);
+    assert_eq!(a, arr2(&[[2, 4, 6], [8, 10, 12]]));
+
+    // stateful closures and non-contiguous views
+    let mut count = 0;
+    a.slice_mut(s![.., ..;2]).map_inplace(|x| { *x = 0; count += 1; });
+    assert_eq!(count, 4);
+    assert_eq!(a, arr2(&[[0, 4, 0], [0, 10, 0]]));
+
+    let mut sum = 0;
+    a.mapv_inplace(|x| { sum += x; x + 1 });
+    assert_eq!(sum, 14);
+    assert_eq!(a, arr2(&[[1, 5, 1], [1, 11, 1]]));
+
+    let b = a.t().to_owned().mapv_into(|x| x - 1);
+    assert_eq!(b, arr2(&[[0, 0], [4, 10], [0, 0]]));
+}
+
 #[test]
 fn as_slice_memory_order()
 {
@@ -637,6 +727,59 @@ fn slice_mut() {
                            [99, 5, 99]]));
 }
 
+#[test]
+fn slice_iter_mut() {
+    let mut a = RcArray::from_vec((0..12).collect()).reshape((4, 3));
+    let ptr = a.as_ptr();
+    for elt in a.slice_iter_mut(s![1..;2, ..]) {
+        *elt *= 10;
+    }
+    // uniquely owned, so no copy was made
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, aview2(&[[ 0,  1,   2],
+                           [30, 40,  50],
+                           [ 6,  7,   8],
+                           [90, 100, 110]]));
+
+    let b = a.clone();
+    assert_eq!(a.slice_iter_mut(s![.., ..;-1]).len(), 12);
+    assert_eq!(a, b);
+}
+
+#[test]
+fn multi_slice_mut() {
+    let mut a = RcArray::from_vec((0..16).collect()).reshape((4, 4));
+    let c = a.clone();
+    {
+        let mut views = a.multi_slice_mut(&[s![..2, ..], s![2.., ..;-1]]);
+        let lower = views.pop().unwrap();
+        views[0].assign(&lower);
+    }
+    assert_eq!(a, aview2(&[[11, 10,  9,  8],
+                           [15, 14, 13, 12],
+                           [ 8,  9, 10, 11],
+                           [12, 13, 14, 15]]));
+    assert!(c != a);
+
+    // interleaved steps are disjoint
+    let mut b = Array::from_vec((0..6).collect());
+    {
+        let mut views = b.multi_slice_mut(&[s![..;2], s![1..;2]]);
+        for elt in views[0].iter_mut() {
+            *elt = -1;
+        }
+        assert_eq!(views[1], aview1(&[1, 3, 5]));
+    }
+    assert_eq!(b, aview1(&[-1, 1, -1, 3, -1, 5]));
+}
+
+#[test]
+#[should_panic]
+fn multi_slice_mut_overlap() {
+    let mut a = Array::<f32, _>::zeros((4, 4));
+    a.multi_slice_mut(&[s![..2, ..3], s![1.., 2..]]);
+}
+
 #[test]
 fn assign_ops()
 {
@@ -770,6 +913,326 @@ fn reshape_f() {
                            [3, 7,11]]));
 }
 
+#[test]
+fn into_reshape() {
+    let a = Array::from_iter(0..12);
+    let ptr = a.as_ptr();
+    let b = a.into_reshape((3, 4));
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(b[[2, 1]], 9);
+
+    // fortran layout and slices are copied into logical order
+    let f = b.clone().reversed_axes();
+    let g = f.into_reshape(12);
+    assert_eq!(g, arr1(&[0, 4, 8, 1, 5, 9, 2, 6, 10, 3, 7, 11]));
+    let mut c = b.clone();
+    c.islice(s![.., ..;2]);
+    assert_eq!(c.into_reshape(6), arr1(&[0, 2, 4, 6, 8, 10]));
+
+    // a shared array stays shared
+    let r = RcArray::from_iter(0..6);
+    let r2 = r.clone().into_reshape((2, 3));
+    assert_eq!(r2.as_ptr(), r.as_ptr());
+}
+
+#[test]
+#[should_panic]
+fn into_reshape_error() {
+    Array::from_iter(0..12).into_reshape((5, 2));
+}
+
+#[test]
+fn checked_shape_errors() {
+    use ndarray::ErrorKind;
+
+    let r = RcArray::from_iter(0..6);
+    assert_eq!(r.try_reshape((3, 2)).unwrap(), r.reshape((3, 2)));
+    let err = r.try_reshape((4, 2)).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.from_shape(), Some(&[6][..]));
+    assert_eq!(err.to_shape(), Some(&[4, 2][..]));
+    assert!(err.to_string().contains("from [6] to [4, 2]"));
+
+    // layout errors have no shapes
+    let err = aview2(&[[1, 2, 3], [4, 5, 6]]).slice(s![.., ..2])
+                                             .into_shape(4).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleLayout);
+    assert_eq!(err.from_shape(), None);
+
+    let a = arr2(&[[1., 2., 3.]]);
+    assert_eq!(a.try_broadcast((2, 3)).unwrap(), a.broadcast((2, 3)).unwrap());
+    let err = a.try_broadcast((3, 2)).unwrap_err();
+    assert_eq!(err.from_shape(), Some(&[1, 3][..]));
+    assert_eq!(err.to_shape(), Some(&[3, 2][..]));
+
+    let mut b = Array::zeros((2, 3));
+    b.try_assign(&a).unwrap();
+    assert_eq!(b, arr2(&[[1., 2., 3.], [1., 2., 3.]]));
+    b.try_assign(&aview0(&0.)).unwrap();
+    assert_eq!(b, Array::zeros((2, 3)));
+    let err = b.try_assign(&a.t()).unwrap_err();
+    assert_eq!(err.from_shape(), Some(&[3, 1][..]));
+    assert_eq!(err.to_shape(), Some(&[2, 3][..]));
+    assert_eq!(b, Array::zeros((2, 3)));
+
+    b.try_zip_mut_with(&a, |x, &y| *x += 2. * y).unwrap();
+    assert_eq!(b, arr2(&[[2., 4., 6.], [2., 4., 6.]]));
+    let err = b.try_zip_mut_with(&arr1(&[1., 2.]), |x, &y| *x += y).unwrap_err();
+    assert_eq!(err.from_shape(), Some(&[2][..]));
+    assert_eq!(err.to_shape(), Some(&[2, 3][..]));
+}
+
+#[test]
+#[should_panic(expected = "from [6] to [4, 2]")]
+fn reshape_error_message() {
+    RcArray::from_iter(0..6).reshape((4, 2));
+}
+
+#[test]
+fn dyn_dimensionality_roundtrip() {
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    let ptr = a.as_ptr();
+    let d = a.into_dyn();
+    assert_eq!(d.shape(), &[2, 3, 4]);
+    let b = d.into_dimensionality::<Ix3>().unwrap();
+    assert_eq!(b.as_ptr(), ptr);
+    assert_eq!(b[[1, 2, 3]], 23);
+
+    // strides are kept, so transposed views convert too
+    let t = b.view().reversed_axes().into_dyn();
+    let t2 = t.into_dimensionality::<Ix3>().unwrap();
+    assert_eq!(t2, b.t());
+
+    // a dynamic array with the wrong number of axes is rejected
+    let d = b.into_dyn();
+    assert!(d.view().into_dimensionality::<Ix2>().is_err());
+    assert!(d.view().into_dimensionality::<IxDyn>().is_ok());
+}
+
+#[test]
+fn ravel() {
+    use ndarray::Order;
+
+    let a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    let ptr = a.as_ptr();
+    let r = a.clone().ravel(Order::RowMajor);
+    assert_eq!(r, arr1(&[0, 1, 2, 3, 4, 5]));
+    assert_eq!(a.clone().ravel(Order::ColumnMajor), arr1(&[0, 3, 1, 4, 2, 5]));
+    // a matching layout reuses the buffer
+    let f = a.reversed_axes();
+    assert_eq!(f.clone().ravel(Order::RowMajor), arr1(&[0, 3, 1, 4, 2, 5]));
+    let c = f.ravel(Order::ColumnMajor);
+    assert_eq!(c.as_ptr(), ptr);
+    assert_eq!(c, arr1(&[0, 1, 2, 3, 4, 5]));
+
+    // non-contiguous arrays are copied
+    let mut b = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    b.islice(s![.., ..;2]);
+    assert_eq!(b.clone().ravel(Order::RowMajor), arr1(&[0, 2, 4, 6, 8, 10]));
+    assert_eq!(b.ravel(Order::ColumnMajor), arr1(&[0, 4, 8, 2, 6, 10]));
+
+    let e = Array::<f32, _>::zeros((0, 3));
+    assert_eq!(e.ravel(Order::ColumnMajor).len(), 0);
+}
+
+#[test]
+fn pad() {
+    use ndarray::PadMode;
+
+    let a = arr1(&[1, 2, 3]);
+    assert_eq!(a.pad(&[(2, 1)], PadMode::Constant(0)), arr1(&[0, 0, 1, 2, 3, 0]));
+    assert_eq!(a.pad(&[(2, 1)], PadMode::Edge), arr1(&[1, 1, 1, 2, 3, 3]));
+    assert_eq!(a.pad(&[(2, 1)], PadMode::Wrap), arr1(&[2, 3, 1, 2, 3, 1]));
+    // wrapping more than once around
+    assert_eq!(a.pad(&[(4, 4)], PadMode::Wrap),
+               arr1(&[3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1]));
+    assert_eq!(a.pad(&[(0, 0)], PadMode::Edge), a);
+
+    let b = arr2(&[[1, 2],
+                   [3, 4]]);
+    assert_eq!(b.t().pad(&[(1, 1), (1, 1)], PadMode::Edge),
+               arr2(&[[1, 1, 3, 3],
+                      [1, 1, 3, 3],
+                      [2, 2, 4, 4],
+                      [2, 2, 4, 4]]));
+    let d = b.view().into_dyn().pad(&[(0, 1), (1, 0)], PadMode::Constant(9));
+    assert_eq!(d, arr2(&[[9, 1, 2],
+                         [9, 3, 4],
+                         [9, 9, 9]]).into_dyn());
+
+    let e = Array::<i32, _>::zeros((0, 2));
+    assert_eq!(e.pad(&[(1, 0), (0, 0)], PadMode::Constant(5)), arr2(&[[5, 5]]));
+    assert_eq!(e.pad(&[(0, 0), (2, 1)], PadMode::Wrap).shape(), &[0, 5]);
+
+    // compare with padding element by element, in three dimensions
+    let c = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let c = c.slice(s![.., ..;-1, ..]);
+    let widths = [(3, 2), (0, 4), (1, 5)];
+    for mode in vec![PadMode::Edge, PadMode::Wrap] {
+        let p = c.pad(&widths, mode.clone());
+        assert_eq!(p.shape(), &[7, 7, 10]);
+        let map = |i: usize, w: usize, n: usize| match mode {
+            PadMode::Edge => ::std::cmp::min(i.saturating_sub(w), n - 1),
+            _ => (i + n * w - w) % n,
+        };
+        for ((i, j, k), &x) in p.indexed_iter() {
+            assert_eq!(x, c[(map(i, 3, 2), map(j, 0, 3), map(k, 1, 4))]);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn pad_empty_axis_edge() {
+    use ndarray::PadMode;
+    Array::<i32, _>::zeros((0, 2)).pad(&[(1, 0), (0, 0)], PadMode::Edge);
+}
+
+#[test]
+fn atleast_nd() {
+    use ndarray::{atleast_2d, atleast_3d};
+
+    let a = arr1(&[1, 2, 3]);
+    let v = atleast_2d(&a);
+    assert_eq!(v, arr2(&[[1, 2, 3]]));
+    assert_eq!(v.as_ptr(), a.as_ptr());
+    assert_eq!(atleast_3d(&a).dim(), (1, 1, 3));
+    assert_eq!(atleast_3d(&arr0(7)), arr3(&[[[7]]]));
+
+    // a matrix is unchanged, strides included
+    let b = arr2(&[[1, 2], [3, 4]]);
+    let bt = b.t();
+    assert_eq!(atleast_2d(&bt), bt);
+    let reversed = b.slice(s![.., ..;-1]);
+    assert_eq!(atleast_3d(&reversed), arr3(&[[[2, 1], [4, 3]]]));
+
+    let d = b.clone().into_dyn();
+    assert_eq!(atleast_2d(&d), b);
+}
+
+#[test]
+#[should_panic]
+fn atleast_2d_too_many_axes() {
+    ndarray::atleast_2d(&Array::<f32, _>::zeros((1, 1, 1)));
+}
+
+#[test]
+fn swap_rows_columns() {
+    let mut a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    a.swap_rows(2, 0);
+    assert_eq!(a.row(0), arr1(&[8, 9, 10, 11]));
+    assert_eq!(a.row(2), arr1(&[0, 1, 2, 3]));
+    a.swap_rows(1, 1);
+    assert_eq!(a.row(1), arr1(&[4, 5, 6, 7]));
+
+    // works through any strides
+    let mut t = a.view_mut().reversed_axes();
+    t.swap_columns(0, 1);
+    assert_eq!(a.row(0), arr1(&[4, 5, 6, 7]));
+    a.swap_columns(1, 3);
+    assert_eq!(a, arr2(&[[4, 7, 6, 5],
+                         [8, 11, 10, 9],
+                         [0, 3, 2, 1]]));
+
+    a.swap((0, 0), (2, 3));
+    assert_eq!(a[[0, 0]], 1);
+    assert_eq!(a[[2, 3]], 4);
+}
+
+#[test]
+#[should_panic]
+fn swap_rows_out_of_bounds() {
+    Array::<f32, _>::zeros((2, 2)).swap_rows(0, 2);
+}
+
+#[test]
+fn insert_axis() {
+    let a = Array::from_iter(0..6).into_shape((2, 3)).unwrap();
+    for ax in 0..3 {
+        let b = a.view().insert_axis(Axis(ax));
+        let mut shape = vec![2, 3];
+        shape.insert(ax, 1);
+        assert_eq!(b.shape(), &shape[..]);
+        assert!(b.is_standard_layout());
+        assert_eq!(b.into_subview(Axis(ax), 0), a);
+    }
+    let t = a.t().insert_axis(Axis(1));
+    assert_eq!(t.shape(), &[3, 1, 2]);
+    assert_eq!(t.subview(Axis(1), 0), a.t());
+
+    let d = a.clone().into_dyn().insert_axis(Axis(2));
+    assert_eq!(d.shape(), &[2, 3, 1]);
+    let r = RcArray::from_iter(0..4).into_shape((1, 1, 2, 2, 1, 1)).unwrap().insert_axis(Axis(6));
+    assert_eq!(r.shape(), &[1, 1, 2, 2, 1, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn insert_axis_out_of_bounds() {
+    arr1(&[1, 2]).insert_axis(Axis(2));
+}
+
+#[test]
+fn append_push_row() {
+    let mut a = Array2::<i32>::zeros((0, 3));
+    for i in 0..100 {
+        a.push_row(&arr1(&[i, i + 1, i + 2])).unwrap();
+    }
+    assert_eq!(a.dim(), (100, 3));
+    assert!(a.is_standard_layout());
+    assert_eq!(a.row(57), arr1(&[57, 58, 59]));
+    assert!(a.push_row(&arr1(&[1, 2])).is_err());
+    assert_eq!(a.dim(), (100, 3));
+
+    // non-standard layout and sliced arrays are copied first
+    let mut b = arr2(&[[1, 2], [3, 4]]).reversed_axes();
+    b.append(&arr2(&[[5, 6]])).unwrap();
+    assert_eq!(b, arr2(&[[1, 3], [2, 4], [5, 6]]));
+    let mut c = Array::from_iter(0..12).into_shape((4, 3)).unwrap();
+    c.islice(s![1..3, ..]);
+    c.append(&arr2(&[[0, 0, 0]]).view()).unwrap();
+    assert_eq!(c, arr2(&[[3, 4, 5], [6, 7, 8], [0, 0, 0]]));
+    c.append(&c.clone().slice(s![..;-1, ..])).unwrap();
+    assert_eq!(c.dim(), (6, 3));
+    assert_eq!(c.row(5), arr1(&[3, 4, 5]));
+
+    // higher dimensions, and appending nothing
+    let mut d = Array::from_iter(0..8).into_shape((2, 2, 2)).unwrap();
+    d.append(&Array::zeros((1, 2, 2))).unwrap();
+    d.append(&Array::zeros((0, 2, 2))).unwrap();
+    assert_eq!(d.shape(), &[3, 2, 2]);
+    let err = d.append(&Array::zeros((1, 2, 3))).unwrap_err();
+    assert_eq!(err.from_shape(), Some(&[1, 2, 3][..]));
+    assert_eq!(err.to_shape(), Some(&[3, 2, 2][..]));
+
+    let mut e = arr0(1);
+    assert!(e.append(&arr0(2)).is_err());
+}
+
+#[test]
+fn append_panicking_clone() {
+    use std::panic::{self, AssertUnwindSafe};
+
+    // cloning a negative value panics
+    #[derive(Debug, PartialEq)]
+    struct Checked(i32);
+    impl Clone for Checked {
+        fn clone(&self) -> Self {
+            assert!(self.0 >= 0);
+            Checked(self.0)
+        }
+    }
+    let mut a = Array::from_vec(vec![Checked(1), Checked(2)]).into_shape((1, 2)).unwrap();
+    let b = Array::from_vec(vec![Checked(3), Checked(4), Checked(5), Checked(-1)])
+        .into_shape((2, 2)).unwrap();
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| a.append(&b))).is_err());
+    // the array is unchanged, and can still grow
+    assert_eq!(a.shape(), &[1, 2]);
+    assert_eq!(a[[0, 1]], Checked(2));
+    a.append(&b.slice(s![..1, ..])).unwrap();
+    assert_eq!(a, arr2(&[[1, 2], [3, 4]]).mapv(Checked));
+}
+
 #[test]
 fn arithmetic_broadcast() {
     let mut a = arr2(&[[1., 2.], [3., 4.]]);
@@ -1086,3 +1549,128 @@ fn test_map_axis() {
     let answer2 = arr1(&[6, 15, 24, 33]);
     assert_eq!(c, answer2);
 }
+
+#[test]
+fn test_fold_axis() {
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    for ax in 0..3 {
+        let s = a.fold_axis(Axis(ax), 0, |&acc, &x| acc + x);
+        assert_eq!(s, a.sum(Axis(ax)));
+        let t = a.t().fold_axis(Axis(ax), 0, |&acc, &x| acc + x);
+        assert_eq!(t, a.t().sum(Axis(ax)));
+    }
+
+    // fold into a different type, visiting elements in order along the axis
+    let b = arr2(&[[1, 2],
+                   [3, 4]]);
+    let digits = b.fold_axis(Axis(0), String::new(), |s, x| format!("{}{}", s, x));
+    assert_eq!(digits, arr1(&["13".to_string(), "24".to_string()]));
+
+    let empty = Array2::<f32>::zeros((0, 3));
+    assert_eq!(empty.fold_axis(Axis(0), 1., |&acc, &x| acc * x), arr1(&[1., 1., 1.]));
+    assert_eq!(empty.fold_axis(Axis(1), 1., |&acc, &x| acc * x).dim(), 0);
+}
+
+#[test]
+fn all_any_axis() {
+    let a = arr2(&[[true, true, false],
+                   [true, false, false]]);
+    assert_eq!(a.all_axis(Axis(0), |&x| x), arr1(&[true, false, false]));
+    assert_eq!(a.any_axis(Axis(0), |&x| x), arr1(&[true, true, false]));
+    assert_eq!(a.all_axis(Axis(1), |&x| x), arr1(&[false, false]));
+    assert_eq!(a.any_axis(Axis(1), |&x| x), arr1(&[true, true]));
+    assert_eq!(a.t().any_axis(Axis(0), |&x| !x), arr1(&[true, true]));
+
+    let b = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    for ax in 0..3 {
+        let all = b.all_axis(Axis(ax), |&x| x % 3 != 0);
+        let any = b.any_axis(Axis(ax), |&x| x % 7 == 0);
+        assert_eq!(all, b.map_axis(Axis(ax), |lane| lane.iter().all(|&x| x % 3 != 0)));
+        assert_eq!(any, b.map_axis(Axis(ax), |lane| lane.iter().any(|&x| x % 7 == 0)));
+    }
+
+    let empty = Array2::<i32>::zeros((0, 2));
+    assert_eq!(empty.all_axis(Axis(0), |_| false), arr1(&[true, true]));
+    assert_eq!(empty.any_axis(Axis(0), |_| true), arr1(&[false, false]));
+}
+
+#[test]
+fn extend_1d() {
+    let mut a: Array1<i32> = (0..4).collect();
+    a.extend(4..6);
+    assert_eq!(a, arr1(&[0, 1, 2, 3, 4, 5]));
+
+    let mut b = arr1(&[0, 1, 2, 3, 4, 5]);
+    b.islice(s![..;-2]);
+    b.extend(vec![10, 11]);
+    assert_eq!(b, arr1(&[5, 3, 1, 10, 11]));
+}
+
+#[test]
+fn apply_along_axis() {
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    for axis in 0..3 {
+        let b = a.apply_along_axis(Axis(axis), |lane| {
+            // each lane repeated twice
+            lane.iter().chain(lane.iter()).cloned().collect()
+        });
+        let mut shape = a.shape().to_vec();
+        shape[axis] *= 2;
+        assert_eq!(b.shape(), &shape[..]);
+        for (ix, &x) in b.indexed_iter() {
+            let mut ix = [ix.0, ix.1, ix.2];
+            ix[axis] %= a.shape()[axis];
+            assert_eq!(x, a[ix]);
+        }
+    }
+
+    let empty = Array2::<f32>::zeros((0, 3));
+    let c = empty.apply_along_axis(Axis(1), |lane| lane.to_owned());
+    assert_eq!(c.shape(), &[0, 0]);
+}
+
+#[test]
+#[should_panic]
+fn apply_along_axis_ragged() {
+    let a = Array2::<usize>::zeros((3, 4));
+    let mut n = 0;
+    a.apply_along_axis(Axis(0), |lane| {
+        n += 1;
+        Array::from_elem(lane.len() + n, 0)
+    });
+}
+
+#[test]
+fn elementwise_comparisons() {
+    let a = arr2(&[[1., 2., 3.],
+                   [4., 5., 6.]]);
+    let b = arr2(&[[1., 0., 3.],
+                   [0., 5., 0.]]);
+    assert_eq!(a.eq_elem(&b), arr2(&[[true, false, true],
+                                     [false, true, false]]));
+    assert_eq!(a.ne_elem(&b), a.eq_elem(&b).map(|x| !x));
+    assert_eq!(a.gt(&b), a.ne_elem(&b));
+    assert_eq!(a.ge(&b), Array::from_elem((2, 3), true));
+    assert_eq!(b.lt(&a), a.gt(&b));
+
+    // broadcasting
+    let c = arr1(&[2., 5., 4.]);
+    assert_eq!(a.le(&c), arr2(&[[true, true, true],
+                                [false, true, false]]));
+    assert_eq!(a.t().lt(&arr2(&[[3.], [3.], [5.]])),
+               arr2(&[[true, false], [true, false], [true, false]]));
+
+    assert_eq!(a.le_scalar(3.), a.map(|&x| x <= 3.));
+    assert_eq!(a.ge_scalar(3.), a.map(|&x| x >= 3.));
+    assert_eq!(a.lt_scalar(3.).iter().filter(|&&x| x).count(), 2);
+    assert_eq!(a.eq_scalar(4.)[(1, 0)], true);
+    assert_eq!(a.ne_scalar(4.)[(1, 0)], false);
+}
+
+#[test]
+#[should_panic]
+fn elementwise_comparison_shape_mismatch() {
+    let a = Array2::<f32>::zeros((2, 3));
+    let b = Array2::<f32>::zeros((3, 2));
+    a.gt(&b);
+}
@@ -0,0 +1,85 @@
+
+#[macro_use(azip, s)]
+extern crate ndarray;
+extern crate itertools;
+
+use ndarray::prelude::*;
+use ndarray::Zip;
+
+use itertools::{assert_equal, cloned};
+
+#[test]
+fn test_azip1() {
+    let mut a = Array::zeros(62);
+    let mut x = 0;
+    azip!(mut a in { *a = x; x += 1; });
+    assert_equal(cloned(&a), 0..a.len());
+}
+
+#[test]
+fn test_azip2() {
+    let mut a = Array::zeros((5, 7));
+    let b = Array::from_shape_fn(a.dim(), |(i, j)| 1. / (i + 2 * j) as f32);
+    azip!(mut a, b in { *a = b; });
+    assert_eq!(a, b);
+}
+
+#[test]
+fn test_azip2_3() {
+    let mut b = Array::from_shape_fn((5, 10, 3), |(i, j, k)| 1. / (i + 2 * j + 3 * k) as f32);
+    let mut c = Array::from_shape_fn((5, 10, 3), |(i, j, k)| i as f32 + j as f32 + k as f32);
+    let a = b.clone();
+    azip!(mut b, mut c in { *b = *c; *c = 0.; });
+    assert!(a != b);
+    assert!(c.iter().all(|&x| x == 0.));
+}
+
+#[test]
+fn test_azip_strided() {
+    let a = Array::from_iter(0..24).into_shape((4, 6)).unwrap();
+    let b = Array::from_iter(0..24).into_shape((6, 4)).unwrap();
+    let mut c = Array::zeros((3, 4));
+    {
+        let a = a.slice(s![1.., 1..5]);
+        let bt = b.t();
+        let b = bt.slice(s![1..;-1, ..4]);
+        azip!(mut c, a, b in { *c = a - b });
+    }
+    let a = a.slice(s![1.., 1..5]).to_owned();
+    let b = b.t().slice(s![1..;-1, ..4]).to_owned();
+    assert_eq!(c, &a - &b);
+}
+
+#[test]
+fn test_zip_views() {
+    let mut a = Array::zeros((4, 3));
+    let b = Array::<f64, _>::from_elem((4, 3), 1.);
+    Zip::from(&mut a).and(&b).and(&b.view()).apply(|a, &b, &c| *a = b + c);
+    assert_eq!(a, Array::from_elem((4, 3), 2.));
+}
+
+#[test]
+#[should_panic]
+fn test_zip_shape_mismatch() {
+    let mut a = Array::<f64, _>::zeros((4, 3));
+    let b = Array::<f64, _>::from_elem((3, 4), 1.);
+    Zip::from(&mut a).and(&b).apply(|a, &b| *a = b);
+}
+
+#[test]
+fn test_zip_0d() {
+    let mut a = arr0(1);
+    let b = arr0(2);
+    azip!(mut a, b in { *a += b });
+    assert_eq!(a, arr0(3));
+}
+
+#[test]
+fn test_azip6() {
+    let mut a = Array::zeros((3, 3));
+    let b = Array::from_elem((3, 3), 1);
+    let c = b.t();
+    let (d, e, f) = (&b * 2, &b * 3, &b * 4);
+    azip!(mut a, b, c, d, e, f in { *a = b + c + d + e + f });
+    assert_eq!(a, Array::from_elem((3, 3), 11));
+}
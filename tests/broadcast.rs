@@ -83,3 +83,41 @@ fn test_broadcast_1d() {
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn broadcast_several_arrays() {
+    use ndarray::{broadcast_arrays, ErrorKind};
+
+    let a = Array::from_iter(0..3).into_shape((1, 3)).unwrap();
+    let b = Array::from_iter(0..2).into_shape((2, 1)).unwrap();
+    let c = arr2(&[[7]]);
+    let views = broadcast_arrays(&[a.view(), b.view(), c.view()]).unwrap();
+    for v in &views {
+        assert_eq!(v.shape(), &[2, 3]);
+    }
+    assert_eq!(views[0], arr2(&[[0, 1, 2], [0, 1, 2]]));
+    assert_eq!(views[1], arr2(&[[0, 0, 0], [1, 1, 1]]));
+    assert_eq!(views[2], Array::from_elem((2, 3), 7));
+    assert_eq!(views[1].strides(), &[1, 0]);
+
+    // views outlive the slice of arguments
+    let views = {
+        let args = vec![a.view(), b.view()];
+        broadcast_arrays(&args).unwrap()
+    };
+    assert_eq!(views.len(), 2);
+
+    // with dynamic dimensions, leading axes are added
+    let d = arr1(&[1, 2, 3]).into_dyn();
+    let e = Array::from_iter(0..6).into_shape((2, 1, 3)).unwrap().into_dyn();
+    let views = broadcast_arrays(&[d.view(), e.view()]).unwrap();
+    assert_eq!(views[0].shape(), &[2, 1, 3]);
+    assert_eq!(views[1], e);
+
+    let err = broadcast_arrays(&[a.view(), b.view(), a.t()]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.from_shape(), Some(&[3, 1][..]));
+    assert_eq!(err.to_shape(), Some(&[2, 3][..]));
+
+    assert!(broadcast_arrays::<f32, Ix2>(&[]).unwrap().is_empty());
+}
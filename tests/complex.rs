@@ -24,3 +24,24 @@ fn complex_mat_mul()
     assert_eq!(r, a);
     assert_eq!(a.mean(Axis(0)), arr1(&[c(1.5, 1.), c(2.5, 0.)]));
 }
+
+#[test]
+fn complex_elementwise()
+{
+    let a = arr2(&[[c(3., 4.), c(0., -2.)], [c(-1., 0.), c(1., 1.)]]);
+    let t = a.t();
+    assert_eq!(t.re(), arr2(&[[3., -1.], [0., 1.]]));
+    assert_eq!(t.im(), arr2(&[[4., 0.], [-2., 1.]]));
+    assert_eq!(t.conj(), t.map(|z| z.conj()));
+    assert_eq!(a.norm(), arr2(&[[5., 2.], [1., 2f64.sqrt()]]));
+    assert_eq!(a.arg().row(1), arr1(&[std::f64::consts::PI, std::f64::consts::FRAC_PI_4]));
+    // |z|^2 == z z*
+    assert_eq!((&a * &a.conj()).re(), a.norm().mapv(|x| x * x).mapv(f64::round));
+
+    // arithmetic with complex scalars and on the left hand side
+    let mut b = a.clone();
+    b.iconj();
+    b *= c(0., 1.);
+    assert_eq!(b, a.conj() * c(0., 1.));
+    assert_eq!(c(1., 0.) - &b, (&b * -1.).map(|z| z + 1.));
+}
@@ -2,7 +2,7 @@
 extern crate ndarray;
 extern crate itertools;
 
-use ndarray::{Array0, Array2};
+use ndarray::{Array0, Array2, Array3};
 use ndarray::RcArray;
 use ndarray::{Ix, Si, S};
 use ndarray::{
@@ -69,6 +69,27 @@ fn indexed()
     }
 }
 
+#[test]
+fn indexed_strided()
+{
+    let a = RcArray::from_iter(0..24).reshape((4, 6));
+    // indices are relative to the sliced view
+    let v = a.slice(s![1..;2, ..;-3]);
+    for ((i, j), elt) in v.indexed_iter() {
+        assert_eq!(*elt, v[[i, j]]);
+        assert_eq!(*elt, a[[1 + 2 * i, 5 - 3 * j]]);
+    }
+    assert_eq!(v.indexed_iter().len(), v.len());
+
+    let mut b = Array2::zeros((3, 4));
+    for ((i, j), elt) in b.slice_mut(s![.., 1..]).indexed_iter_mut() {
+        *elt = 10 * i + j;
+    }
+    assert_eq!(b, arr2(&[[0,  0,  1,  2],
+                         [0, 10, 11, 12],
+                         [0, 20, 21, 22]]));
+}
+
 
 fn assert_slice_correct<A, S, D>(v: &ArrayBase<S, D>)
     where S: Data<Elem=A>,
@@ -154,6 +175,41 @@ fn inner_iter_corner_cases() {
                  vec![aview1(&[]); 3]);
 }
 
+#[test]
+fn lanes() {
+    let a = RcArray::from_iter(0..12);
+    let a = a.reshape((2, 3, 2));
+    // [[[0, 1],
+    //   [2, 3],
+    //   [4, 5]],
+    //  [[6, 7],
+    //   [8, 9],
+    //    ...
+    assert_equal(a.lanes(Axis(2)), a.inner_iter());
+    assert_equal(a.lanes(Axis(1)),
+                 vec![aview1(&[0, 2, 4]), aview1(&[1, 3, 5]),
+                      aview1(&[6, 8, 10]), aview1(&[7, 9, 11])]);
+    assert_equal(a.lanes(Axis(0)),
+                 vec![aview1(&[0, 6]), aview1(&[1, 7]), aview1(&[2, 8]),
+                      aview1(&[3, 9]), aview1(&[4, 10]), aview1(&[5, 11])]);
+    assert_eq!(a.lanes(Axis(0)).len(), 6);
+
+    let a2 = RcArray::<i32, _>::zeros((0, 3));
+    assert_equal(a2.lanes(Axis(0)),
+                 vec![aview1(&[]); 3]);
+}
+
+#[test]
+fn lanes_mut() {
+    let mut a = Array2::<f64>::from_elem((3, 4), 1.);
+    for (i, mut lane) in enumerate(a.lanes_mut(Axis(0))) {
+        lane *= i as f64;
+    }
+    assert_equal(a.lanes(Axis(0)),
+                 vec![aview1(&[0., 0., 0.]), aview1(&[1., 1., 1.]),
+                      aview1(&[2., 2., 2.]), aview1(&[3., 3., 3.])]);
+}
+
 #[test]
 fn inner_iter_size_hint() {
     // Check that the size hint is correctly computed
@@ -236,6 +292,15 @@ fn axis_iter() {
                  vec![a.subview(Axis(1), 0),
                       a.subview(Axis(1), 1),
                       a.subview(Axis(1), 2)]);
+
+    // the innermost axis, traversed from both ends
+    let mut it = a.axis_iter(Axis(2));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next_back().unwrap(), a.subview(Axis(2), 1));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next().unwrap(), a.subview(Axis(2), 0));
+    assert!(it.next().is_none());
+    assert!(it.next_back().is_none());
 }
 
 #[test]
@@ -479,6 +544,8 @@ fn iterators_are_send_sync() {
     _send_sync(&a.axis_iter_mut(Axis(1)));
     _send_sync(&a.axis_chunks_iter(Axis(1), 1));
     _send_sync(&a.axis_chunks_iter_mut(Axis(1), 1));
+    _send_sync(&a.blocks((1, 1, 1)));
+    _send_sync(&a.blocks_mut((1, 1, 1)));
     _send_sync(&indices(a.dim()));
 }
 
@@ -494,3 +561,162 @@ fn test_fold() {
     a += 1;
     assert_eq!(a.iter().fold(0, |acc, &x| acc + x), 1);
 }
+
+#[test]
+fn into_iter() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_equal(a.clone().into_iter(), a.iter().cloned());
+    assert_equal(a.clone().reversed_axes().into_iter(), vec![1, 4, 2, 5, 3, 6]);
+
+    let mut a = Array2::from_shape_fn((4, 5), |(i, j)| vec![i, j]);
+    a.islice(s![1..;2, ..;-2]);
+    let elts: Vec<_> = a.iter().cloned().collect();
+    let mut iter = a.into_iter();
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some(elts[0].clone()));
+    assert_equal(iter, elts[1..].iter().cloned());
+
+    let a = RcArray::from_iter(0..10).reshape((2, 5));
+    let b = a.clone();
+    assert_equal(a.into_iter(), b.iter().cloned());
+    assert_equal(b.into_iter(), 0..10);
+}
+
+#[test]
+fn into_iter_drops() {
+    use std::cell::{Cell, RefCell};
+
+    struct Counted<'a>(&'a Cell<usize>);
+    impl<'a> Drop for Counted<'a> {
+        fn drop(&mut self) { self.0.set(self.0.get() + 1); }
+    }
+
+    let count = Cell::new(0);
+    let mut a = Array2::from_shape_fn((3, 4), |_| Counted(&count));
+    a.islice(s![..2, 1..]);
+    let mut iter = a.into_iter();
+    assert_eq!(count.get(), 6);
+    drop(iter.next());
+    assert_eq!(count.get(), 7);
+    drop(iter);
+    assert_eq!(count.get(), 12);
+
+    // the elements outside the array are dropped once, whatever its layout
+    struct Tracked<'a>(usize, &'a RefCell<Vec<usize>>);
+    impl<'a> Drop for Tracked<'a> {
+        fn drop(&mut self) { self.1.borrow_mut().push(self.0); }
+    }
+
+    let dropped = RefCell::new(Vec::new());
+    let mut a = Array3::from_shape_fn((3, 4, 5), |(i, j, k)| {
+        Tracked(20 * i + 5 * j + k, &dropped)
+    });
+    a.islice(s![..;-2, 1..3, ..;2]);
+    a.swap_axes(0, 2);
+    let kept: Vec<_> = a.iter().map(|t| t.0).collect();
+    let iter = a.into_iter();
+    let mut hidden = dropped.borrow().clone();
+    hidden.sort();
+    assert_eq!(hidden, (0..60).filter(|x| !kept.contains(x)).collect::<Vec<_>>());
+    drop(iter);
+    let mut all = dropped.borrow().clone();
+    all.sort();
+    assert_eq!(all, (0..60).collect::<Vec<_>>());
+}
+
+#[test]
+fn double_ended_strided() {
+    let mut a = RcArray::from_iter(0..24).reshape((2, 3, 4));
+    a.islice(&[S, Si(0, None, -1), Si(1, None, 2)]);
+    let v: Vec<_> = a.iter().cloned().collect();
+    assert_equal(a.iter().rev(), v.iter().rev());
+    assert_equal(a.iter_mut().rev().map(|x| *x), v.iter().rev().cloned());
+    assert_eq!(a.iter().rposition(|&x| x == 9), v.iter().rposition(|&x| x == 9));
+
+    // alternate between both ends
+    let mut it = a.iter();
+    let mut w = Vec::new();
+    while let Some(&x) = it.next() {
+        w.push(x);
+        if let Some(&y) = it.next_back() {
+            w.push(y);
+        }
+    }
+    let mut expected = Vec::new();
+    for i in 0..v.len() / 2 {
+        expected.push(v[i]);
+        expected.push(v[v.len() - 1 - i]);
+    }
+    assert_eq!(w, expected);
+
+    // fold after consuming from the back
+    let mut it = a.iter();
+    it.next_back();
+    it.next_back();
+    assert_eq!(it.len(), v.len() - 2);
+    assert_eq!(it.fold(0, |acc, &x| acc + x), v[..v.len() - 2].iter().sum::<i32>());
+}
+
+#[test]
+fn indices_of_shape() {
+    use ndarray::indices_of;
+
+    let a = Array2::<f32>::zeros((3, 4));
+    let b = a.t();
+    assert_eq!(indices_of(&a).len(), 12);
+    assert_equal(indices_of(&b), indices((4, 3)));
+    for (i, j) in indices_of(&b) {
+        assert_eq!(b[(i, j)], a[(j, i)]);
+    }
+
+    let c = Array2::<f32>::zeros((0, 4));
+    assert_eq!(indices_of(&c).next(), None);
+}
+
+#[test]
+fn iter_f() {
+    use ndarray::ShapeBuilder;
+
+    let a = RcArray::from_iter(0..24).reshape((2, 3, 4));
+    let mut v = Vec::new();
+    for k in 0..4 {
+        for j in 0..3 {
+            for i in 0..2 {
+                v.push(a[[i, j, k]]);
+            }
+        }
+    }
+    assert_equal(a.iter_f().cloned(), v.iter().cloned());
+    assert_equal(a.t().iter(), a.iter_f());
+
+    // column major memory layout is traversed in memory order
+    let mut b = Array2::from_shape_vec((3, 4).f(), (0..12).collect()).unwrap();
+    assert_equal(b.iter_f().cloned(), 0..12);
+    for (i, x) in b.iter_mut_f().enumerate() {
+        *x -= i;
+    }
+    assert!(b.iter().all(|&x| x == 0));
+}
+
+#[test]
+fn blocks() {
+    let mut a = RcArray::from_iter(0..60).reshape((3, 4, 5));
+    a.swap_axes(0, 2);
+    let b = a.clone();
+    let mut count = 0;
+    for (n, mut block) in a.blocks_mut((2, 3, 2)).enumerate() {
+        assert!(block.shape().iter().zip(&[2, 3, 2]).all(|(x, y)| x <= y));
+        count += block.len();
+        block.fill(n);
+    }
+    assert_eq!(count, 60);
+    // blocks are laid out on a 3 × 2 × 2 grid
+    for ((i, j, k), &x) in a.indexed_iter() {
+        assert_eq!(x, (i / 2) * 4 + (j / 3) * 2 + k / 2);
+    }
+    let elts: Vec<_> = b.blocks((5, 4, 3)).flat_map(|v| v.iter().cloned().collect::<Vec<_>>()).collect();
+    assert_equal(elts, b.iter().cloned());
+
+    let empty = Array2::<f32>::zeros((0, 3));
+    assert_eq!(empty.blocks((2, 2)).len(), 0);
+}
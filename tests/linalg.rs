@@ -0,0 +1,1056 @@
+
+#[macro_use(s)]
+extern crate ndarray;
+extern crate num_complex;
+
+use ndarray::prelude::*;
+use ndarray::{ComplexField, Data};
+use ndarray::linalg::{
+    apply_givens, apply_householder_left, apply_householder_right, cholesky,
+    cholesky_inplace, cond, det, eig, eigh, eigvals, eigvalsh, expm, inverse,
+    least_squares, lu, lu_inplace, make_givens, make_householder, matrix_power,
+    norm, pinv, polyfit, polyval, qr, qr_inplace, rank, slogdet, solve,
+    solve_tridiagonal, subst_bw, subst_fw, svd, svd_values, vander,
+    Banded, ConvergenceError, Diag, Lower, Norm, NotPositiveDefiniteError,
+    SingularError, Symmetric, Upper,
+};
+use num_complex::Complex;
+
+/// A reproducible, well-scrambled `m × n` test matrix.
+fn scrambled(m: usize, n: usize) -> Array2<f64> {
+    Array::from_iter((0..m * n).map(|i| ((i * 37 + 11) % 29) as f64 - 14.))
+        .into_shape((m, n)).unwrap()
+}
+
+/// A reproducible, well conditioned `n × n` test matrix.
+fn nonsingular(n: usize) -> Array2<f64> {
+    scrambled(n, n) + &(Array2::eye(n) * 20.)
+}
+
+/// Scrambled test matrices of many shapes: square, tall, wide and empty.
+fn test_matrices() -> Vec<Array2<f64>> {
+    [(1, 1), (4, 4), (7, 7), (6, 3), (3, 6), (1, 4), (4, 1), (0, 0), (0, 3), (3, 0)]
+        .iter().map(|&(m, n)| scrambled(m, n)).collect()
+}
+
+/// Scrambled square test matrices of the sizes zero to eight.
+fn square_matrices() -> Vec<Array2<f64>> {
+    (0..9).map(|n| scrambled(n, n)).collect()
+}
+
+/// Assert that `a` and `b` have the same shape, and that their elements
+/// differ by at most `tol`.
+fn assert_close<A, S1, S2, D>(a: &ArrayBase<S1, D>, b: &ArrayBase<S2, D>, tol: f64)
+    where A: ComplexField<Real=f64>,
+          S1: Data<Elem=A>,
+          S2: Data<Elem=A>,
+          D: Dimension,
+{
+    assert_eq!(a.shape(), b.shape());
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        assert!((x - y).modulus() <= tol, "{:?} and {:?} differ by more than {}", x, y, tol);
+    }
+}
+
+#[test]
+fn lu_reconstruct() {
+    for a in &test_matrices() {
+        let (m, n) = a.dim();
+        let f = lu(a);
+        let (l, u, p) = (f.l(), f.u(), f.p());
+        let k = ::std::cmp::min(m, n);
+        assert_eq!(l.dim(), (m, k));
+        assert_eq!(u.dim(), (k, n));
+        assert_close(&p.dot(a), &l.dot(&u), 1e-10);
+        for ((i, j), &x) in l.indexed_iter() {
+            if i < j { assert_eq!(x, 0.); }
+            if i == j { assert_eq!(x, 1.); }
+            // partial pivoting bounds the multipliers
+            assert!(x.abs() <= 1.);
+        }
+        for ((i, j), &x) in u.indexed_iter() {
+            if i > j { assert_eq!(x, 0.); }
+        }
+        let mut perm = f.perm().to_vec();
+        perm.sort();
+        assert_eq!(perm, (0..m).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn lu_strided_and_singular() {
+    let a = scrambled(6, 6);
+    let v = a.slice(s![..;-2, ..;2]);
+    let f = lu(&v);
+    assert_close(&f.p().dot(&v), &f.l().dot(&f.u()), 1e-10);
+    assert!(!f.is_singular());
+
+    let s = arr2(&[[1., 2., 3.],
+                   [2., 4., 6.],
+                   [1., 0., 1.]]);
+    let f = lu(&s);
+    assert!(f.is_singular());
+    assert_close(&f.p().dot(&s), &f.l().dot(&f.u()), 1e-12);
+    assert!(lu(&Array2::<f32>::zeros((3, 3))).is_singular());
+}
+
+#[test]
+fn determinant() {
+    let a = arr2(&[[2., -1., 0.],
+                   [1., 3., 4.],
+                   [0., 5., -2.]]);
+    // cofactor expansion along the first row
+    let expected: f64 = 2. * (3. * -2. - 4. * 5.) + 1. * (1. * -2. - 4. * 0.);
+    assert!((det(&a) - expected).abs() < 1e-12);
+    assert!((det(&a.t()) - expected).abs() < 1e-12);
+    let (sign, logabsdet) = slogdet(&a);
+    assert_eq!(sign, -1.);
+    assert!((logabsdet - expected.abs().ln()).abs() < 1e-12);
+
+    // swapping two rows flips the sign
+    let swapped = a.select(Axis(0), &[1, 0, 2]);
+    assert!((det(&swapped) + expected).abs() < 1e-12);
+    assert_eq!(det(&Array2::<f64>::eye(5)), 1.);
+    assert_eq!(det(&Array2::<f64>::zeros((0, 0))), 1.);
+
+    let singular = arr2(&[[1., 2., 3.],
+                          [2., 4., 6.],
+                          [1., 0., 1.]]);
+    assert_eq!(det(&singular), 0.);
+    assert_eq!(slogdet(&singular), (0., ::std::f64::NEG_INFINITY));
+
+    // the determinant is the product of the eigenvalues
+    let b = scrambled(6, 6);
+    let b = &b + &b.t() + &(Array2::eye(6) * 3.);
+    let prod = eigvalsh(&b).unwrap().fold(1., |acc, &x| acc * x);
+    assert!((det(&b) - prod).abs() < 1e-9 * prod.abs());
+    let (sign, logabsdet) = slogdet(&b);
+    assert!((sign * logabsdet.exp() - prod).abs() < 1e-9 * prod.abs());
+}
+
+#[test]
+#[should_panic]
+fn determinant_not_square() {
+    det(&Array2::<f64>::zeros((2, 3)));
+}
+
+#[test]
+fn matrix_inverse() {
+    for n in 1..6 {
+        let a = nonsingular(n);
+        let inv = inverse(&a).unwrap();
+        assert_close(&a.dot(&inv), &Array2::eye(n), 1e-12);
+        assert_close(&inv.dot(&a), &Array2::eye(n), 1e-12);
+        // inverting a transposed view gives the transposed inverse
+        assert_close(&inverse(&a.t()).unwrap(), &inv.t(), 1e-12);
+    }
+    assert_eq!(inverse(&Array2::<f64>::zeros((0, 0))).unwrap().dim(), (0, 0));
+    // needs pivoting
+    let p = arr2(&[[0., 1.],
+                   [1., 0.]]);
+    assert_eq!(inverse(&p).unwrap(), p);
+
+    let singular = arr2(&[[1., 2., 3.],
+                          [2., 4., 6.],
+                          [1., 0., 1.]]);
+    assert_eq!(inverse(&singular), Err(SingularError));
+    assert_eq!(inverse(&Array2::<f32>::zeros((2, 2))), Err(SingularError));
+}
+
+#[test]
+#[should_panic]
+fn matrix_inverse_not_square() {
+    let _ = inverse(&Array2::<f64>::zeros((3, 2)));
+}
+
+#[test]
+fn inplace_factorizations() {
+    let a = scrambled(7, 5);
+    // into a strided view of a larger buffer
+    let mut buf = Array2::zeros((7, 10));
+    {
+        let mut packed = buf.slice_mut(s![.., ..;2]);
+        packed.assign(&a);
+        let perm = lu_inplace(&mut packed);
+        let f = lu(&a);
+        assert_eq!(&perm[..], f.perm());
+        assert_eq!(packed, f.packed());
+    }
+
+    let mut packed = a.clone();
+    let tau = qr_inplace(&mut packed);
+    assert_eq!(tau.len(), 5);
+    let r = qr(&a).r();
+    for ((i, j), &x) in r.indexed_iter() {
+        if i <= j { assert_eq!(packed[[i, j]], x); }
+    }
+
+    let b = scrambled(6, 6);
+    let spd = b.t().dot(&b) + &Array2::eye(6);
+    let mut l = spd.clone();
+    cholesky_inplace(&mut l).unwrap();
+    assert_eq!(l, cholesky(&spd).unwrap().l());
+    let mut indefinite = -spd;
+    assert_eq!(cholesky_inplace(&mut indefinite), Err(NotPositiveDefiniteError));
+}
+
+#[test]
+fn cholesky_real() {
+    for b in &square_matrices() {
+        let n = b.rows();
+        let a = b.t().dot(b) + &Array2::eye(n);
+        let f = cholesky(&a).unwrap();
+        let l = f.l();
+        assert_close(&l.dot(&l.t()), &a, 1e-9);
+        for ((i, j), &x) in l.indexed_iter() {
+            if i < j { assert_eq!(x, 0.); }
+            if i == j { assert!(x > 0.); }
+        }
+        // only the lower triangle is read
+        let mut lower = a.clone();
+        for i in 0..n {
+            for j in i + 1..n {
+                lower[[i, j]] = 0.;
+            }
+        }
+        assert_eq!(cholesky(&lower).unwrap().l(), l);
+        // factor once, solve many
+        let x = scrambled(n, 2);
+        assert_close(&f.solve(&a.dot(&x)), &x, 1e-9);
+        assert_close(&f.solve(&a.dot(&x.column(1))), &x.column(1), 1e-9);
+    }
+    let indefinite = arr2(&[[1., 2.],
+                            [2., 1.]]);
+    assert_eq!(cholesky(&indefinite).unwrap_err(), NotPositiveDefiniteError);
+    assert_eq!(cholesky(&Array2::<f32>::zeros((2, 2))).unwrap_err(), NotPositiveDefiniteError);
+}
+
+#[test]
+fn cholesky_hermitian() {
+    let n = 5;
+    let re = scrambled(n, n);
+    let im = scrambled(n, n).reversed_axes() * 0.5;
+    let b = Array::from_iter(re.iter().zip(im.iter()).map(|(&x, &y)| Complex::new(x, y)))
+        .into_shape((n, n)).unwrap();
+    let a = b.t().conj().dot(&b) + &Array2::eye(n);
+    let f = cholesky(&a).unwrap();
+    let l = f.l().to_owned();
+    let lh = l.t().conj();
+    assert_close(&l.dot(&lh), &a, 1e-9);
+    for i in 0..n {
+        assert_eq!(l[[i, i]].im, 0.);
+        assert!(l[[i, i]].re > 0.);
+    }
+
+    let x = Array::from_iter((0..n).map(|i| Complex::new(i as f64, 1. - i as f64)));
+    let rhs = a.dot(&x);
+    let y = subst_fw(&l, &rhs, Diag::NonUnit).unwrap();
+    let y = subst_bw(&lh, &y, Diag::NonUnit).unwrap();
+    assert_close(&y, &x, 1e-9);
+    assert_close(&f.solve(&rhs), &x, 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn cholesky_not_square() {
+    let _ = cholesky(&Array2::<f64>::zeros((3, 2)));
+}
+
+#[test]
+fn triangular_substitution() {
+    let a = nonsingular(6);
+    let x = Array::linspace(-1., 1., 6);
+    let mut l = a.clone();
+    let mut u = a.clone();
+    for i in 0..6 {
+        for j in 0..6 {
+            if i < j { l[[i, j]] = 0.; }
+            if i > j { u[[i, j]] = 0.; }
+        }
+    }
+    // the other triangle is not read
+    assert_close(&subst_fw(&a, &l.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-12);
+    assert_close(&subst_bw(&a, &u.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-12);
+    assert!(subst_fw(&a.t(), &l.t().dot(&x), Diag::NonUnit).is_ok());
+
+    l[[3, 3]] = 0.;
+    assert_eq!(subst_fw(&l, &x, Diag::NonUnit), Err(SingularError));
+    assert_eq!(subst_bw(&l.t(), &x, Diag::NonUnit), Err(SingularError));
+}
+
+#[test]
+fn triangular_substitution_matrix() {
+    // several blocks of rows, and a right hand side with several columns
+    let n = 150;
+    let a = Array::from_iter((0..n * n).map(|i| ((i * 37 + 11) % 29) as f64 / 29. - 0.5))
+        .into_shape((n, n)).unwrap() + &(Array2::eye(n) * 2.);
+    let x = scrambled(n, 3);
+    let mut l = a.clone();
+    let mut u = a.clone();
+    let mut unit_l = a.clone();
+    for i in 0..n {
+        for j in 0..n {
+            if i < j { l[[i, j]] = 0.; unit_l[[i, j]] = 0.; }
+            if i > j { u[[i, j]] = 0.; }
+        }
+        unit_l[[i, i]] = 1.;
+    }
+    assert_close(&subst_fw(&a, &l.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-9);
+    assert_close(&subst_bw(&a, &u.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-9);
+    assert_close(&subst_fw(&a, &unit_l.dot(&x), Diag::Unit).unwrap(), &x, 1e-9);
+    let unit_u = unit_l.t();
+    assert_close(&subst_bw(&a.t(), &unit_u.dot(&x), Diag::Unit).unwrap(), &x, 1e-9);
+
+    // matches solving column by column
+    let b = scrambled(n, 2);
+    let y = subst_fw(&a, &b, Diag::NonUnit).unwrap();
+    for j in 0..2 {
+        let col = subst_fw(&a, &b.column(j), Diag::NonUnit).unwrap();
+        assert_close(&col, &y.column(j), 1e-12);
+    }
+
+    // a zero on the diagonal is singular, unless it is ignored
+    l[[100, 100]] = 0.;
+    assert_eq!(subst_fw(&l, &x, Diag::NonUnit), Err(SingularError));
+    assert!(subst_fw(&l, &x, Diag::Unit).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn triangular_substitution_wrong_length() {
+    let _ = subst_fw(&Array2::<f64>::eye(3), &Array1::zeros(2), Diag::NonUnit);
+}
+
+#[test]
+fn triangular_types() {
+    let n = 6;
+    let a = nonsingular(n);
+    let x = scrambled(n, 2);
+    let lower = Lower::new(a.view());
+    let upper = Upper::new(a.view());
+    let (l, u) = (lower.to_dense(), upper.to_dense());
+    let mut sum = &l + &u;
+    sum.diag_mut().assign(&a.diag());
+    assert_eq!(sum, a);
+    assert_close(&lower.dot(&x), &l.dot(&x), 1e-12);
+    assert_close(&upper.dot(&x.column(1)), &u.dot(&x.column(1)), 1e-12);
+    assert_close(&lower.solve(&l.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-12);
+    assert_close(&upper.solve(&u.dot(&x), Diag::NonUnit).unwrap(), &x, 1e-12);
+    assert_eq!(lower.dim(), n);
+    assert_eq!(upper.into_inner(), a.view());
+
+    let singular = Upper::new(Array2::<f64>::zeros((2, 2)));
+    assert_eq!(singular.solve(&arr1(&[1., 1.]), Diag::NonUnit), Err(SingularError));
+    assert_eq!(singular.solve(&arr1(&[1., 1.]), Diag::Unit), Ok(arr1(&[1., 1.])));
+}
+
+#[test]
+#[should_panic]
+fn triangular_type_not_square() {
+    Lower::new(Array2::<f64>::zeros((2, 3)));
+}
+
+#[test]
+fn symmetric_type() {
+    let n = 5;
+    let b = scrambled(n, n);
+    let dense = b.t().dot(&b) + &Array2::eye(n);
+    let mut lower = dense.clone();
+    for i in 0..n {
+        for j in i + 1..n {
+            lower[[i, j]] = f64::NAN;
+        }
+    }
+    let a = Symmetric::new(lower);
+    assert_eq!(a.to_dense(), dense);
+    let x = scrambled(n, 3);
+    assert_close(&a.dot(&x), &dense.dot(&x), 1e-9);
+    assert_close(&a.solve(&dense.dot(&x)).unwrap(), &x, 1e-9);
+    let l = a.cholesky().unwrap().into_l();
+    assert_close(&l.dot(&l.t()), &dense, 1e-9);
+    assert_eq!(Symmetric::new(arr2(&[[1., 0.], [2., 1.]])).solve(&arr1(&[1., 1.])),
+               Err(NotPositiveDefiniteError));
+
+    // Hermitian
+    let c = |re, im| Complex::new(re, im);
+    let h = arr2(&[[c(4., 0.), c(0., 0.)],
+                   [c(1., 2.), c(3., 0.)]]);
+    let h = Symmetric::new(h);
+    let x = arr1(&[c(1., 1.), c(-1., 0.)]);
+    let expected = h.to_dense().dot(&x);
+    assert_eq!(h.to_dense()[[0, 1]], c(1., -2.));
+    assert_close(&h.dot(&x), &expected, 1e-12);
+    assert_close(&h.solve(&expected).unwrap(), &x, 1e-12);
+}
+
+#[test]
+fn householder_reflector() {
+    let x = Array::linspace(-2f64, 3., 5);
+    let mut v = x.clone();
+    let (tau, beta) = make_householder(v.view_mut());
+    assert!((beta.abs() - x.dot(&x).sqrt()).abs() < 1e-12);
+    v[0] = 1.;
+    // H = I - tau v vᵀ, built by reflecting the identity from either side
+    let mut left = Array2::eye(5);
+    apply_householder_left(v.view(), tau, left.view_mut());
+    let mut right = Array2::eye(5);
+    apply_householder_right(v.view(), tau, right.view_mut());
+    assert_close(&left, &right, 1e-12);
+    assert_close(&left.dot(&left.t()), &Array2::eye(5), 1e-12);
+    let mut e0 = Array1::zeros(5);
+    e0[0] = beta;
+    assert_close(&left.dot(&x), &e0, 1e-12);
+
+    // already a multiple of e₀
+    let mut y = arr1(&[2., 0., 0.]);
+    assert_eq!(make_householder(y.view_mut()), (0., 2.));
+}
+
+#[test]
+#[should_panic]
+fn householder_wrong_length() {
+    let mut a = Array2::<f64>::zeros((3, 2));
+    apply_householder_right(Array1::zeros(3).view(), 1., a.view_mut());
+}
+
+#[test]
+fn givens_rotation() {
+    let (c, s, r) = make_givens(-3f64, 4.);
+    assert!((r - 5.).abs() < 1e-12);
+    assert!((c * c + s * s - 1.).abs() < 1e-12);
+    assert_eq!(make_givens(0., 0.), (1., 0., 0.));
+
+    // rows, columns and vectors, in either order of the indices
+    let a = scrambled(4, 4);
+    let mut rows = a.clone();
+    apply_givens(&mut rows, Axis(0), 3, 1, c, s);
+    let mut g = Array2::eye(4);
+    g[[3, 3]] = c;
+    g[[3, 1]] = -s;
+    g[[1, 3]] = s;
+    g[[1, 1]] = c;
+    assert_close(&rows, &g.dot(&a), 1e-12);
+    let mut cols = a.clone();
+    apply_givens(&mut cols, Axis(1), 3, 1, c, s);
+    assert_close(&cols, &a.dot(&g.t()), 1e-12);
+    let mut x = arr1(&[1., -3., 7., 4.]);
+    apply_givens(&mut x, Axis(0), 1, 3, c, s);
+    assert_close(&x, &arr1(&[1., 5., 7., 0.]), 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn givens_same_index() {
+    apply_givens(&mut Array2::<f64>::zeros((3, 3)), Axis(1), 2, 2, 1., 0.);
+}
+
+#[test]
+fn factor_once_solve_many() {
+    let n = 6;
+    let a = nonsingular(n);
+    let f = lu(&a);
+    for k in 0..3 {
+        let x = scrambled(n, k + 1);
+        assert_close(&f.solve(&a.dot(&x)).unwrap(), &x, 1e-10);
+        assert_close(&f.solve(&a.dot(&x.column(k))).unwrap(), &x.column(k), 1e-10);
+    }
+    assert_eq!(lu(&Array2::<f64>::zeros((2, 2))).solve(&arr1(&[1., 1.])), Err(SingularError));
+
+    // QR: least squares with a tall matrix, and a square system
+    let tall = scrambled(9, 4);
+    let f = qr(&tall);
+    let b = scrambled(9, 2);
+    let x = f.solve(&b).unwrap();
+    assert_eq!(x.dim(), (4, 2));
+    assert_close(&x, least_squares(&tall, &b, None).unwrap().solution(), 1e-10);
+    let x1 = f.solve(&b.column(0)).unwrap();
+    assert_close(&x1, &x.column(0), 1e-12);
+    let y = scrambled(n, 1);
+    assert_close(&qr(&a).solve(&a.dot(&y)).unwrap(), &y, 1e-10);
+}
+
+#[test]
+#[should_panic]
+fn lu_solve_not_square() {
+    let _ = lu(&scrambled(3, 2)).solve(&arr1(&[1., 2., 3.]));
+}
+
+#[test]
+#[should_panic]
+fn qr_solve_wide() {
+    let _ = qr(&scrambled(2, 3)).solve(&arr1(&[1., 2.]));
+}
+
+#[test]
+fn linear_solve() {
+    for n in 1..7 {
+        let a = nonsingular(n);
+        let x = Array::linspace(-1., 2., n);
+        let b = a.dot(&x);
+        assert_close(&solve(&a, &b).unwrap(), &x, 1e-12);
+
+        let xs = scrambled(n, 3);
+        let bs = a.dot(&xs);
+        assert_close(&solve(&a, &bs).unwrap(), &xs, 1e-12);
+        // strided right hand sides
+        let bt = bs.t().to_owned();
+        assert_close(&solve(&a, &bt.t()).unwrap(), &xs, 1e-12);
+        assert_close(&solve(&a, &bs.column(1)).unwrap(), &xs.column(1), 1e-12);
+    }
+    let singular = arr2(&[[1., 2.],
+                          [2., 4.]]);
+    assert_eq!(solve(&singular, &arr1(&[1., 2.])), Err(SingularError));
+    assert_eq!(solve(&Array2::<f64>::zeros((0, 0)), &Array1::zeros(0)).unwrap().len(), 0);
+}
+
+#[test]
+#[should_panic]
+fn linear_solve_wrong_rows() {
+    let _ = solve(&Array2::<f64>::eye(3), &Array1::zeros(2));
+}
+
+#[test]
+#[should_panic]
+fn linear_solve_3d() {
+    let _ = solve(&Array2::<f64>::eye(3), &Array3::zeros((3, 1, 1)));
+}
+
+#[test]
+fn least_squares_overdetermined() {
+    let a = scrambled(7, 3);
+    let x = arr1(&[1., -2., 0.5]);
+    let noise = arr1(&[0.1, -0.2, 0.05, 0., 0.3, -0.1, 0.2]);
+    let b = a.dot(&x) + &noise;
+    let fit = least_squares(&a, &b, None).unwrap();
+    assert_eq!(fit.rank(), 3);
+    assert_eq!(fit.singular_values().len(), 3);
+    // the residual is orthogonal to the columns of a
+    let r = a.dot(fit.solution()) - &b;
+    assert_close(&a.t().dot(&r), &Array1::zeros(3), 1e-10);
+    let rss = r.dot(&r);
+    assert_close(&fit.residuals().unwrap(), &arr1(&[rss]), 1e-10);
+
+    // several right hand sides, and an exactly consistent system
+    let xs = scrambled(3, 2);
+    let fit = least_squares(&a, &a.dot(&xs), None).unwrap();
+    assert_close(fit.solution(), &xs, 1e-10);
+    assert_close(&fit.residuals().unwrap(), &Array1::zeros(2), 1e-10);
+}
+
+#[test]
+fn least_squares_rank_deficient() {
+    // the third column is the sum of the first two
+    let mut a = scrambled(6, 3);
+    let c = &a.column(0) + &a.column(1);
+    a.column_mut(2).assign(&c);
+    let b = Array::linspace(0., 1., 6);
+    let fit = least_squares(&a, &b, None).unwrap();
+    assert_eq!(fit.rank(), 2);
+    assert!(fit.residuals().is_none());
+    let x = fit.solution();
+    assert_close(&a.t().dot(&(a.dot(x) - &b)), &Array1::zeros(3), 1e-10);
+    // the minimum norm solution is orthogonal to the null space (1, 1, -1)
+    assert!((x[0] + x[1] - x[2]).abs() < 1e-10);
+}
+
+#[test]
+fn least_squares_underdetermined() {
+    let a = arr2(&[[1., 1., 0.],
+                   [0., 1., 1.]]);
+    let b = arr1(&[1., 1.]);
+    let fit = least_squares(&a, &b, None).unwrap();
+    assert_eq!(fit.rank(), 2);
+    assert!(fit.residuals().is_none());
+    assert_close(&a.dot(fit.solution()), &b, 1e-12);
+    assert_close(fit.solution(), &arr1(&[1. / 3., 2. / 3., 1. / 3.]), 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn least_squares_wrong_rows() {
+    let _ = least_squares(&Array2::<f64>::zeros((3, 2)), &Array1::zeros(2), None);
+}
+
+fn max_abs_col_sum(a: &Array2<f64>) -> f64 {
+    a.map_axis(Axis(0), |col| col.fold(0., |acc, x| acc + x.abs())).fold(0., |acc, &x| acc.max(x))
+}
+
+#[test]
+fn matrix_norms() {
+    let a = scrambled(4, 3);
+    assert_eq!(norm(&a, Norm::One), max_abs_col_sum(&a));
+    assert_eq!(norm(&a, Norm::Inf), max_abs_col_sum(&a.t().to_owned()));
+    assert_eq!(norm(&a.t(), Norm::One), norm(&a, Norm::Inf));
+    let fro = a.fold(0., |acc, x| acc + x * x).sqrt();
+    assert!((norm(&a, Norm::Frobenius) - fro).abs() < 1e-12 * fro);
+    assert_eq!(norm(&a, Norm::Two), svd_values(&a).unwrap()[0]);
+    // the 2-norm is at most the Frobenius norm, and sqrt(|a|₁ |a|∞)
+    assert!(norm(&a, Norm::Two) <= norm(&a, Norm::Frobenius));
+    assert!(norm(&a, Norm::Two) <= (norm(&a, Norm::One) * norm(&a, Norm::Inf)).sqrt());
+
+    let v = a.slice(s![..;-2, ..]);
+    assert_eq!(norm(&v, Norm::One), max_abs_col_sum(&v.to_owned()));
+    // no overflow in the Frobenius norm
+    let big = Array2::from_elem((2, 2), 1e200f64);
+    assert!((norm(&big, Norm::Frobenius) - 2e200).abs() < 1e188);
+    for &kind in &[Norm::One, Norm::Two, Norm::Inf, Norm::Frobenius] {
+        assert_eq!(norm(&Array2::<f64>::zeros((0, 3)), kind), 0.);
+        let expected = if kind == Norm::Frobenius { 3f64.sqrt() } else { 1. };
+        assert!((norm(&Array2::<f64>::eye(3), kind) - expected).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn condition_number() {
+    for n in 1..8 {
+        let a = scrambled(n, n) + &(Array2::eye(n) * 7.5);
+        let inv = inverse(&a).unwrap();
+        let exact_one = max_abs_col_sum(&a) * max_abs_col_sum(&inv);
+        let est = cond(&a, Norm::One);
+        assert!(est <= exact_one * (1. + 1e-12) && est >= exact_one / 3., "n = {}", n);
+        let exact_inf = max_abs_col_sum(&a.t().to_owned()) * max_abs_col_sum(&inv.t().to_owned());
+        let est = cond(&a, Norm::Inf);
+        assert!(est <= exact_inf * (1. + 1e-12) && est >= exact_inf / 3., "n = {}", n);
+
+        let s = svd_values(&a).unwrap();
+        assert!((cond(&a, Norm::Two) - s[0] / s[n - 1]).abs() < 1e-9 * s[0] / s[n - 1]);
+        let fro = |m: &Array2<f64>| m.fold(0., |acc, x| acc + x * x).sqrt();
+        assert!((cond(&a, Norm::Frobenius) - fro(&a) * fro(&inv)).abs() < 1e-9 * fro(&a) * fro(&inv));
+        // the 2-norm condition number is the smallest
+        assert!(cond(&a, Norm::Two) <= cond(&a, Norm::Frobenius));
+    }
+    assert_eq!(cond(&Array2::<f64>::eye(4), Norm::Two), 1.);
+    assert_eq!(cond(&Array2::<f64>::eye(4), Norm::One), 1.);
+
+    let singular = arr2(&[[1., 2.],
+                          [2., 4.]]);
+    for &norm in &[Norm::One, Norm::Two, Norm::Inf, Norm::Frobenius] {
+        assert_eq!(cond(&singular, norm), ::std::f64::INFINITY);
+    }
+    // a badly conditioned Hilbert matrix
+    let hilbert = Array2::from_shape_fn((6, 6), |(i, j)| 1. / (i + j + 1) as f64);
+    let exact = max_abs_col_sum(&hilbert) * max_abs_col_sum(&inverse(&hilbert).unwrap());
+    let est = cond(&hilbert, Norm::One);
+    assert!(est <= exact * (1. + 1e-6) && est >= exact / 3.);
+    assert!(est > 1e7);
+}
+
+#[test]
+#[should_panic]
+fn condition_number_not_square() {
+    cond(&Array2::<f64>::zeros((2, 3)), Norm::Two);
+}
+
+#[test]
+fn matrix_exponential() {
+    assert_eq!(expm(&Array2::<f64>::zeros((3, 3))), Array2::eye(3));
+    assert_eq!(expm(&Array2::<f64>::zeros((0, 0))).dim(), (0, 0));
+    // diagonal matrices, including a large norm that needs many squarings
+    let d = arr1(&[-3., 0.5, 10.]);
+    let mut a = Array2::zeros((3, 3));
+    a.diag_mut().assign(&d);
+    let mut expected = Array2::zeros((3, 3));
+    expected.diag_mut().assign(&d.mapv(f64::exp));
+    let e = expm(&a);
+    for (x, y) in e.iter().zip(&expected) {
+        assert!((x - y).abs() <= 1e-13 * y.abs().max(1.));
+    }
+    // nilpotent: exp(a) = I + a + a²/2
+    let nil = arr2(&[[0., 1., 2.],
+                     [0., 0., 3.],
+                     [0., 0., 0.]]);
+    let series = Array2::eye(3) + &nil + &(nil.dot(&nil) / 2.);
+    assert_close(&expm(&nil), &series, 1e-14);
+    // symmetric: exp(a) = V exp(Λ) Vᵀ
+    let b = scrambled(4, 4) / 10.;
+    let s = &b + &b.t();
+    let (w, v) = eigh(&s).unwrap();
+    let expected = (&v * &w.mapv(f64::exp)).dot(&v.t());
+    assert_close(&expm(&s), &expected, 1e-10);
+    // exp(a) exp(-a) = I
+    let b = scrambled(5, 5) / 4.;
+    assert_close(&expm(&b).dot(&expm(&-&b)), &Array2::eye(5), 1e-9);
+}
+
+#[test]
+fn integer_matrix_power() {
+    let a = scrambled(4, 4) / 10. + &Array2::eye(4);
+    assert_eq!(matrix_power(&a, 0).unwrap(), Array2::eye(4));
+    assert_eq!(matrix_power(&a, 1).unwrap(), a);
+    let mut expected = Array2::eye(4);
+    for k in 1..12 {
+        expected = expected.dot(&a);
+        assert_close(&matrix_power(&a, k).unwrap(), &expected, 1e-9 * expected.fold(1., |m, x| x.abs().max(m)));
+    }
+    let inv = inverse(&a).unwrap();
+    assert_close(&matrix_power(&a, -1).unwrap(), &inv, 1e-12);
+    assert_close(&matrix_power(&a, -3).unwrap(), &inv.dot(&inv).dot(&inv), 1e-10);
+    assert_close(&matrix_power(&a, 5).unwrap().dot(&matrix_power(&a, -5).unwrap()), &Array2::eye(4), 1e-9);
+
+    // a Markov chain transition matrix keeps its rows summing to one
+    let p = arr2(&[[0.9, 0.1],
+                   [0.5, 0.5]]);
+    let p100 = matrix_power(&p, 100).unwrap();
+    assert_close(&p100, &arr2(&[[5. / 6., 1. / 6.], [5. / 6., 1. / 6.]]), 1e-12);
+
+    let singular = arr2(&[[1., 2.],
+                          [2., 4.]]);
+    assert!(matrix_power(&singular, 3).is_ok());
+    assert_eq!(matrix_power(&singular, -2), Err(SingularError));
+    assert_eq!(matrix_power(&Array2::<f64>::zeros((0, 0)), -2).unwrap().dim(), (0, 0));
+}
+
+#[test]
+fn polynomial_fit() {
+    // exact data of a cubic, on a wide interval
+    let c = arr1(&[0.5, -2., 0.25, 1e-3]);
+    let x = Array::linspace(-50., 50., 40);
+    let y = polyval(&c, &x);
+    let fit = polyfit(&x, &y, 3);
+    assert_close(&fit, &c, 1e-9);
+
+    // a line through noisy data is the least squares solution
+    let x = arr1(&[0., 1., 2., 3.]);
+    let y = arr1(&[1., 3., 2., 5.]);
+    let line = polyfit(&x, &y, 1);
+    let ls = least_squares(&vander(&x, 2), &y, None).unwrap();
+    assert_close(&line, ls.solution(), 1e-12);
+
+    // a constant is the mean
+    assert_close(&polyfit(&x, &y, 0), &arr1(&[2.75]), 1e-12);
+    assert_eq!(polyval(&Array1::<f64>::zeros(0), &x), Array1::zeros(4));
+    assert_eq!(vander(&x, 0).dim(), (4, 0));
+}
+
+#[test]
+#[should_panic]
+fn polyfit_wrong_length() {
+    polyfit(&arr1(&[0., 1.]), &arr1(&[0., 1., 2.]), 1);
+}
+
+#[test]
+fn tridiagonal_solve() {
+    for n in 1..8 {
+        let lower = Array::linspace(-1., 1., n - 1);
+        let upper = Array::linspace(0.5, -0.5, n - 1);
+        let diag = Array::linspace(4., 5., n);
+        let mut a = Array2::zeros((n, n));
+        a.diag_mut().assign(&diag);
+        for i in 0..n - 1 {
+            a[[i + 1, i]] = lower[i];
+            a[[i, i + 1]] = upper[i];
+        }
+        let x = Array::linspace(-2., 3., n);
+        let sol = solve_tridiagonal(&lower, &diag, &upper, &a.dot(&x)).unwrap();
+        assert_close(&sol, &x, 1e-12);
+        let xs = scrambled(n, 3);
+        let sol = solve_tridiagonal(&lower, &diag, &upper, &a.dot(&xs)).unwrap();
+        assert_close(&sol, &xs, 1e-12);
+    }
+    // the diagonals can be rows of a (3, n) array
+    let bands = arr2(&[[0., 1., 1.],
+                       [2., 2., 2.],
+                       [1., 1., 0.]]);
+    let sol = solve_tridiagonal(&bands.row(0).slice(s![1..]), &bands.row(1),
+                                &bands.row(2).slice(s![..-1]), &arr1(&[3., 4., 3.])).unwrap();
+    assert_close(&sol, &arr1(&[1., 1., 1.]), 1e-12);
+
+    let zeros = Array1::<f64>::zeros(0);
+    assert_eq!(solve_tridiagonal(&zeros, &zeros, &zeros, &zeros).unwrap().len(), 0);
+    let one = arr1(&[1.]);
+    assert_eq!(solve_tridiagonal(&one, &arr1(&[1., 1.]), &one, &arr1(&[1., 2.])),
+               Err(SingularError));
+}
+
+#[test]
+#[should_panic]
+fn tridiagonal_solve_bad_diagonals() {
+    let _ = solve_tridiagonal(&Array1::<f64>::zeros(3), &Array1::zeros(3), &Array1::zeros(2),
+                              &Array1::zeros(3));
+}
+
+/// A banded test matrix, that needs pivoting.
+fn banded_matrix(n: usize, kl: usize, ku: usize) -> Array2<f64> {
+    let a = scrambled(n, n);
+    Array2::from_shape_fn((n, n), |(i, j)| {
+        if i > j + kl || j > i + ku { 0. } else { a[[i, j]] }
+    })
+}
+
+#[test]
+fn banded_storage() {
+    for &(n, kl, ku) in &[(1, 0, 0), (5, 1, 2), (6, 2, 0), (6, 0, 3), (4, 5, 5), (0, 1, 1)] {
+        let a = banded_matrix(n, kl, ku);
+        let b = Banded::from_dense(&a, kl, ku);
+        assert_eq!((b.dim(), b.kl(), b.ku()), (n, kl, ku));
+        assert_eq!(b.band().dim(), (kl + ku + 1, n));
+        assert_eq!(b.to_dense(), a);
+        let x = Array::linspace(-1., 2., n);
+        assert_close(&b.dot(&x), &a.dot(&x), 1e-12);
+    }
+    // elements outside the band are dropped
+    let b = Banded::from_dense(&scrambled(4, 4), 1, 0);
+    assert_eq!(b.to_dense(), banded_matrix(4, 1, 0));
+    let mut b = Banded::from_band(Array2::zeros((2, 3)), 1, 0);
+    b.band_mut().row_mut(0).fill(1.);
+    assert_eq!(b.to_dense(), Array2::eye(3));
+}
+
+#[test]
+fn banded_solve() {
+    for &(n, kl, ku) in &[(1, 0, 0), (5, 1, 2), (7, 2, 1), (6, 2, 0), (6, 0, 3), (4, 5, 5)] {
+        let a = banded_matrix(n, kl, ku) + &(Array2::eye(n) * 0.5);
+        let b = Banded::from_dense(&a, kl, ku);
+        let x = Array::linspace(-1., 2., n);
+        let sol = b.solve(&a.dot(&x)).unwrap();
+        assert_close(&sol, &x, 1e-10);
+        let xs = scrambled(n, 2);
+        assert_close(&b.solve(&a.dot(&xs)).unwrap(), &xs, 1e-10);
+    }
+    // a zero diagonal needs pivoting
+    let a = arr2(&[[0., 1., 0.],
+                   [1., 0., 1.],
+                   [0., 1., 1.]]);
+    let b = Banded::from_dense(&a, 1, 1);
+    let x = arr1(&[1., 2., 3.]);
+    assert_close(&b.solve(&a.dot(&x)).unwrap(), &x, 1e-12);
+    let singular = Banded::from_dense(&arr2(&[[1., 1.], [1., 1.]]), 1, 1);
+    assert_eq!(singular.solve(&arr1(&[1., 1.])), Err(SingularError));
+}
+
+#[test]
+#[should_panic]
+fn banded_wrong_band() {
+    Banded::from_band(Array2::<f64>::zeros((2, 4)), 1, 1);
+}
+
+#[test]
+fn qr_reconstruct() {
+    for a in &test_matrices() {
+        let (m, n) = a.dim();
+        let f = qr(a);
+        let (q, r) = (f.q(), f.r());
+        assert_eq!((q.dim(), r.dim()), ((m, m), (m, n)));
+        assert_close(&q.dot(&r), a, 1e-10);
+        assert_close(&q.t().dot(&q), &Array2::eye(m), 1e-10);
+        for ((i, j), &x) in r.indexed_iter() {
+            if i > j { assert_eq!(x, 0.); }
+        }
+
+        let k = ::std::cmp::min(m, n);
+        let (q, r) = (f.q_thin(), f.r_thin());
+        assert_eq!((q.dim(), r.dim()), ((m, k), (k, n)));
+        assert_close(&q.dot(&r), a, 1e-10);
+        assert_close(&q.t().dot(&q), &Array2::eye(k), 1e-10);
+    }
+}
+
+#[test]
+fn qr_rank_deficient() {
+    // dependent and zero columns, and a strided input
+    let mut a = scrambled(5, 4);
+    let c = &a.column(0) * 2.;
+    a.column_mut(2).assign(&c);
+    a.column_mut(3).fill(0.);
+    let v = a.slice(s![..;-1, ..]);
+    let f = qr(&v);
+    assert_close(&f.q().dot(&f.r()), &v, 1e-10);
+    assert_close(&f.q().t().dot(&f.q()), &Array2::eye(5), 1e-10);
+    assert!(f.r()[[2, 2]].abs() < 1e-10);
+    assert_eq!(f.r()[[3, 3]], 0.);
+}
+
+#[test]
+fn svd_reconstruct() {
+    let mut cases = test_matrices();
+    cases.push(scrambled(8, 5).slice(s![..;-1, ..]).to_owned());
+    // rank one, and zero
+    cases.push(arr1(&[1., 2., -2.]).outer(&arr1(&[3., 0., 4., 0.])));
+    cases.push(Array2::zeros((3, 2)));
+    for a in &cases {
+        let (m, n) = a.dim();
+        let k = ::std::cmp::min(m, n);
+        let (u, s, vt) = svd(a).unwrap();
+        assert_eq!((u.dim(), s.dim(), vt.dim()), ((m, k), k, (k, n)));
+        assert_close(&(&u * &s).dot(&vt), a, 1e-9);
+        assert_close(&u.t().dot(&u), &Array2::eye(k), 1e-10);
+        assert_close(&vt.dot(&vt.t()), &Array2::eye(k), 1e-10);
+        for i in 1..k {
+            assert!(s[i - 1] >= s[i] && s[i] >= 0.);
+        }
+        assert_close(&svd_values(a).unwrap(), &s, 1e-9);
+    }
+}
+
+#[test]
+fn svd_rank_deficient() {
+    // rank one
+    let x = arr1(&[1., 2., -2.]);
+    let y = arr1(&[3., 0., 4., 0.]);
+    assert_close(&svd_values(&x.outer(&y)).unwrap(), &arr1(&[15., 0., 0.]), 1e-12);
+
+    // known singular values
+    let d = arr2(&[[0., 2., 0.],
+                   [0., 0., -3.],
+                   [1., 0., 0.]]);
+    assert_close(&svd_values(&d).unwrap(), &arr1(&[3., 2., 1.]), 1e-12);
+}
+
+#[test]
+fn svd_not_finite() {
+    let a = arr2(&[[1., 2.], [f64::NAN, 4.], [5., 6.]]);
+    assert_eq!(svd_values(&a), Err(ConvergenceError));
+    assert!(svd(&a).is_err());
+    assert!(rank(&a, None).is_err());
+    assert!(pinv(&a, None).is_err());
+    assert!(norm(&a, Norm::Two).is_nan());
+}
+
+#[test]
+fn matrix_rank() {
+    assert_eq!(rank(&scrambled(4, 6), None).unwrap(), 4);
+    assert_eq!(rank(&Array2::<f64>::eye(5), None).unwrap(), 5);
+    assert_eq!(rank(&Array2::<f64>::zeros((3, 4)), None).unwrap(), 0);
+    assert_eq!(rank(&Array2::<f64>::zeros((0, 4)), None).unwrap(), 0);
+    let x = arr1(&[1., 2., 2.]);
+    let y = arr1(&[3., -1., 0., 5.]);
+    let outer = x.into_shape((3, 1)).unwrap().dot(&y.into_shape((1, 4)).unwrap());
+    assert_eq!(rank(&outer, None).unwrap(), 1);
+    assert_eq!(rank(&outer.t(), None).unwrap(), 1);
+    // a nearly dependent row counts unless the tolerance is raised
+    let mut a = scrambled(3, 3);
+    let r = &a.row(0) * 2. + &a.row(1);
+    a.row_mut(2).assign(&r);
+    assert_eq!(rank(&a, None).unwrap(), 2);
+    a[[2, 2]] += 1e-6;
+    assert_eq!(rank(&a, None).unwrap(), 3);
+    assert_eq!(rank(&a, Some(1e-3)).unwrap(), 2);
+}
+
+#[test]
+fn pseudo_inverse() {
+    let mut cases = test_matrices();
+    let x = arr1(&[1., 2., 2.]);
+    cases.push(x.outer(&x));
+    cases.push(Array2::zeros((2, 3)));
+    for a in &cases {
+        let p = pinv(a, None).unwrap();
+        assert_eq!(p.dim(), (a.cols(), a.rows()));
+        // the four Penrose conditions
+        assert_close(&a.dot(&p).dot(a), a, 1e-9);
+        assert_close(&p.dot(a).dot(&p), &p, 1e-9);
+        let ap = a.dot(&p);
+        assert_close(&ap, &ap.t(), 1e-9);
+        let pa = p.dot(a);
+        assert_close(&pa, &pa.t(), 1e-9);
+    }
+    let a = nonsingular(4);
+    assert_close(&pinv(&a, None).unwrap(), &inverse(&a).unwrap(), 1e-12);
+    assert_eq!(pinv(&Array2::<f64>::zeros((2, 3)), None).unwrap(), Array2::zeros((3, 2)));
+
+    // a large rcond discards the small singular value
+    let d = arr2(&[[10., 0.],
+                   [0., 1e-3]]);
+    assert_close(&pinv(&d, None).unwrap(), &arr2(&[[0.1, 0.], [0., 1e3]]), 1e-9);
+    assert_close(&pinv(&d, Some(1e-2)).unwrap(), &arr2(&[[0.1, 0.], [0., 0.]]), 1e-12);
+}
+
+#[test]
+fn eigh_symmetric() {
+    let mut cases = square_matrices().iter().map(|b| b + &b.t()).collect::<Vec<_>>();
+    // repeated eigenvalues, and already diagonal or tridiagonal matrices
+    cases.push(Array2::eye(4));
+    cases.push(Array2::zeros((3, 3)));
+    let t = Array2::from_shape_fn((6, 6), |(i, j)| {
+        if i == j { 2. } else if i + 1 == j || j + 1 == i { -1. } else { 0. }
+    });
+    cases.push(t.clone());
+    let x = arr1(&[1., 2., 2.]);
+    let p = x.outer(&x);
+    cases.push(p.clone());
+    for a in &cases {
+        let n = a.rows();
+        let (w, v) = eigh(a).unwrap();
+        assert_eq!((w.dim(), v.dim()), (n, (n, n)));
+        assert_close(&a.dot(&v), &(&v * &w), 1e-9);
+        assert_close(&v.t().dot(&v), &Array2::eye(n), 1e-10);
+        for i in 1..n {
+            assert!(w[i - 1] <= w[i]);
+        }
+        assert_close(&eigvalsh(a).unwrap(), &w, 1e-9);
+    }
+
+    // eigenvalues of the second difference matrix, 2 - 2 cos(k π / 7)
+    let expected = Array::from_iter((1..7).map(|k| 2. - 2. * (k as f64 * ::std::f64::consts::PI / 7.).cos()));
+    assert_close(&eigvalsh(&t).unwrap(), &expected, 1e-12);
+    assert_close(&eigvalsh(&p).unwrap(), &arr1(&[0., 0., 9.]), 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn eigh_not_square() {
+    let _ = eigh(&Array2::<f64>::zeros((2, 3)));
+}
+
+#[test]
+fn eigh_not_finite() {
+    let a = arr2(&[[1., f64::INFINITY], [f64::INFINITY, 1.]]);
+    assert_eq!(eigvalsh(&a), Err(ConvergenceError));
+    assert!(eigh(&a).is_err());
+}
+
+#[test]
+fn eig_general() {
+    let mut cases = square_matrices();
+    cases.push(Array2::eye(3));
+    cases.push(arr2(&[[1., 1.], [0., 1.]]).mapv(|x: f64| x * 0.));
+    let b = scrambled(5, 5);
+    cases.push(&b + &b.t());
+    // a rotation has the eigenvalues cos θ ± i sin θ, and 1 along its axis
+    let (c, s) = (0.6, 0.8);
+    let r = arr2(&[[c, -s, 0.],
+                   [s, c, 0.],
+                   [0., 0., 1.]]);
+    cases.push(r.clone());
+    // the companion matrix of (x - 1)(x - 2)(x - 3)
+    let comp = arr2(&[[6., -11., 6.],
+                      [1., 0., 0.],
+                      [0., 1., 0.]]);
+    cases.push(comp.clone());
+    for a in &cases {
+        let n = a.rows();
+        let (w, v) = eig(a).unwrap();
+        assert_eq!((w.dim(), v.dim()), (n, (n, n)));
+        let ac = a.mapv(|x| Complex::new(x, 0.));
+        assert_close(&ac.dot(&v), &(&v * &w), 1e-8);
+        let norms = Array::from_iter(v.axis_iter(Axis(1)).map(|col| {
+            col.fold(0., |acc, z| acc + z.norm_sqr()).sqrt()
+        }));
+        assert_close(&norms, &Array1::from_elem(n, 1.), 1e-9);
+        assert_close(&eigvals(a).unwrap(), &w, 1e-9);
+    }
+
+    let w = eigvals(&r).unwrap();
+    let mut expected = vec![Complex::new(c, s), Complex::new(c, -s), Complex::new(1., 0.)];
+    for x in &w {
+        let pos = expected.iter().position(|y| (x - y).norm() < 1e-12).unwrap();
+        expected.remove(pos);
+    }
+
+    let mut re = eigvals(&comp).unwrap().iter().map(|z| { assert!(z.im.abs() < 1e-9); z.re }).collect::<Vec<_>>();
+    re.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_close(&arr1(&re), &arr1(&[1., 2., 3.]), 1e-9);
+}
+
+#[test]
+#[should_panic]
+fn eig_not_square() {
+    let _ = eig(&Array2::<f64>::zeros((3, 2)));
+}
+
+#[test]
+fn eig_not_finite() {
+    let a = arr2(&[[1., 2., 3.], [4., f64::NAN, 6.], [7., 8., 9.]]);
+    assert_eq!(eigvals(&a), Err(ConvergenceError));
+    assert!(eig(&a).is_err());
+}
@@ -5,6 +5,10 @@ use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{LinalgScalar, Data};
 use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::general_mat_vec_mul;
+use ndarray::linalg::einsum;
+use ndarray::linalg::tensordot;
+use ndarray::linalg::kron;
 
 use std::fmt;
 use num_traits::Float;
@@ -74,6 +78,84 @@ fn operations()
     test_oper("neg", &[1.0,2.0,3.0,4.0], &[1.0, 1.0, 2.0, 3.0], &[-1.0,-2.0,-3.0,-4.0]);
 }
 
+#[test]
+fn integer_bit_operations()
+{
+    let a = Array::from_iter(0..12u32).into_shape((3, 4)).unwrap();
+    let b = arr1(&[1u32, 2, 3, 4]);
+
+    // array-array, with `b` broadcast along the rows
+    assert_eq!(&a % &b, a.map(|&x| x % (x % 4 + 1)));
+    assert_eq!(&a & &b, a.map(|&x| x & (x % 4 + 1)));
+    assert_eq!(&a | &b, a.map(|&x| x | (x % 4 + 1)));
+    assert_eq!(&a ^ &b, a.map(|&x| x ^ (x % 4 + 1)));
+    assert_eq!(&a << &b, a.map(|&x| x << (x % 4 + 1)));
+    assert_eq!(&a >> &b, a.map(|&x| x >> (x % 4 + 1)));
+
+    // array-scalar
+    assert_eq!(&a % 5, a.map(|&x| x % 5));
+    assert_eq!(&a & 6, a.map(|&x| x & 6));
+    assert_eq!(a.clone() << 2, a.map(|&x| x << 2));
+    assert_eq!(0xff ^ &a, a.map(|&x| 0xff ^ x));
+
+    // in place, on a strided view
+    let mut c = a.clone();
+    {
+        let mut v = c.slice_mut(s![.., ..;2]);
+        v >>= 1;
+        v |= &arr1(&[8, 16]);
+    }
+    let mut d = a.clone();
+    for (j, mut col) in d.axis_iter_mut(Axis(1)).enumerate() {
+        if j % 2 == 0 {
+            col.mapv_inplace(|x| (x >> 1) | (8 << (j / 2)));
+        }
+    }
+    assert_eq!(c, d);
+    c %= &b;
+    c &= 3;
+    assert_eq!(c, (&d % &b) & 3);
+}
+
+#[test]
+fn operations_co_broadcast()
+{
+    let col = Array::from_iter(0..3).into_shape((3, 1)).unwrap();
+    let row = Array::from_iter(0..4).into_shape((1, 4)).unwrap();
+    let sum = &col + &row;
+    assert_eq!(sum.dim(), (3, 4));
+    for ((i, j), &x) in sum.indexed_iter() {
+        assert_eq!(x, (i + j) as i32);
+    }
+    assert_eq!(&row - &col, -&sum + 2 * &row);
+
+    // the lhs may be the smaller operand
+    let m = Array::from_elem((3, 4), 2);
+    assert_eq!(&row * &m, 2 * &row.broadcast((3, 4)).unwrap());
+    assert_eq!(&aview0(&5).broadcast((1, 1)).unwrap() - &m, Array::from_elem((3, 4), 3));
+    // ... or have fewer axes
+    let v = arr1(&[1, 2, 3, 4]);
+    assert_eq!(&row + &v, arr2(&[[1, 3, 5, 7]]));
+}
+
+#[test]
+#[should_panic]
+fn operations_co_broadcast_fail()
+{
+    let a = Array::<f32, _>::zeros((3, 1));
+    let b = Array::<f32, _>::zeros((2, 4));
+    let _ = &a + &b;
+}
+
+#[test]
+#[should_panic]
+fn operations_co_broadcast_more_axes()
+{
+    let a = Array::<f32, _>::zeros(4);
+    let b = Array::<f32, _>::zeros((2, 4));
+    let _ = &a + &b;
+}
+
 #[test]
 fn scalar_operations()
 {
@@ -109,6 +191,103 @@ fn scalar_operations()
     }
 }
 
+#[test]
+fn scalar_operations_both_sides()
+{
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    assert_eq!(&a * 2., arr2(&[[2., 4.], [6., 8.]]));
+    assert_eq!(2. * &a, &a * 2.);
+    assert_eq!(&a + 1., 1. + &a);
+    assert_eq!(10. - &a, arr2(&[[9., 8.], [7., 6.]]));
+    assert_eq!(12. / &a.view(), arr2(&[[12., 6.], [4., 3.]]));
+    assert_eq!(a.clone() - 1., arr2(&[[0., 1.], [2., 3.]]));
+
+    let b = arr1(&[1usize, 2, 3]);
+    assert_eq!(2 * &b, arr1(&[2, 4, 6]));
+    assert_eq!(7 % b.clone(), arr1(&[0, 1, 1]));
+    let c = arr1(&[-1isize, 2]);
+    assert_eq!(1 - c, arr1(&[2, -1]));
+
+    let d = arr1(&[true, false]);
+    assert_eq!(true ^ &d, arr1(&[false, true]));
+}
+
+#[test]
+fn scalar_assign_ops_in_place()
+{
+    let mut a = RcArray::from_iter(0..12).reshape((3, 4));
+    let ptr = a.as_ptr();
+    a *= 2;
+    a += 1;
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, RcArray::from_iter((0..12).map(|x| 2 * x + 1)).reshape((3, 4)));
+
+    // only the elements in the view are modified
+    let mut b = Array::from_elem((4, 4), 1.);
+    {
+        let mut v = b.slice_mut(s![..;2, 1..;2]);
+        v /= 4.;
+        v -= 0.25;
+    }
+    assert_eq!(b.scalar_sum(), 12.);
+    assert_eq!(b.iter().filter(|&&x| x == 0.).count(), 4);
+}
+
+#[test]
+fn float_maths()
+{
+    let a = Array::linspace(0.1f64, 2., 20).into_shape((4, 5)).unwrap();
+    let t = a.t();
+    assert_eq!(t.exp(), t.mapv(f64::exp));
+    assert_eq!(t.sin(), t.map(|x| x.sin()));
+    assert!(t.ln().exp().all_close(&t, 1e-12));
+    assert!(t.sqrt().powi(2).all_close(&t, 1e-12));
+    assert!(t.log(3.).mapv(|x| 3f64.powf(x)).all_close(&t, 1e-12));
+    assert_eq!(t.exp().shape(), &[5, 4]);
+
+    let b = arr1(&[-1.5f32, 0.25, 2.5]);
+    assert_eq!(b.abs(), arr1(&[1.5, 0.25, 2.5]));
+    assert_eq!(b.floor(), arr1(&[-2., 0., 2.]));
+    assert_eq!(b.signum(), arr1(&[-1., 1., 1.]));
+}
+
+#[test]
+fn float_rounding_to_int()
+{
+    use std::f64;
+
+    let a = arr2(&[[-1.5, -0.5], [0.5, 2.7]]);
+    let t = a.t();
+    assert_eq!(t.floor_as::<i32>(), Some(t.floor().mapv(|x| x as i32)));
+    assert_eq!(t.ceil_as::<i64>(), Some(arr2(&[[-1, 1], [0, 3]])));
+    assert_eq!(t.round_as::<i8>(), Some(arr2(&[[-2, 1], [-1, 3]])));
+    assert_eq!(t.trunc_as::<isize>(), Some(arr2(&[[-1, 0], [0, 2]])));
+
+    // values not representable in the target type
+    assert_eq!(t.floor_as::<u32>(), None);
+    assert_eq!(arr1(&[f64::NAN]).round_as::<i32>(), None);
+    assert_eq!(arr1(&[300.]).trunc_as::<u8>(), None);
+}
+
+#[test]
+fn abs_signum()
+{
+    let a = Array::from_iter(-6..6).into_shape((3, 4)).unwrap();
+    assert_eq!(a.t().abs(), a.t().mapv(i32::abs));
+    assert_eq!(a.t().signum(), a.t().mapv(i32::signum));
+    assert_eq!(a.row(1).signum(), arr1(&[-1, -1, 0, 1]));
+
+    let mut b = a.clone();
+    b.slice_mut(s![.., ..;2]).iabs();
+    assert_eq!(b.column(0), a.column(0).abs());
+    assert_eq!(b.column(1), a.column(1));
+
+    let mut f = arr1(&[-0.5f64, -0., 3.]);
+    f.iabs();
+    assert_eq!(f, arr1(&[0.5, 0., 3.]));
+    assert!(f[1].is_sign_positive());
+}
+
 fn assert_approx_eq<F: fmt::Debug + Float>(f: F, g: F, tol: F) -> bool {
     assert!((f - g).abs() <= tol, "{:?} approx== {:?} (tol={:?})",
             f, g, tol);
@@ -224,6 +403,70 @@ fn dot_product_neg_stride() {
     }
 }
 
+#[test]
+fn dot_product_strided_lengths() {
+    // exercise the blocked accumulator loop and its remainder
+    for n in 1..12 {
+        let a = Array::range(0., 2. * n as f32, 1.);
+        let b = &a * 3. - 1.;
+        let a = a.slice(s![..;2]);
+        let b = b.slice(s![..;-2]);
+        assert_eq!(a.len(), n);
+        assert_approx_eq(a.dot(&b), reference_dot(&a, &b), 1e-5);
+
+        let ai = Array::from_iter(0..2 * n as i64);
+        let bi = &ai * 3 - 1;
+        let ai = ai.slice(s![..;2]);
+        let bi = bi.slice(s![..;-2]);
+        let expected = ai.iter().zip(&bi).fold(0, |acc, (&x, &y)| acc + x * y);
+        assert_eq!(ai.dot(&bi), expected);
+    }
+}
+
+#[test]
+fn outer_product() {
+    let a = arr1(&[1., 2., 3.]);
+    let b = Array::range(0., 8., 1.);
+    let b = b.slice(s![..;-2]);
+    let c = a.outer(&b);
+    assert_eq!(c.dim(), (3, 4));
+    for ((i, j), &x) in c.indexed_iter() {
+        assert_eq!(x, a[i] * b[j]);
+    }
+    // agrees with matrix multiplication of a column by a row
+    let col = a.view().into_shape((3, 1)).unwrap();
+    let row = b.to_owned().into_shape((1, 4)).unwrap();
+    assert_eq!(c, col.dot(&row));
+
+    let e = Array::<f32, _>::zeros(0);
+    assert_eq!(e.outer(&a).dim(), (0, 3));
+}
+
+#[test]
+fn cross_product() {
+    let a = arr1(&[2, -1, 3]);
+    let b = arr1(&[1, 4, -2]);
+    let c = a.cross(&b);
+    assert_eq!(c, arr1(&[-10, 7, 9]));
+    // orthogonal to both inputs, and anticommutative
+    assert_eq!(c.dot(&a), 0);
+    assert_eq!(c.dot(&b), 0);
+    assert_eq!(b.cross(&a), -c);
+
+    // strided input
+    let v = Array::range(0., 6., 1.);
+    let x = arr1(&[1., 0., 0.]);
+    assert_eq!(v.slice(s![..;2]).cross(&x), arr1(&[0., 4., -2.]));
+
+    assert_eq!(arr1(&[1, 0]).cross(&arr1(&[0, 1])), arr1(&[0, 0, 1]));
+}
+
+#[should_panic]
+#[test]
+fn cross_product_wrong_len() {
+    arr1(&[1., 2., 3., 4.]).cross(&arr1(&[1., 2., 3., 4.]));
+}
+
 #[test]
 fn fold_and_sum() {
     let a = Array::linspace(0., 127., 128).into_shape((8, 16)).unwrap();
@@ -263,6 +506,466 @@ fn fold_and_sum() {
     }
 }
 
+#[test]
+fn scalar_sum_accuracy_and_product() {
+    // a sum of many small values, where naive f32 summation drifts
+    let n = 1 << 20;
+    let a = Array::from_elem(n, 0.1f32);
+    let exact = 0.1f64 * n as f64;
+    assert!((a.scalar_sum() as f64 - exact).abs() < 1e-4 * exact);
+    let b = a.into_shape((1 << 10, 1 << 10)).unwrap();
+    assert!((b.t().scalar_sum() as f64 - exact).abs() < 1e-4 * exact);
+
+    let c = Array::from_iter(1..11).into_shape((2, 5)).unwrap();
+    assert_eq!(c.product(), 3628800);
+    assert_eq!(c.t().product(), 3628800);
+    assert_eq!(c.slice(s![.., ..;2]).product(), 1 * 3 * 5 * 6 * 8 * 10);
+    assert_eq!(Array::<f64, _>::zeros((0, 3)).product(), 1.);
+}
+
+#[test]
+fn min_max() {
+    use std::f64;
+
+    let a = arr2(&[[3, 7, -1], [2, 9, 0]]);
+    assert_eq!(a.min(), Some(&-1));
+    assert_eq!(a.t().max(), Some(&9));
+    assert_eq!(a.min_axis(Axis(0)), arr1(&[2, 7, -1]));
+    assert_eq!(a.max_axis(Axis(0)), arr1(&[3, 9, 0]));
+    assert_eq!(a.slice(s![.., ..;-2]).min_axis(Axis(1)), arr1(&[-1, 0]));
+    assert_eq!(a.max_axis(Axis(1)), arr1(&[7, 9]));
+    assert_eq!(Array::<i32, _>::zeros((0, 2)).max(), None);
+
+    let f = arr2(&[[1., f64::NAN], [-2., 0.5]]);
+    assert!(f.min().unwrap().is_nan());
+    let m = f.max_axis(Axis(0));
+    assert_eq!(m[0], 1.);
+    assert!(m[1].is_nan());
+    assert_eq!(f.column(0).min(), Some(&-2.));
+}
+
+#[test]
+fn peak_to_peak() {
+    use std::f64;
+
+    let a = Array::from_iter((0..24).map(|x| (x * 7) % 24)).into_shape((2, 3, 4)).unwrap();
+    for ax in 0..3 {
+        let ptp = a.ptp(Axis(ax));
+        assert_eq!(ptp, &a.max_axis(Axis(ax)) - &a.min_axis(Axis(ax)));
+        assert_eq!(a.t().ptp(Axis(2 - ax)), ptp.t());
+    }
+    let b = arr2(&[[1., f64::NAN, 3.], [f64::NAN, 0., 1.5], [2., 2., 2.]]);
+    let ptp = b.ptp(Axis(1));
+    assert!(ptp[0].is_nan() && ptp[1].is_nan());
+    assert_eq!(ptp[2], 0.);
+    assert_eq!(b.slice(s![1.., 1..]).ptp(Axis(0)), arr1(&[2., 0.5]));
+}
+
+#[test]
+#[should_panic]
+fn peak_to_peak_empty() {
+    Array2::<i32>::zeros((0, 2)).ptp(Axis(0));
+}
+
+#[test]
+fn trace() {
+    let a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    for offset in -4..6 {
+        let expected = a.indexed_iter()
+                        .filter(|&((i, j), _)| j as isize - i as isize == offset)
+                        .fold(0, |acc, (_, &x)| acc + x);
+        assert_eq!(a.trace(offset), expected, "offset = {}", offset);
+        assert_eq!(a.t().trace(-offset), expected, "offset = {}", offset);
+    }
+    assert_eq!(a.slice(s![..;-1, 1..;2]).trace(0), 9 + 7);
+    assert_eq!(Array2::<f64>::zeros((0, 3)).trace(0), 0.);
+
+    let b = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    for offset in -3..5 {
+        let t = b.trace_axes(offset, Axis(1), Axis(2));
+        assert_eq!(t.shape(), &[2]);
+        for k in 0..2 {
+            assert_eq!(t[[k]], b.subview(Axis(0), k).trace(offset));
+        }
+        // swapping the axes negates the offset
+        assert_eq!(b.trace_axes(-offset, Axis(2), Axis(1)), t);
+    }
+    let t = b.trace_axes(0, Axis(0), Axis(2));
+    assert_eq!(t, arr1(&[0 + 13, 4 + 17, 8 + 21]).into_dyn());
+    assert_eq!(b.trace_axes(0, Axis(0), Axis(1)).shape(), &[4]);
+}
+
+#[test]
+#[should_panic]
+fn trace_same_axes() {
+    Array3::<f64>::zeros((2, 2, 2)).trace_axes(0, Axis(1), Axis(1));
+}
+
+#[test]
+fn argmin_argmax() {
+    use std::f64;
+
+    let a = arr2(&[[3, 9, -1], [2, 9, -1]]);
+    assert_eq!(a.argmin(), Some((0, 2)));
+    assert_eq!(a.argmax(), Some((0, 1)));
+    assert_eq!(a.t().argmax(), Some((1, 0)));
+    assert_eq!(a.argmin_axis(Axis(0)), arr1(&[1, 0, 0]));
+    assert_eq!(a.argmax_axis(Axis(1)), arr1(&[1, 1]));
+    assert_eq!(a.slice(s![.., ..;-1]).argmin_axis(Axis(1)), arr1(&[0, 0]));
+    assert_eq!(Array::<i32, _>::zeros((0, 2)).argmax(), None);
+
+    let c = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    assert_eq!(c.argmax(), Some((1, 2, 3)));
+    assert_eq!(c.into_shape(24).unwrap().argmin(), Some(0));
+
+    let f = arr2(&[[1., f64::NAN], [-2., f64::NAN]]);
+    assert_eq!(f.argmin(), Some((0, 1)));
+    assert_eq!(f.argmax_axis(Axis(1)), arr1(&[1, 1]));
+    assert_eq!(f.argmin_axis(Axis(0))[0], 1);
+}
+
+#[test]
+#[should_panic]
+fn min_axis_empty() {
+    Array::<i32, _>::zeros((0, 2)).min_axis(Axis(0));
+}
+
+#[test]
+fn var_std() {
+    let a = range_mat64(5, 4);
+    for ax in 0..2 {
+        let axis = Axis(ax);
+        let n = a.len_of(axis) as f64;
+        let mean = a.mean(axis);
+        let dev = a.map_axis(axis, |lane| {
+            lane.fold(0., |acc, &x| acc + (x - lane.scalar_sum() / n).powi(2))
+        });
+        assert!(a.var(axis, 0.).all_close(&(&dev / n), 1e-12));
+        assert!(a.var(axis, 1.).all_close(&(&dev / (n - 1.)), 1e-12));
+        assert!(a.std(axis, 1.).all_close(&(&dev / (n - 1.)).mapv(f64::sqrt), 1e-12));
+        assert_eq!(mean.dim(), a.var(axis, 0.).dim());
+    }
+
+    // large offset: a naive sum of squares would lose all precision
+    let b = Array::from_iter((0..100).map(|x| 1e9 + (x % 4) as f64));
+    assert!(b.var(Axis(0), 0.).all_close(&aview0(&1.25), 1e-6));
+
+    let c = Array::from_elem((3, 2, 4), 1.5f32);
+    assert_eq!(c.var(Axis(1), 1.), Array::zeros((3, 4)));
+}
+
+#[test]
+fn median_percentile() {
+    use std::f64;
+
+    fn reference(lane: ArrayView1<f64>, q: f64) -> f64 {
+        let mut v = lane.iter().cloned().collect::<Vec<_>>();
+        v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let rank = q / 100. * (v.len() - 1) as f64;
+        let (lo, hi) = (rank.floor() as usize, rank.ceil() as usize);
+        v[lo] + (v[hi] - v[lo]) * (rank - rank.floor())
+    }
+
+    // scrambled values with repeats
+    let a = Array::from_iter((0..60).map(|x| ((x * 37) % 23) as f64))
+        .into_shape((3, 4, 5)).unwrap();
+    for ax in 0..3 {
+        for &q in &[0., 10., 25., 50., 62.5, 90., 100.] {
+            let p = a.percentile(q, Axis(ax));
+            let r = a.map_axis(Axis(ax), |lane| reference(lane, q));
+            assert!(p.all_close(&r, 1e-12), "q = {}, axis = {}", q, ax);
+        }
+        assert_eq!(a.median(Axis(ax)), a.percentile(50., Axis(ax)));
+    }
+    assert_eq!(a.t().median(Axis(2)), a.median(Axis(0)).t());
+
+    let b = arr2(&[[1., f64::NAN, 3.], [2., 1., 0.]]);
+    let m = b.median(Axis(1));
+    assert!(m[0].is_nan());
+    assert_eq!(m[1], 1.);
+    assert_eq!(arr1(&[5.]).median(Axis(0)), aview0(&5.));
+    let c = Array::from_iter((0..1000).map(|x| (x % 3) as f64));
+    assert_eq!(c.percentile(50., Axis(0)), aview0(&1.));
+    assert_eq!(c.percentile(20., Axis(0)), aview0(&0.));
+}
+
+#[test]
+#[should_panic]
+fn percentile_bad_q() {
+    range_mat64(2, 3).percentile(101., Axis(0));
+}
+
+#[test]
+fn trapezoidal_integration() {
+    // exact for linear functions
+    let x = Array::linspace(0., 2., 9);
+    let y = x.mapv(|x| 3. * x + 1.);
+    assert!((y.trapz(0.25, Axis(0))[()] - 8.).abs() < 1e-12);
+    assert!((y.trapz_xy(&x, Axis(0))[()] - 8.).abs() < 1e-12);
+    // integrating backwards changes the sign
+    assert!((y.trapz_xy(&x.slice(s![..;-1]), Axis(0))[()] + y.trapz_xy(&x, Axis(0))[()]).abs()
+            < 1e-12);
+
+    // uneven spacing, compared with summing the trapezoids
+    let x = arr1(&[0., 0.1, 0.5, 0.6, 1.5]);
+    let a = Array::from_iter((0..15).map(|i| ((i * 7) % 5) as f64)).into_shape((3, 5)).unwrap();
+    let r = a.trapz_xy(&x, Axis(1));
+    for (i, row) in a.outer_iter().enumerate() {
+        let mut s = 0.;
+        for k in 0..4 {
+            s += (x[k + 1] - x[k]) * (row[k] + row[k + 1]) / 2.;
+        }
+        assert!((r[i] - s).abs() < 1e-12);
+    }
+    assert!(a.t().trapz(2., Axis(0)).all_close(&a.trapz(2., Axis(1)), 1e-12));
+    assert_eq!(a.trapz(1., Axis(0)), (&a.row(0) + &a.row(2)) / 2. + &a.row(1));
+
+    assert_eq!(arr2(&[[5., 6.]]).trapz(1., Axis(0)), arr1(&[0., 0.]));
+    assert_eq!(Array2::<f64>::zeros((0, 2)).trapz(1., Axis(0)), arr1(&[0., 0.]));
+}
+
+#[test]
+#[should_panic]
+fn trapz_xy_wrong_len() {
+    range_mat64(2, 3).trapz_xy(&arr1(&[0., 1.]), Axis(1));
+}
+
+#[test]
+fn discrete_differences() {
+    let a = Array::from_iter((0..60).map(|x| (x * x * 7 % 31) as f64)).into_shape((3, 4, 5)).unwrap();
+    for ax in 0..3 {
+        let len = a.len_of(Axis(ax));
+        let d = a.diff(1, Axis(ax));
+        let mut dim = a.raw_dim();
+        dim[ax] = len - 1;
+        assert_eq!(d.raw_dim(), dim);
+        for (i, &x) in d.indexed_iter() {
+            let (i0, i1, i2) = i;
+            let mut j = [i0, i1, i2];
+            j[ax] += 1;
+            assert_eq!(x, a[j] - a[i]);
+        }
+        assert_eq!(a.diff(2, Axis(ax)), d.diff(1, Axis(ax)));
+        assert_eq!(a.diff(0, Axis(ax)), a);
+        assert_eq!(a.diff(len + 2, Axis(ax)).len(), 0);
+    }
+    let b = a.slice(s![.., ..;-1, ..;2]);
+    assert_eq!(b.diff(1, Axis(1)), b.to_owned().diff(1, Axis(1)));
+    assert_eq!(b.diff(1, Axis(1)), -a.diff(1, Axis(1)).slice(s![.., ..;-1, ..;2]).to_owned());
+}
+
+#[test]
+fn product_along_axis() {
+    let a = Array::from_iter((0..24).map(|x| x % 5 + 1)).into_shape((2, 3, 4)).unwrap();
+    for ax in 0..3 {
+        let p = a.product_axis(Axis(ax));
+        assert_eq!(p, a.map_axis(Axis(ax), |lane| lane.product()));
+        assert_eq!(a.t().product_axis(Axis(2 - ax)), p.t());
+    }
+    // geometric mean
+    let b = arr2(&[[1., 4.], [9., 16.]]);
+    assert_eq!(b.product_axis(Axis(1)).mapv(f64::sqrt), arr1(&[2., 12.]));
+    assert_eq!(Array2::<i32>::zeros((0, 3)).product_axis(Axis(0)), arr1(&[1, 1, 1]));
+}
+
+#[test]
+fn sums_with_wider_accumulator() {
+    let a = Array::from_iter((0..600).map(|x| (x % 256) as u8)).into_shape((20, 30)).unwrap();
+    let total = a.iter().map(|&x| x as u64).sum::<u64>();
+    assert_eq!(a.scalar_sum_as::<u64>(), total);
+    assert_eq!(a.t().scalar_sum_as::<u64>(), total);
+    assert_eq!(a.scalar_sum_as::<f64>(), total as f64);
+    for ax in 0..2 {
+        let wide = a.mapv(|x| x as u32);
+        assert_eq!(a.sum_as::<u32>(Axis(ax)), wide.sum(Axis(ax)));
+        let mean = a.mean_as::<f64>(Axis(ax));
+        assert!(mean.all_close(&a.mapv(|x| x as f64).mean(Axis(ax)), 1e-9));
+    }
+    assert_eq!(arr1(&[-1i8, -128]).scalar_sum_as::<i32>(), -129);
+    assert_eq!(Array1::<u8>::zeros(0).scalar_sum_as::<u16>(), 0);
+}
+
+#[test]
+#[should_panic]
+fn sum_as_unrepresentable() {
+    arr1(&[-1i32, 2]).scalar_sum_as::<u64>();
+}
+
+#[test]
+fn logsumexp() {
+    use std::f64;
+
+    let a = range_mat64(3, 4).mapv(|x| x / 3.);
+    for ax in 0..2 {
+        let naive = a.mapv(f64::exp).sum(Axis(ax)).mapv(f64::ln);
+        assert!(a.logsumexp(Axis(ax)).all_close(&naive, 1e-12));
+        let shifted = (&a + 800.).logsumexp(Axis(ax)) - 800.;
+        assert!(shifted.all_close(&naive, 1e-10));
+    }
+
+    let inf = f64::INFINITY;
+    let b = arr2(&[[-inf, -inf],
+                   [1., -inf],
+                   [inf, inf],
+                   [f64::NAN, 1.]]);
+    let lse = b.logsumexp(Axis(1));
+    assert_eq!(lse[0], -inf);
+    assert_eq!(lse[1], 1.);
+    assert_eq!(lse[2], inf);
+    assert!(lse[3].is_nan());
+    assert_eq!(Array2::<f64>::zeros((0, 2)).logsumexp(Axis(0)), arr1(&[-inf, -inf]));
+}
+
+#[test]
+fn weighted_mean() {
+    let a = range_mat64(4, 3);
+    let ones = Array::from_elem(4, 1.);
+    assert!(a.weighted_mean(&ones, Axis(0)).all_close(&a.mean(Axis(0)), 1e-12));
+    let w = arr1(&[0., 2., 0., 2.]);
+    let m = a.weighted_mean(&w, Axis(0));
+    assert!(m.all_close(&((&a.row(1) + &a.row(3)) / 2.), 1e-12));
+
+    let w = arr1(&[1., 2., 5.]);
+    let m = a.t().weighted_mean(&w.slice(s![..;-1]), Axis(0));
+    for (i, &x) in m.iter().enumerate() {
+        let r = a.row(i);
+        let expected = (5. * r[0] + 2. * r[1] + r[2]) / 8.;
+        assert!((x - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+#[should_panic]
+fn weighted_mean_wrong_len() {
+    range_mat64(4, 3).weighted_mean(&arr1(&[1., 1., 1.]), Axis(0));
+}
+
+#[test]
+#[should_panic]
+fn weighted_mean_zero_weights() {
+    range_mat64(2, 3).weighted_mean(&arr1(&[1., -1.]), Axis(0));
+}
+
+#[test]
+fn nan_reductions() {
+    use std::f64::NAN;
+
+    let a = arr2(&[[1., NAN, 3.],
+                   [NAN, NAN, -1.],
+                   [2., NAN, 5.]]);
+    assert_eq!(a.nancount(Axis(0)), arr1(&[2, 0, 3]));
+    assert_eq!(a.nancount(Axis(1)), arr1(&[2, 1, 2]));
+    assert_eq!(a.nansum(Axis(0)), arr1(&[3., 0., 7.]));
+    assert_eq!(a.nansum(Axis(1)), arr1(&[4., -1., 7.]));
+    assert_eq!(a.nanmax(Axis(1)), arr1(&[3., -1., 5.]));
+    assert_eq!(a.nanmin(Axis(1)), arr1(&[1., -1., 2.]));
+    assert_eq!(a.t().nanmin(Axis(0)), a.nanmin(Axis(1)));
+
+    let mean = a.nanmean(Axis(0));
+    assert_eq!(mean[0], 1.5);
+    assert!(mean[1].is_nan());
+    assert!(a.nanmax(Axis(0))[1].is_nan());
+    assert_eq!(mean[2], 7. / 3.);
+
+    // without NaN, the same as the plain reductions
+    let b = range_mat64(4, 5);
+    assert_eq!(b.nansum(Axis(1)), b.sum(Axis(1)));
+    assert!(b.nanmean(Axis(0)).all_close(&b.mean(Axis(0)), 1e-12));
+    assert_eq!(b.nanmax(Axis(0)), b.max_axis(Axis(0)));
+}
+
+#[test]
+fn vector_norms() {
+    use std::f64;
+
+    let a = arr2(&[[1., -2., 2.],
+                   [0., -4., 0.]]);
+    assert_eq!(a.norm_l1(), 9.);
+    assert_eq!(a.norm_l2(), 5.);
+    assert_eq!(a.norm_max(), 4.);
+    assert_eq!(a.t().norm_l2(), 5.);
+    assert_eq!(a.slice(s![.., ..;-2]).norm_l1(), 3.);
+
+    // no overflow or underflow from squaring
+    let big = arr1(&[3e200, -4e200]);
+    assert!((big.norm_l2() / 5e200 - 1.).abs() < 1e-15);
+    let small = arr1(&[3e-200, 4e-200]);
+    assert!((small.norm_l2() / 5e-200 - 1.).abs() < 1e-15);
+    assert_eq!(arr1(&[1e-310f64]).norm_l2(), 1e-310);
+
+    let empty = Array1::<f32>::zeros(0);
+    assert_eq!((empty.norm_l1(), empty.norm_l2(), empty.norm_max()), (0., 0., 0.));
+    assert_eq!(arr1(&[1., f64::INFINITY]).norm_l2(), f64::INFINITY);
+    let nan = arr1(&[1., f64::NAN, f64::INFINITY]);
+    assert!(nan.norm_l1().is_nan());
+    assert!(nan.norm_l2().is_nan());
+    assert!(nan.norm_max().is_nan());
+}
+
+#[test]
+fn count_nonzero_and_indices() {
+    let a = Array::from_iter((0..24).map(|x| x % 5 / 3)).into_shape((2, 3, 4)).unwrap();
+    let n = a.iter().filter(|&&x| x != 0).count();
+    assert_eq!(a.count_nonzero(), n);
+    assert_eq!(a.t().count_nonzero(), n);
+
+    let index = a.nonzero();
+    assert_eq!(index.len(), 3);
+    let found = (0..n).map(|k| (index[0][k], index[1][k], index[2][k])).collect::<Vec<_>>();
+    let expected = a.indexed_iter().filter(|&(_, &x)| x != 0).map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+    assert_eq!(found, expected);
+
+    let b = a.slice(s![.., ..;-1, ..]);
+    let index = b.indices_where(|&x| x == 0);
+    assert_eq!(index[1].len(), b.len() - n);
+    for k in 0..index[0].len() {
+        assert_eq!(b[[index[0][k], index[1][k], index[2][k]]], 0);
+    }
+
+    assert_eq!(Array2::<f64>::zeros((3, 0)).nonzero(), vec![arr1(&[]), arr1(&[])]);
+    assert!(arr0(1.).nonzero().is_empty());
+    assert_eq!(arr0(1.).count_nonzero(), 1);
+}
+
+#[test]
+fn cumsum_cumprod() {
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap().into_dyn();
+    for ax in 0..3 {
+        let s = a.cumsum(Axis(ax));
+        let p = a.mapv(|x| x % 3 + 1).cumprod(Axis(ax));
+        for (index, &x) in s.indexed_iter() {
+            let mut i = index.clone();
+            let mut sum = 0;
+            let mut prod = 1;
+            for k in 0..index[ax] + 1 {
+                i[ax] = k;
+                sum += a[i.clone()];
+                prod *= a[i.clone()] % 3 + 1;
+            }
+            assert_eq!(x, sum);
+            assert_eq!(p[index], prod);
+        }
+    }
+
+    // in place, on a non-contiguous view
+    let mut b = a.clone();
+    b.slice_mut(s![.., ..;-1, ..;2]).cumsum_inplace(Axis(1));
+    assert_eq!(b.slice(s![.., ..;-1, ..;2]), a.slice(s![.., ..;-1, ..;2]).cumsum(Axis(1)));
+    assert_eq!(b.slice(s![.., .., 1..;2]), a.slice(s![.., .., 1..;2]));
+
+    let mut c = arr1(&[1., 2., 3.]);
+    c.cumprod_inplace(Axis(0));
+    assert_eq!(c, arr1(&[1., 2., 6.]));
+    assert_eq!(Array2::<f32>::zeros((0, 3)).cumsum(Axis(0)).dim(), (0, 3));
+}
+
+#[test]
+#[should_panic]
+fn var_too_large_ddof() {
+    range_mat64(2, 3).var(Axis(0), 2.);
+}
+
 fn range_mat(m: Ix, n: Ix) -> Array2<f32> {
     Array::linspace(0., (m * n - 1) as f32, m * n).into_shape((m, n)).unwrap()
 }
@@ -457,6 +1160,19 @@ fn scaled_add() {
     let d = alpha * &b + &a;
     assert_eq!(c, d);
 
+    // same memory order (transposed), strided and broadcast operands
+    let mut c = a.t().to_owned();
+    c.scaled_add(alpha, &b.t());
+    assert_eq!(c, d.t());
+    let mut c = a.clone();
+    c.slice_mut(s![..;2, ..]).scaled_add(alpha, &b.slice(s![1..;2, ..]));
+    let mut e = a.clone();
+    e.slice_mut(s![..;2, ..]).zip_mut_with(&b.slice(s![1..;2, ..]),
+                                           |x, &y| *x = *x + alpha * y);
+    assert_eq!(c, e);
+    let mut c = a.clone();
+    c.scaled_add(alpha, &b.row(0));
+    assert_eq!(c, &a + &(alpha * &b.row(0)));
 }
 
 #[test]
@@ -520,3 +1236,300 @@ fn gen_mat_mul_i32() {
         assert_eq!(&c, &answer);
     }
 }
+
+#[test]
+fn gen_mat_mul_i32_tiles() {
+    // sizes crossing the tile and register block boundaries,
+    // with strided and transposed operands
+    let alpha = 3;
+    let sizes = vec![(0, 3, 2), (3, 0, 2), (1, 1, 1), (5, 7, 6),
+                     (65, 5, 67), (66, 260, 3), (70, 257, 65)];
+    for &(m, k, n) in &sizes {
+        for &beta in &[0, -2] {
+            let a = range_i32(k, 2 * m).mapv(|x| x % 7 - 3);
+            let a = a.slice(s![.., ..;2]).reversed_axes();
+            let b = range_i32(k, n).mapv(|x| x % 5 - 2);
+            let mut c = Array::from_elem((m, n), 7);
+            let answer = alpha * reference_mat_mul(&a, &b) + beta * &c;
+            general_mat_mul(alpha, &a, &b, beta, &mut c);
+            assert_eq!(c, answer);
+            assert_eq!(a.dot(&b), reference_mat_mul(&a, &b));
+        }
+    }
+}
+
+#[test]
+fn clip() {
+    use std::f64;
+
+    let a = Array::linspace(-2., 2., 9).into_shape((3, 3)).unwrap();
+    let b = a.t().clip(-1., 0.5);
+    assert_eq!(b, a.t().mapv(|x| x.max(-1.).min(0.5)));
+
+    let mut c = a.clone();
+    c.slice_mut(s![.., 1..]).clip_inplace(0., 0.);
+    assert_eq!(c.column(0), a.column(0));
+    assert_eq!(c.slice(s![.., 1..]).scalar_sum(), 0.);
+
+    let d = arr1(&[f64::NAN, 7.]).clip(0., 1.);
+    assert!(d[0].is_nan());
+    assert_eq!(d[1], 1.);
+
+    assert_eq!(arr1(&[3, -4, 5]).clip(-3, 3), arr1(&[3, -3, 3]));
+}
+
+#[test]
+#[should_panic]
+fn clip_bad_range() {
+    arr1(&[1., 2.]).clip(1., 0.);
+}
+
+#[test]
+fn minimum_maximum() {
+    use std::f64;
+
+    let a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    let b = a.t().mapv(|x| 11 - x);
+    assert_eq!(a.t().maximum(&b), a.t().mapv(|x| std::cmp::max(x, 11 - x)));
+    assert_eq!(a.t().minimum(&b), a.t().mapv(|x| std::cmp::min(x, 11 - x)));
+
+    // broadcast a row
+    let row = arr1(&[1, 6, 2, 9]);
+    let mut c = a.clone();
+    c.minimum_inplace(&row);
+    assert_eq!(c, arr2(&[[0, 1, 2, 3], [1, 5, 2, 7], [1, 6, 2, 9]]));
+    c.maximum_inplace(&aview0(&2));
+    assert_eq!(c, arr2(&[[2, 2, 2, 3], [2, 5, 2, 7], [2, 6, 2, 9]]));
+
+    // NaN in self is kept, NaN in rhs is ignored
+    let x = arr1(&[f64::NAN, 1.]);
+    let y = arr1(&[0., f64::NAN]);
+    let m = x.maximum(&y);
+    assert!(m[0].is_nan());
+    assert_eq!(m[1], 1.);
+}
+
+#[test]
+#[should_panic]
+fn maximum_broadcast_fail() {
+    arr1(&[1, 2, 3]).maximum(&arr1(&[1, 2]));
+}
+
+#[test]
+fn pow() {
+    let a = Array::from_iter(0..12).into_shape((3, 4)).unwrap();
+    assert_eq!(a.t().pow(2), a.t().map(|&x| x * x));
+    assert_eq!(a.pow(0), Array::from_elem((3, 4), 1));
+
+    let b = a.mapv(|x| x as f64);
+    assert_eq!(b.powi(3), b.pow(3));
+    assert!(b.powf(0.5).all_close(&b.sqrt(), 1e-12));
+
+    let exps = arr1(&[0., 1., 2., 0.5]);
+    let c = b.powf_array(&exps);
+    for ((i, j), &x) in c.indexed_iter() {
+        assert_eq!(x, b[(i, j)].powf(exps[j]));
+    }
+    assert_eq!(b.powf_array(&b), b.map(|&x| x.powf(x)));
+}
+
+#[test]
+fn unary_ops_by_reference() {
+    let a = arr2(&[[1., -2.], [0., 4.]]);
+    assert_eq!(-&a, arr2(&[[-1., 2.], [-0., -4.]]));
+    assert_eq!(-&a.t(), -a.t().to_owned());
+    assert_eq!(&a + &-&a, Array::zeros((2, 2)));
+
+    let b = arr1(&[3i32, -7]);
+    assert_eq!(-&b.view(), arr1(&[-3, 7]));
+    assert_eq!(!&b, arr1(&[!3, !-7]));
+
+    let mask = a.gt_scalar(0.);
+    assert_eq!(!&mask, a.le_scalar(0.));
+    assert_eq!(!mask, arr2(&[[false, true], [true, false]]));
+}
+
+#[test]
+fn overflow_aware_integer_ops() {
+    let a = arr2(&[[i8::max_value(), 1], [i8::min_value(), -1]]);
+    let one = arr0(1i8);
+    assert_eq!(a.checked_add(&one), None);
+    assert_eq!(a.checked_sub(&one), None);
+    assert_eq!(a.slice(s![.., 1..]).checked_add(&one), Some(arr2(&[[2], [0]])));
+    assert_eq!(a.checked_mul(&arr1(&[2, 1])), None);
+    assert_eq!(a.checked_mul(&arr1(&[1, 0])),
+               Some(arr2(&[[i8::max_value(), 0], [i8::min_value(), 0]])));
+
+    assert_eq!(a.saturating_add(&one), arr2(&[[127, 2], [-127, 0]]));
+    assert_eq!(a.saturating_sub(&one), arr2(&[[126, 0], [-128, -2]]));
+    assert_eq!(a.wrapping_add(&one), arr2(&[[-128, 2], [-127, 0]]));
+    assert_eq!(a.wrapping_sub(&one), arr2(&[[126, 0], [127, -2]]));
+    assert_eq!(a.wrapping_mul(&a.t()), arr2(&[[1, -128], [-128, 1]]));
+}
+
+#[test]
+fn gen_mat_vec_mul() {
+    let alpha = -2.3;
+    let beta = 3.14;
+    let sizes = vec![(4, 4), (8, 8), (17, 15), (4, 17), (17, 3), (1, 1)];
+    for &(m, k) in &sizes {
+        for &s1 in &[1, 2, -1, -2] {
+            for &s2 in &[1, 2, -1, -2] {
+                let a = range_mat64(m, k);
+                let a = a.slice(s![..;s1, ..]);
+                let m = a.rows();
+                let x = Array::range(0., (2 * k) as f64, 1.);
+                let x = x.slice(s![..;s2]);
+                let x = x.slice(s![..k as isize]);
+                let mut y = Array::linspace(1., 2., m);
+                let y0 = y.clone();
+
+                let col = x.to_owned().into_shape((k, 1)).unwrap();
+                let answer = alpha * a.dot(&col).into_shape(m).unwrap() + beta * &y0;
+                general_mat_vec_mul(alpha, &a, &x, beta, &mut y);
+                assert_close(y.view().into_shape((m, 1)).unwrap(),
+                             answer.view().into_shape((m, 1)).unwrap());
+
+                // mat-vec dot agrees with mat-mat dot
+                let v = a.dot(&x);
+                assert_close(v.into_shape((m, 1)).unwrap().view(), a.dot(&col).view());
+            }
+        }
+    }
+}
+
+#[should_panic]
+#[test]
+fn gen_mat_vec_mul_shape_error() {
+    let a = range_mat64(3, 4);
+    let x = Array::zeros(3);
+    let mut y = Array::zeros(3);
+    general_mat_vec_mul(1., &a, &x, 0., &mut y);
+}
+
+#[test]
+fn einsum_products() {
+    let a = range_i32(4, 6).mapv(|x| x % 7 - 3);
+    let b = range_i32(6, 5).mapv(|x| x % 5 - 2);
+    let a = a.slice(s![..;-1, ..]);
+    let b = b.t();
+    let ad = a.view().into_dyn();
+    let bd = b.view().into_dyn();
+
+    let c = einsum("ij,kj->ik", &[ad.clone(), bd.clone()]).unwrap();
+    assert_eq!(c, a.dot(&b.t()).into_dyn());
+    // implicit output, and transposed output
+    let c = einsum("ij, kj", &[ad.clone(), bd.clone()]).unwrap();
+    assert_eq!(c, a.dot(&b.t()).into_dyn());
+    let c = einsum("ij,kj->ki", &[ad.clone(), bd.clone()]).unwrap();
+    assert_eq!(c, b.dot(&a.t()).into_dyn());
+
+    // transposition, sum and outer product
+    assert_eq!(einsum("ij->ji", &[ad.clone()]).unwrap(), a.t().into_dyn());
+    assert_eq!(einsum("ij->", &[ad.clone()]).unwrap(), arr0(a.scalar_sum()).into_dyn());
+    let x = a.row(0);
+    let y = b.row(1);
+    assert_eq!(einsum("i,j", &[x.into_dyn(), y.into_dyn()]).unwrap(),
+               x.outer(&y).into_dyn());
+}
+
+#[test]
+fn einsum_diagonal_and_batches() {
+    let m = range_i32(3, 3);
+    let md = m.view().into_dyn();
+    assert_eq!(einsum("ii", &[md.clone()]).unwrap(), arr0(0 + 4 + 8).into_dyn());
+    assert_eq!(einsum("ii->i", &[md.clone()]).unwrap(), arr1(&[0, 4, 8]).into_dyn());
+
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    let b = Array::from_iter(0..16).into_shape((2, 4, 2)).unwrap();
+    let c = einsum("bij,bjk->bik", &[a.view().into_dyn(), b.view().into_dyn()]).unwrap();
+    assert_eq!(c.shape(), &[2, 3, 2]);
+    for k in 0..2 {
+        let ck = c.subview(Axis(0), k);
+        let ab = a.subview(Axis(0), k).dot(&b.subview(Axis(0), k));
+        assert_eq!(ck, ab.into_dyn());
+    }
+
+    let e = Array::<i32, _>::zeros((0, 3));
+    let r = einsum("ij,jk->ik", &[e.view().into_dyn(), md.clone()]).unwrap();
+    assert_eq!(r.shape(), &[0, 3]);
+    let r = einsum("ij->j", &[e.view().into_dyn()]).unwrap();
+    assert_eq!(r, arr1(&[0, 0, 0]).into_dyn());
+}
+
+#[test]
+fn einsum_shape_error() {
+    let a = range_i32(3, 4);
+    let b = range_i32(3, 4);
+    assert!(einsum("ij,jk", &[a.view().into_dyn(), b.view().into_dyn()]).is_err());
+    assert!(einsum("ii", &[a.view().into_dyn()]).is_err());
+}
+
+#[should_panic]
+#[test]
+fn einsum_bad_spec() {
+    let a = range_i32(3, 4);
+    let _ = einsum("ij->k", &[a.view().into_dyn()]);
+}
+
+#[test]
+fn tensordot_axes() {
+    let a = Array::from_iter(0..60).into_shape((3, 4, 5)).unwrap();
+    let b = Array::from_iter(0..40).into_shape((5, 2, 4)).unwrap();
+    let ad = a.view().into_dyn();
+    let bd = b.view().into_dyn();
+
+    let c = tensordot(&a, &b, &[Axis(2), Axis(1)], &[Axis(0), Axis(2)]);
+    assert_eq!(c, einsum("ijk,kbj->ib", &[ad.clone(), bd.clone()]).unwrap());
+
+    // pairs in a different order, and a strided operand
+    let c = tensordot(&b, &a, &[Axis(2)], &[Axis(1)]);
+    assert_eq!(c.shape(), &[5, 2, 3, 5]);
+    assert_eq!(c, einsum("xyj,ijk->xyik", &[bd.clone(), ad.clone()]).unwrap());
+    let s = a.slice(s![..;-1, ..;2, ..]);
+    let c = tensordot(&s, &b, &[Axis(2)], &[Axis(0)]);
+    assert_eq!(c, einsum("ijk,kbx->ijbx", &[s.into_dyn(), bd.clone()]).unwrap());
+
+    // full contraction
+    let c = tensordot(&a, &a, &[Axis(0), Axis(1), Axis(2)], &[Axis(0), Axis(1), Axis(2)]);
+    assert_eq!(c, arr0(a.fold(0, |acc, &x| acc + x * x)).into_dyn());
+}
+
+#[should_panic]
+#[test]
+fn tensordot_len_mismatch() {
+    let a = range_i32(3, 4);
+    tensordot(&a, &a, &[Axis(1)], &[Axis(1), Axis(0)]);
+}
+
+#[should_panic]
+#[test]
+fn tensordot_shape_mismatch() {
+    let a = range_i32(3, 4);
+    tensordot(&a, &a, &[Axis(1)], &[Axis(0)]);
+}
+
+#[test]
+fn kron_product() {
+    let a = range_i32(2, 3);
+    let b = range_i32(4, 2);
+    let b = b.slice(s![..;-1, ..]);
+    let k = kron(&a, &b);
+    assert_eq!(k.dim(), (8, 6));
+    for ((i, j), &x) in k.indexed_iter() {
+        assert_eq!(x, a[[i / 4, j / 2]] * b[[i % 4, j % 2]]);
+    }
+    // mixed product property
+    let c = range_i32(3, 2);
+    let d = range_i32(2, 3);
+    assert_eq!(kron(&a, &b).dot(&kron(&c, &d)), kron(&a.dot(&c), &b.dot(&d)));
+
+    // 2-D Laplacian from 1-D ones
+    let l = arr2(&[[-2, 1, 0], [1, -2, 1], [0, 1, -2]]);
+    let eye = Array2::eye(3);
+    let lap = kron(&l, &eye) + kron(&eye, &l);
+    assert_eq!(lap.dim(), (9, 9));
+    assert_eq!(lap.diag(), Array::from_elem(9, -4));
+
+    assert_eq!(kron(&Array2::<i32>::zeros((0, 2)), &a).dim(), (0, 6));
+}
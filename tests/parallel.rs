@@ -0,0 +1,48 @@
+#[macro_use(s)]
+extern crate ndarray;
+
+use ndarray::prelude::*;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn par_iter_visits_all() {
+    let a = Array::from_shape_fn((17, 31, 5), |(i, j, k)| i + j + k);
+    let sum = AtomicUsize::new(0);
+    for threads in 1..8 {
+        sum.store(0, Ordering::SeqCst);
+        a.par_iter().threads(threads).for_each(|&x| {
+            sum.fetch_add(x, Ordering::SeqCst);
+        });
+        assert_eq!(sum.load(Ordering::SeqCst), a.scalar_sum());
+    }
+}
+
+#[test]
+fn par_iter_mut_strided() {
+    let mut a = Array::from_shape_fn((40, 9), |(i, j)| (i * 9 + j) as f32);
+    let mut answer = a.clone();
+    answer.slice_mut(s![..;-3, 1..;2]).map_inplace(|x| *x += 1.);
+    a.slice_mut(s![..;-3, 1..;2]).par_iter_mut().threads(5).for_each(|x| *x += 1.);
+    assert_eq!(a, answer);
+}
+
+#[test]
+fn par_map() {
+    let a = Array::from_shape_fn((13, 70), |(i, j)| (i * 70 + j) as f64);
+    let t = a.t();
+    assert_eq!(t.par_map(|x| x * 2.), t.map(|x| x * 2.));
+    assert!(t.par_map(|x| x * 2.).is_standard_layout());
+
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.par_map(|&x| x), empty);
+}
+
+#[test]
+fn par_map_large() {
+    let a = Array::from_shape_fn((600, 70), |(i, j)| (i * 70 + j) as u64);
+    let s = a.slice(s![..;2, ..]).reversed_axes();
+    let m = s.par_map(|x| x.to_string());
+    assert_eq!(m, s.map(|x| x.to_string()));
+    assert!(m.is_standard_layout());
+}
@@ -5,10 +5,15 @@ extern crate ndarray;
 
 use ndarray::{
     aview1,
+    arr1,
     arr2,
     Axis,
+    Array,
     Array2,
+    Ix3,
     ErrorKind,
+    stack_new_axis,
+    tile,
 };
 
 #[test]
@@ -41,3 +46,66 @@ fn stacking() {
     let res: Result<Array2<f64>, _> = ndarray::stack(Axis(0), &[]);
     assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
 }
+
+#[test]
+fn stacking_new_axis() {
+    let a = arr2(&[[1, 2, 3],
+                   [4, 5, 6]]);
+    let b = a.map(|x| x * 10);
+    let c = stack_new_axis(Axis(0), &[a.view(), b.view(), a.view()]).unwrap();
+    assert_eq!(c.shape(), &[3, 2, 3]);
+    assert_eq!(c.subview(Axis(0), 1), b);
+    let c = stack_new_axis(Axis(1), &[a.view(), b.view()]).unwrap();
+    assert_eq!(c.shape(), &[2, 2, 3]);
+    assert_eq!(c.subview(Axis(1), 1), b);
+    let c = stack_new_axis(Axis(2), &[a.t(), b.t()]).unwrap();
+    assert_eq!(c.shape(), &[3, 2, 2]);
+    assert_eq!(c.subview(Axis(2), 0), a.t());
+
+    let d = stack_new_axis(Axis(0), &[a.row(0), a.row(1)]).unwrap();
+    assert_eq!(d, a);
+
+    let res = stack_new_axis(Axis(0), &[a.view(), a.t()]);
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+    assert_eq!(err.from_shape(), Some(&[3, 2][..]));
+    assert_eq!(err.to_shape(), Some(&[2, 3][..]));
+    let res = stack_new_axis(Axis(3), &[a.view()]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::OutOfBounds);
+    let res = stack_new_axis::<f64, Ix3>(Axis(0), &[]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::Unsupported);
+}
+
+#[test]
+fn tiling() {
+    let a = arr1(&[1, 2, 3]);
+    assert_eq!(tile(&a, &[3]), arr1(&[1, 2, 3, 1, 2, 3, 1, 2, 3]));
+    assert_eq!(tile(&a, &[0]).len(), 0);
+
+    // non-contiguous input
+    let b = arr2(&[[1, 2],
+                   [3, 4]]);
+    assert_eq!(tile(&b.t(), &[1, 2]), arr2(&[[1, 3, 1, 3],
+                                             [2, 4, 2, 4]]));
+    assert_eq!(tile(&b, &[2, 0]).shape(), &[4, 0]);
+
+    let c = Array::from_iter(0..6).into_shape((1, 2, 3)).unwrap();
+    let t = tile(&c.view().into_dyn(), &[2, 2, 1]);
+    assert_eq!(t.shape(), &[2, 4, 3]);
+    for ((i, j, k), &x) in t.into_dimensionality::<Ix3>().unwrap().indexed_iter() {
+        assert_eq!(x, c[(i % 1, j % 2, k % 3)]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn tiling_overflow() {
+    // the result would be empty, but the length of axis 1 overflows
+    tile(&Array2::<i32>::zeros((0, 2)), &[1, usize::max_value()]);
+}
+
+#[test]
+#[should_panic]
+fn tiling_wrong_reps() {
+    tile(&arr1(&[1, 2]), &[2, 2]);
+}
@@ -0,0 +1,122 @@
+
+extern crate ndarray;
+
+use ndarray::prelude::*;
+use ndarray::stats::{histogram, histogram2d, Bins};
+use ndarray::stats::{cov, corrcoef};
+
+#[test]
+fn histogram_uniform() {
+    let a = Array::linspace(0., 1., 101);
+    let (counts, edges) = histogram(&a, Bins::Count(10));
+    assert_eq!(edges, Array::linspace(0., 1., 11));
+    assert_eq!(counts.scalar_sum(), 101);
+    // each element lands in the bin between the edges around it
+    for &x in &a {
+        let (c, _) = histogram(&aview1(&[x]), Bins::Uniform(10, 0., 1.));
+        let i = c.iter().position(|&n| n == 1).unwrap();
+        assert!(edges[i] <= x && (x < edges[i + 1] || i == 9), "x = {}", x);
+    }
+
+    // out of range and NaN are not counted; strided input
+    let b = arr2(&[[-1., 0., 0.5],
+                   [::std::f64::NAN, 2., 3.]]);
+    let (counts, _) = histogram(&b.t(), Bins::Uniform(2, 0., 2.));
+    assert_eq!(counts, arr1(&[2, 1]));
+
+    // a single distinct value
+    let (counts, edges) = histogram(&arr1(&[3., 3.]), Bins::Count(2));
+    assert_eq!(counts, arr1(&[0, 2]));
+    assert_eq!(edges, arr1(&[2.5, 3., 3.5]));
+    // too large to widen by a half
+    let (counts, edges) = histogram(&arr1(&[1e17, 1e17, 1e17]), Bins::Count(4));
+    assert_eq!(counts.scalar_sum(), 3);
+    assert!(edges[0] < 1e17 && 1e17 < edges[4]);
+    assert!(edges.iter().zip(edges.iter().skip(1)).all(|(a, b)| a < b));
+    let (counts, _) = histogram(&Array1::<f32>::zeros(0), Bins::Count(3));
+    assert_eq!(counts, arr1(&[0, 0, 0]));
+}
+
+#[test]
+fn histogram_edges() {
+    let a = Array::range(0., 10., 0.5);
+    let edges = arr1(&[1., 2., 5., 9.]);
+    let (counts, e) = histogram(&a, Bins::Edges(edges.clone()));
+    assert_eq!(e, edges);
+    assert_eq!(counts, arr1(&[2, 6, 9]));
+}
+
+#[test]
+#[should_panic]
+fn histogram_edges_not_increasing() {
+    histogram(&arr1(&[1.]), Bins::Edges(arr1(&[0., 2., 2.])));
+}
+
+#[test]
+#[should_panic]
+fn histogram_no_bins() {
+    histogram(&arr1(&[1.]), Bins::Uniform(0, 0., 1.));
+}
+
+#[test]
+fn histogram_2d() {
+    let x = Array::from_iter((0..100).map(|i| (i % 10) as f64));
+    let y = Array::from_iter((0..100).map(|i| (i / 10) as f64));
+    let (counts, xe, ye) = histogram2d(&x, &y, Bins::Count(5),
+                                       Bins::Edges(arr1(&[0., 5., 20.])));
+    assert_eq!(xe, Array::linspace(0., 9., 6));
+    assert_eq!(ye, arr1(&[0., 5., 20.]));
+    assert_eq!(counts.dim(), (5, 2));
+    assert_eq!(counts, Array2::from_elem((5, 2), 10));
+    assert_eq!(counts.sum(Axis(1)), histogram(&x, Bins::Count(5)).0);
+}
+
+#[test]
+#[should_panic]
+fn histogram_2d_shape_mismatch() {
+    histogram2d(&arr1(&[1., 2.]), &arr1(&[1.]), Bins::Count(2), Bins::Count(2));
+}
+
+#[test]
+fn covariance() {
+    let a = Array::from_iter((0..20).map(|x| ((x * 7) % 11) as f64)).into_shape((4, 5)).unwrap();
+    for &ddof in &[0., 1.] {
+        let c = cov(&a, ddof);
+        assert_eq!(c.dim(), (4, 4));
+        assert!(c.diag().all_close(&a.var(Axis(1), ddof), 1e-12));
+        for i in 0..4 {
+            for j in 0..4 {
+                let (x, y) = (a.row(i), a.row(j));
+                let (mx, my) = (x.scalar_sum() / 5., y.scalar_sum() / 5.);
+                let s = x.iter().zip(&y).map(|(&x, &y)| (x - mx) * (y - my))
+                         .fold(0., |acc, p| acc + p);
+                assert!((c[[i, j]] - s / (5. - ddof)).abs() < 1e-12);
+            }
+        }
+    }
+    // variables in columns
+    assert!(cov(&a.t(), 1.).all_close(&cov(&a.t().to_owned(), 1.), 1e-12));
+    assert_eq!(cov(&a.t(), 1.).dim(), (5, 5));
+}
+
+#[test]
+#[should_panic]
+fn covariance_ddof_too_large() {
+    cov(&Array2::<f64>::zeros((2, 3)), 3.);
+}
+
+#[test]
+fn correlation() {
+    let a = arr2(&[[1f64, 2., 3., 4.],
+                   [2., 4., 6., 8.],
+                   [4., 3., 2., 1.],
+                   [5., 5., 5., 5.]]);
+    let r = corrcoef(&a);
+    for &(i, j, x) in &[(0, 0, 1.), (0, 1, 1.), (0, 2, -1.), (1, 2, -1.)] {
+        assert!((r[[i, j]] - x).abs() < 1e-12);
+        assert_eq!(r[[i, j]], r[[j, i]]);
+    }
+    assert!(r.iter().all(|&x| x.is_nan() || (x >= -1. && x <= 1.)));
+    // constant variable
+    assert!(r.row(3).iter().all(|x| x.is_nan()));
+}